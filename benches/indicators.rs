@@ -12,12 +12,17 @@ fn bench_indicator<T: IndicatorConfig + Default>(b: &mut test::Bencher) {
 	let mut indicator = T::default().init(iter.next().unwrap()).unwrap();
 
 	for _ in 0..50 {
-		indicator.next(iter.next().unwrap());
+		let _ = indicator.next(iter.next().unwrap());
 	}
 
 	b.iter(|| indicator.next(iter.next().unwrap()))
 }
 
+#[bench]
+fn bench_indicator_alligator(b: &mut test::Bencher) {
+	bench_indicator::<Alligator>(b);
+}
+
 #[bench]
 fn bench_indicator_aroon(b: &mut test::Bencher) {
 	bench_indicator::<Aroon>(b);
@@ -33,6 +38,28 @@ fn bench_awesome_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<AwesomeOscillator>(b);
 }
 
+#[bench]
+fn bench_band_width(b: &mut test::Bencher) {
+	bench_indicator::<BandWidth>(b);
+}
+
+#[bench]
+fn bench_beta_indicator(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().zip(candles.iter().rev()).cycle();
+	let mut indicator = BetaIndicator::default().init(&candles[0]).unwrap();
+
+	for _ in 0..50 {
+		let (candle, benchmark) = iter.next().unwrap();
+		let _ = indicator.next_with(candle, benchmark.close);
+	}
+
+	b.iter(|| {
+		let (candle, benchmark) = iter.next().unwrap();
+		indicator.next_with(candle, benchmark.close)
+	})
+}
+
 #[bench]
 fn bench_bollinger_bands(b: &mut test::Bencher) {
 	bench_indicator::<BollingerBands>(b);
@@ -58,6 +85,11 @@ fn bench_chande_momentum_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<ChandeMomentumOscillator>(b);
 }
 
+#[bench]
+fn bench_chandelier_exit(b: &mut test::Bencher) {
+	bench_indicator::<ChandelierExit>(b);
+}
+
 #[bench]
 fn bench_commodity_channel_index(b: &mut test::Bencher) {
 	bench_indicator::<CommodityChannelIndex>(b);
@@ -83,6 +115,11 @@ fn bench_ease_of_movement(b: &mut test::Bencher) {
 	bench_indicator::<EaseOfMovement>(b);
 }
 
+#[bench]
+fn bench_elder_impulse(b: &mut test::Bencher) {
+	bench_indicator::<ElderImpulse>(b);
+}
+
 #[bench]
 fn bench_elders_force_index(b: &mut test::Bencher) {
 	bench_indicator::<EldersForceIndex>(b);
@@ -98,6 +135,11 @@ fn bench_fisher_transform(b: &mut test::Bencher) {
 	bench_indicator::<FisherTransform>(b);
 }
 
+#[bench]
+fn bench_guppy_multiple_moving_average(b: &mut test::Bencher) {
+	bench_indicator::<GuppyMultipleMovingAverage>(b);
+}
+
 #[bench]
 fn bench_hull_moving_average(b: &mut test::Bencher) {
 	bench_indicator::<HullMovingAverage>(b);
@@ -128,11 +170,31 @@ fn bench_know_sure_thing(b: &mut test::Bencher) {
 	bench_indicator::<KnowSureThing>(b);
 }
 
+#[bench]
+fn bench_lin_reg_channel(b: &mut test::Bencher) {
+	bench_indicator::<LinRegChannel>(b);
+}
+
 #[bench]
 fn bench_macd(b: &mut test::Bencher) {
 	bench_indicator::<MACD>(b);
 }
 
+#[bench]
+fn bench_macd_divergence(b: &mut test::Bencher) {
+	bench_indicator::<MacdDivergence>(b);
+}
+
+#[bench]
+fn bench_mass_index(b: &mut test::Bencher) {
+	bench_indicator::<MassIndex>(b);
+}
+
+#[bench]
+fn bench_mean_reversion(b: &mut test::Bencher) {
+	bench_indicator::<MeanReversion>(b);
+}
+
 #[bench]
 fn bench_momentum_index(b: &mut test::Bencher) {
 	bench_indicator::<MomentumIndex>(b);
@@ -143,16 +205,36 @@ fn bench_money_flow_index(b: &mut test::Bencher) {
 	bench_indicator::<MoneyFlowIndex>(b);
 }
 
+#[bench]
+fn bench_normalized_macd(b: &mut test::Bencher) {
+	bench_indicator::<NormalizedMACD>(b);
+}
+
 #[bench]
 fn bench_parabolic_sar(b: &mut test::Bencher) {
 	bench_indicator::<ParabolicSAR>(b);
 }
 
+#[bench]
+fn bench_percentage_price_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<PercentagePriceOscillator>(b);
+}
+
+#[bench]
+fn bench_pivot_points(b: &mut test::Bencher) {
+	bench_indicator::<PivotPoints>(b);
+}
+
 #[bench]
 fn bench_pivot_reversal_strategy(b: &mut test::Bencher) {
 	bench_indicator::<PivotReversalStrategy>(b);
 }
 
+#[bench]
+fn bench_rainbow_oscillator(b: &mut test::Bencher) {
+	bench_indicator::<RainbowOscillator>(b);
+}
+
 #[bench]
 fn bench_relative_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<RelativeStrengthIndex>(b);
@@ -163,16 +245,31 @@ fn bench_relative_vigor_index(b: &mut test::Bencher) {
 	bench_indicator::<RelativeVigorIndex>(b);
 }
 
+#[bench]
+fn bench_schaff_trend_cycle(b: &mut test::Bencher) {
+	bench_indicator::<SchaffTrendCycle>(b);
+}
+
 #[bench]
 fn bench_smi_ergodic_indicator(b: &mut test::Bencher) {
 	bench_indicator::<SMIErgodicIndicator>(b);
 }
 
+#[bench]
+fn bench_smoothed_heikin_ashi(b: &mut test::Bencher) {
+	bench_indicator::<SmoothedHeikinAshi>(b);
+}
+
 #[bench]
 fn bench_stochastic_oscillator(b: &mut test::Bencher) {
 	bench_indicator::<StochasticOscillator>(b);
 }
 
+#[bench]
+fn bench_stochastic_relative_strength_index(b: &mut test::Bencher) {
+	bench_indicator::<StochasticRelativeStrengthIndex>(b);
+}
+
 #[bench]
 fn bench_trend_strength_index(b: &mut test::Bencher) {
 	bench_indicator::<TrendStrengthIndex>(b);
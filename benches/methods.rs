@@ -1,6 +1,6 @@
 #![feature(test)]
-use yata::core::ValueType;
-use yata::helpers::RandomCandles;
+use yata::core::{Action, ValueType};
+use yata::helpers::{RandomCandles, MA};
 use yata::methods::*;
 use yata::prelude::Method;
 
@@ -13,7 +13,7 @@ fn bench_adi_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = ADI::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -24,7 +24,7 @@ fn bench_adi_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = ADI::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -36,7 +36,7 @@ fn bench_conv_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Conv::new((0..10).map(|x| x as ValueType).collect(), &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -47,7 +47,7 @@ fn bench_conv_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Conv::new((0..100).map(|x| x as ValueType).collect(), &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -89,6 +89,77 @@ fn bench_cross_under(b: &mut test::Bencher) {
 	b.iter(|| method.next(iter.next().unwrap()))
 }
 
+// SignalDecay -----------------------------------------------------------------------------
+#[bench]
+fn bench_signal_decay(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| Action::from(c.close - c.open))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SignalDecay::new((10, Decay::Linear), &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Position ------------------------------------------------------------------------------
+#[bench]
+fn bench_position(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| Action::from(c.close - c.open))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Position::new((), &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// CovarianceMatrix ----------------------------------------------------------------------
+#[bench]
+fn bench_covariance_matrix_w10_k4(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| [c.open, c.high, c.low, c.close])
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = CovarianceMatrix::new(10, &candles[0][..]).unwrap();
+	b.iter(|| method.next(&iter.next().unwrap()[..]))
+}
+
+// AutoCorrelation -----------------------------------------------------------------------
+#[bench]
+fn bench_auto_correlation_w10_l1(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = AutoCorrelation::new((10, 1), &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_auto_correlation_w100_l5(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = AutoCorrelation::new((100, 5), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// RibbonCompression ---------------------------------------------------------------------
+#[bench]
+fn bench_ribbon_compression_k4(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.map(|c| [c.open, c.high, c.low, c.close])
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RibbonCompression::new((), &candles[0][..]).unwrap();
+	b.iter(|| method.next(&iter.next().unwrap()[..]))
+}
+
 // SMA -----------------------------------------------------------------------------------
 #[bench]
 fn bench_sma_w10(b: &mut test::Bencher) {
@@ -96,7 +167,7 @@ fn bench_sma_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = SMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -107,7 +178,7 @@ fn bench_sma_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = SMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -119,7 +190,7 @@ fn bench_wma_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = WMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -130,7 +201,7 @@ fn bench_wma_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = WMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -142,7 +213,7 @@ fn bench_ema_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = EMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -153,7 +224,7 @@ fn bench_ema_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = EMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -165,7 +236,7 @@ fn bench_dma_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = DMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -176,7 +247,7 @@ fn bench_dma_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = DMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -188,7 +259,7 @@ fn bench_tma_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = TMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -199,7 +270,7 @@ fn bench_tma_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = TMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -211,7 +282,7 @@ fn bench_dema_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = DEMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -222,7 +293,7 @@ fn bench_dema_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = DEMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -234,7 +305,7 @@ fn bench_tema_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = TEMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -245,7 +316,7 @@ fn bench_tema_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = TEMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -257,7 +328,7 @@ fn bench_smm_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = SMM::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -268,7 +339,7 @@ fn bench_smm_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = SMM::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -280,7 +351,7 @@ fn bench_hma_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = HMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -291,7 +362,7 @@ fn bench_hma_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = HMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -303,7 +374,7 @@ fn bench_lin_reg_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LinReg::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -314,7 +385,7 @@ fn bench_lin_reg_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LinReg::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -326,7 +397,7 @@ fn bench_derivative_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Derivative::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -337,7 +408,7 @@ fn bench_derivative_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Derivative::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -349,7 +420,7 @@ fn bench_integral_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Integral::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -360,7 +431,7 @@ fn bench_integral_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Integral::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -372,7 +443,7 @@ fn bench_momentum_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Momentum::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -383,7 +454,23 @@ fn bench_momentum_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Momentum::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// SRZones --------------------------------------------------------------------------------
+#[bench]
+fn bench_sr_zones_c20(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new()
+		.take(1000)
+		.enumerate()
+		.map(|(i, c)| (Action::from(i % 5 == 0), c.close))
+		.collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SRZones::new((0.005, 20), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -395,7 +482,7 @@ fn bench_past_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Past::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -406,7 +493,7 @@ fn bench_past_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Past::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -418,7 +505,7 @@ fn bench_rate_of_change_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = RateOfChange::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -429,7 +516,7 @@ fn bench_rate_of_change_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = RateOfChange::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -441,7 +528,7 @@ fn bench_reverse_signal_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = ReversalSignal::new(5, 5, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -452,7 +539,7 @@ fn bench_reverse_signal_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = ReversalSignal::new(50, 50, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -464,7 +551,7 @@ fn bench_reverse_low_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LowerReversalSignal::new(5, 5, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -475,7 +562,7 @@ fn bench_reverse_low_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LowerReversalSignal::new(50, 50, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -487,7 +574,7 @@ fn bench_reverse_high_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LowerReversalSignal::new(5, 5, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -498,7 +585,7 @@ fn bench_reverse_high_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = UpperReversalSignal::new(50, 50, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -510,7 +597,7 @@ fn bench_rma_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = RMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -521,7 +608,7 @@ fn bench_rma_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = RMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -533,7 +620,7 @@ fn bench_st_dev_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = StDev::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -544,7 +631,7 @@ fn bench_st_dev_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = StDev::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -556,7 +643,7 @@ fn bench_swma_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = SWMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -567,7 +654,7 @@ fn bench_swma_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = SWMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -579,7 +666,7 @@ fn bench_trima_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = TRIMA::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -590,7 +677,30 @@ fn bench_trima_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = TRIMA::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// TriangularMA ----------------------------------------------------------------------------
+#[bench]
+fn bench_triangular_ma_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = TriangularMA::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_triangular_ma_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = TriangularMA::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -602,7 +712,7 @@ fn bench_linear_volatility_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LinearVolatility::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -613,7 +723,7 @@ fn bench_linear_volatility_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LinearVolatility::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -626,7 +736,7 @@ fn bench_vwma_w10(b: &mut test::Bencher) {
 	let mut method = VWMA::new(10, &(candles[0].close, candles[0].volume)).unwrap();
 	for _ in 0..10 {
 		let candle = iter.next().unwrap();
-		method.next(&(candle.close, candle.volume));
+		let _ = method.next(&(candle.close, candle.volume));
 	}
 	b.iter(|| {
 		let candle = iter.next().unwrap();
@@ -641,7 +751,7 @@ fn bench_vwma_w100(b: &mut test::Bencher) {
 	let mut method = VWMA::new(100, &(candles[0].close, candles[0].volume)).unwrap();
 	for _ in 0..100 {
 		let candle = iter.next().unwrap();
-		method.next(&(candle.close, candle.volume));
+		let _ = method.next(&(candle.close, candle.volume));
 	}
 	b.iter(|| {
 		let candle = iter.next().unwrap();
@@ -656,7 +766,7 @@ fn bench_highest_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Highest::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -667,7 +777,7 @@ fn bench_highest_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Highest::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -679,7 +789,7 @@ fn bench_lowest_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Lowest::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -690,7 +800,7 @@ fn bench_lowest_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = Lowest::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -702,7 +812,7 @@ fn bench_highest_lowest_delta_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = HighestLowestDelta::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -713,7 +823,30 @@ fn bench_highest_lowest_delta_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = HighestLowestDelta::new(10, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// RangePosition  ------------------------------------------------------------------------
+#[bench]
+fn bench_range_position_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RangePosition::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_range_position_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RangePosition::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -725,7 +858,7 @@ fn bench_highest_index_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = HighestIndex::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -736,7 +869,7 @@ fn bench_highest_index_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = HighestIndex::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -748,7 +881,7 @@ fn bench_lowest_index_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LowestIndex::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -759,7 +892,7 @@ fn bench_lowest_index_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = LowestIndex::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -771,7 +904,7 @@ fn bench_mean_abs_dev_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = MeanAbsDev::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -782,7 +915,7 @@ fn bench_mean_abs_dev_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = MeanAbsDev::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -794,7 +927,7 @@ fn bench_median_abs_dev_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = MedianAbsDev::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -805,7 +938,7 @@ fn bench_median_abs_dev_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = MedianAbsDev::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -817,7 +950,7 @@ fn bench_cci_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = CCI::new(10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -828,7 +961,7 @@ fn bench_cci_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = CCI::new(100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -840,7 +973,7 @@ fn bench_tsi_w10(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = TSI::new(10, 10, &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -851,7 +984,7 @@ fn bench_tsi_w100(b: &mut test::Bencher) {
 	let mut iter = candles.iter().cycle();
 	let mut method = TSI::new(100, 100, &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -869,9 +1002,9 @@ fn bench_tr(b: &mut test::Bencher) {
 fn bench_vidya_w10(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = Vidya::new(10, &candles[0]).unwrap();
+	let mut method = Vidya::new((10, 10), &candles[0]).unwrap();
 	for _ in 0..10 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
@@ -880,9 +1013,515 @@ fn bench_vidya_w10(b: &mut test::Bencher) {
 fn bench_vidya_w100(b: &mut test::Bencher) {
 	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
 	let mut iter = candles.iter().cycle();
-	let mut method = Vidya::new(100, &candles[0]).unwrap();
+	let mut method = Vidya::new((100, 100), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// ZLEMA  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_zlema_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ZLEMA::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_zlema_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = ZLEMA::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Entropy  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_entropy_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Entropy::new((10, 10), &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_entropy_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Entropy::new((100, 10), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// AbsMax  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_abs_max_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = AbsMax::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_abs_max_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = AbsMax::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// NormalizeByAbsMax  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_normalize_by_abs_max_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = NormalizeByAbsMax::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_normalize_by_abs_max_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = NormalizeByAbsMax::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// SmoothedRocSum  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_smoothed_roc_sum_coppock(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = SmoothedRocSum::new(SmoothedRocSumParams::new(MA::WMA(10)).roc(14, 1.0).roc(11, 1.0), &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// RollingSharpe  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_rolling_sharpe_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RollingSharpe::new((10, 252.0, 0.0), &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_rolling_sharpe_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RollingSharpe::new((100, 252.0, 0.0), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// RollingSortino  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_rolling_sortino_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RollingSortino::new((10, 252.0, 0.0), &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_rolling_sortino_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = RollingSortino::new((100, 252.0, 0.0), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Streak  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_streak(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Streak::new((), &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// PercentB  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_percent_b_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = PercentB::new((10, 2.0), &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_percent_b_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = PercentB::new((100, 2.0), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// EfficiencyRatio  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_efficiency_ratio_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = EfficiencyRatio::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_efficiency_ratio_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = EfficiencyRatio::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// UpDownCount  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_up_down_count_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = UpDownCount::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_up_down_count_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = UpDownCount::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Hysteresis -----------------------------------------------------------------------------------
+#[bench]
+fn bench_hysteresis(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Hysteresis::new((-0.5, 0.5), &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// CandleStats  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_candle_stats_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = CandleStats::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_candle_stats_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = CandleStats::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// CenterOfGravity  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_center_of_gravity_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = CenterOfGravity::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_center_of_gravity_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = CenterOfGravity::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// GeometricMean  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_geometric_mean_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = GeometricMean::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_geometric_mean_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = GeometricMean::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// NarrowestRange  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_narrowest_range_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = NarrowestRange::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_narrowest_range_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = NarrowestRange::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Cumulative  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_cumulative_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Cumulative::<ROC>::new((1, 10), &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_cumulative_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Cumulative::<ROC>::new((1, 100), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// HighestHeap  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_highest_heap_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = HighestHeap::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_highest_heap_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = HighestHeap::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// LowestHeap  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_lowest_heap_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = LowestHeap::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_lowest_heap_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = LowestHeap::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// TrendStrength  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_trend_strength_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = TrendStrength::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_trend_strength_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = TrendStrength::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// LaguerreRsi  --------------------------------------------------------------------------
+#[bench]
+fn bench_laguerre_rsi(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = LaguerreRsi::new(0.5, &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// Cmo  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_cmo_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Cmo::new(10, &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_cmo_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = Cmo::new(100, &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// WilliamsAD  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_williams_ad(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = WilliamsAD::new((), &candles[0]).unwrap();
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// VolumeOscillator  ---------------------------------------------------------------------
+#[bench]
+fn bench_volume_oscillator_sma_5_20(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method: VolumeOscillator = VolumeOscillator::new((MA::SMA(5), MA::SMA(20)), &candles[0]).unwrap();
+	for _ in 0..20 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// TopKVolume  ---------------------------------------------------------------------------
+#[bench]
+fn bench_top_k_volume_w100_k5(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = TopKVolume::new((100, 5), &candles[0]).unwrap();
+	for _ in 0..100 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+// T3  -----------------------------------------------------------------------------------
+#[bench]
+fn bench_t3_w10(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = T3::new((10, 0.7), &candles[0]).unwrap();
+	for _ in 0..10 {
+		let _ = method.next(iter.next().unwrap());
+	}
+	b.iter(|| method.next(iter.next().unwrap()))
+}
+
+#[bench]
+fn bench_t3_w100(b: &mut test::Bencher) {
+	let candles: Vec<_> = RandomCandles::new().take(1000).map(|c| c.close).collect();
+	let mut iter = candles.iter().cycle();
+	let mut method = T3::new((100, 0.7), &candles[0]).unwrap();
 	for _ in 0..100 {
-		method.next(iter.next().unwrap());
+		let _ = method.next(iter.next().unwrap());
 	}
 	b.iter(|| method.next(iter.next().unwrap()))
 }
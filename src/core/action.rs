@@ -94,6 +94,96 @@ impl Action {
 	pub const fn is_some(self) -> bool {
 		!self.is_none()
 	}
+
+	/// Checks if there is a *buy* signal with non-zero strength
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Action;
+	///
+	/// assert!(Action::BUY_ALL.is_buy());
+	/// assert!(Action::Buy(1).is_buy());
+	/// assert!(!Action::Buy(0).is_buy());
+	/// assert!(!Action::SELL_ALL.is_buy());
+	/// assert!(!Action::None.is_buy());
+	/// ```
+	#[must_use]
+	pub const fn is_buy(self) -> bool {
+		matches!(self, Self::Buy(value) if value > 0)
+	}
+
+	/// Checks if there is a *sell* signal with non-zero strength
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Action;
+	///
+	/// assert!(Action::SELL_ALL.is_sell());
+	/// assert!(Action::Sell(1).is_sell());
+	/// assert!(!Action::Sell(0).is_sell());
+	/// assert!(!Action::BUY_ALL.is_sell());
+	/// assert!(!Action::None.is_sell());
+	/// ```
+	#[must_use]
+	pub const fn is_sell(self) -> bool {
+		matches!(self, Self::Sell(value) if value > 0)
+	}
+
+	/// Checks if signals have opposite directions, so they can not be [merged](Action::merge) without losing both
+	///
+	/// Signals with zero strength never conflict.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Action;
+	///
+	/// assert!(Action::BUY_ALL.conflicts_with(Action::Sell(1)));
+	/// assert!(!Action::BUY_ALL.conflicts_with(Action::Buy(1)));
+	/// assert!(!Action::BUY_ALL.conflicts_with(Action::Sell(0)));
+	/// assert!(!Action::SELL_ALL.conflicts_with(Action::None));
+	/// ```
+	#[must_use]
+	pub const fn conflicts_with(self, other: Self) -> bool {
+		(self.is_buy() && other.is_sell()) || (self.is_sell() && other.is_buy())
+	}
+
+	/// Merges two signals (f.e. of different indicators) into one
+	///
+	/// Rules are:
+	/// * when one of the signals is [`Action::None`], returns the other one;
+	/// * when both signals have the same direction, returns the strongest one;
+	/// * when one of the signals has zero strength, returns the other one;
+	/// * when signals have opposite directions (see [`conflicts_with`](Action::conflicts_with)), returns [`Action::None`].
+	///
+	/// The result does not depend on the order of the signals.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Action;
+	///
+	/// assert_eq!(Action::None.merge(Action::Sell(10)), Action::Sell(10));
+	/// assert_eq!(Action::Buy(10).merge(Action::BUY_ALL), Action::BUY_ALL);
+	/// assert_eq!(Action::Buy(0).merge(Action::Sell(10)), Action::Sell(10));
+	///
+	/// // conflict
+	/// assert_eq!(Action::BUY_ALL.merge(Action::Sell(10)), Action::None);
+	/// ```
+	#[must_use]
+	pub const fn merge(self, other: Self) -> Self {
+		match (self, other) {
+			(Self::None, action) | (action, Self::None) => action,
+			(Self::Buy(a), Self::Buy(b)) => Self::Buy(if a > b { a } else { b }),
+			(Self::Sell(a), Self::Sell(b)) => Self::Sell(if a > b { a } else { b }),
+			(action, Self::Buy(0) | Self::Sell(0)) | (Self::Buy(0) | Self::Sell(0), action) => {
+				action
+			}
+			_ => Self::None,
+		}
+	}
 }
 
 impl PartialEq for Action {
@@ -124,6 +214,31 @@ impl From<bool> for Action {
 	}
 }
 
+impl From<Option<bool>> for Action {
+	fn from(value: Option<bool>) -> Self {
+		match value {
+			None => Self::None,
+			Some(true) => Self::BUY_ALL,
+			Some(false) => Self::SELL_ALL,
+		}
+	}
+}
+
+/// Converts `Action` into `Some(true)` for *buy* signal, `Some(false)` for *sell* signal and `None` otherwise.
+///
+/// Signals with zero strength (`Buy(0)` and `Sell(0)`) have no side, so they are converted to `None`.
+impl From<Action> for Option<bool> {
+	fn from(value: Action) -> Self {
+		if value.is_buy() {
+			Some(true)
+		} else if value.is_sell() {
+			Some(false)
+		} else {
+			None
+		}
+	}
+}
+
 impl From<i8> for Action {
 	fn from(value: i8) -> Self {
 		match value {
@@ -434,6 +549,103 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn test_action_bool_optional() {
+		assert_eq!(Action::from(Some(true)), Action::BUY_ALL);
+		assert_eq!(Action::from(Some(false)), Action::SELL_ALL);
+		assert_eq!(Action::from(None::<bool>), Action::None);
+
+		assert_eq!(Option::<bool>::from(Action::None), None);
+		assert_eq!(Option::<bool>::from(Action::Buy(0)), None);
+		assert_eq!(Option::<bool>::from(Action::Sell(0)), None);
+
+		(1..=BOUND).for_each(|x| {
+			assert_eq!(Option::<bool>::from(Action::Buy(x)), Some(true));
+			assert_eq!(Option::<bool>::from(Action::Sell(x)), Some(false));
+		});
+
+		for value in [None, Some(true), Some(false)] {
+			let action: Action = value.into();
+			assert_eq!(Option::<bool>::from(action), value);
+		}
+	}
+
+	#[test]
+	fn test_action_predicates() {
+		assert!(Action::None.is_none());
+		assert!(!Action::None.is_buy());
+		assert!(!Action::None.is_sell());
+
+		assert!(!Action::Buy(0).is_buy());
+		assert!(!Action::Buy(0).is_sell());
+		assert!(!Action::Sell(0).is_buy());
+		assert!(!Action::Sell(0).is_sell());
+
+		(1..=BOUND).for_each(|x| {
+			let buy = Action::Buy(x);
+			let sell = Action::Sell(x);
+
+			assert!(buy.is_buy() && !buy.is_sell() && !buy.is_none());
+			assert!(sell.is_sell() && !sell.is_buy() && !sell.is_none());
+			assert_eq!(buy.is_buy(), buy.analog() > 0);
+			assert_eq!(sell.is_sell(), sell.analog() < 0);
+		});
+	}
+
+	#[test]
+	fn test_action_merge() {
+		let (buy, sell, none) = (Action::BUY_ALL, Action::SELL_ALL, Action::None);
+
+		let cases = [
+			(buy, buy, buy),
+			(buy, sell, none),
+			(buy, none, buy),
+			(sell, buy, none),
+			(sell, sell, sell),
+			(sell, none, sell),
+			(none, buy, buy),
+			(none, sell, sell),
+			(none, none, none),
+		];
+
+		for (a, b, expected) in cases {
+			assert_eq!(a.merge(b), expected, "{a:?} merge {b:?}");
+			assert_eq!(a.conflicts_with(b), a.is_some() && b.is_some() && a != b);
+		}
+	}
+
+	#[test]
+	fn test_action_merge_strength() {
+		for a in 0..=BOUND {
+			for b in [0, 1, 100, BOUND] {
+				let strongest = a.max(b);
+
+				assert_eq!(Action::Buy(a).merge(Action::Buy(b)), Action::Buy(strongest));
+				assert_eq!(
+					Action::Sell(a).merge(Action::Sell(b)),
+					Action::Sell(strongest)
+				);
+
+				let expected = match (a, b) {
+					(0, 0) => Action::Buy(0),
+					(0, b) => Action::Sell(b),
+					(a, 0) => Action::Buy(a),
+					_ => Action::None,
+				};
+
+				assert_eq!(Action::Buy(a).merge(Action::Sell(b)), expected);
+				assert_eq!(Action::Sell(b).merge(Action::Buy(a)), expected);
+				assert_eq!(
+					Action::Buy(a).conflicts_with(Action::Sell(b)),
+					a > 0 && b > 0
+				);
+
+				assert_eq!(Action::Buy(a).merge(Action::None), Action::Buy(a));
+				assert_eq!(Action::None.merge(Action::Sell(b)), Action::Sell(b));
+			}
+		}
+	}
+
 	#[test]
 	fn test_action_neg() {
 		(0..=BOUND).for_each(|x| {
@@ -145,6 +145,72 @@ impl Candle {
 			volume: src.volume(),
 		}
 	}
+
+	/// Combines two consecutive candles into one candle
+	///
+	/// `open` is taken from `self`, `close` is taken from `other`, `high` and `low` are the extremes of both candles and `volume` is summed.
+	///
+	/// Same as `self + other`.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	///
+	/// let first = Candle { open: 3.0, high: 5.0, low: 2.0, close: 4.0, volume: 10.0 };
+	/// let second = Candle { open: 4.0, high: 6.0, low: 3.0, close: 5.5, volume: 15.0 };
+	///
+	/// let merged = first.merge(&second);
+	/// assert_eq!(merged, Candle { open: 3.0, high: 6.0, low: 2.0, close: 5.5, volume: 25.0 });
+	/// ```
+	#[must_use]
+	pub fn merge<T: OHLCV + ?Sized>(&self, other: &T) -> Self {
+		Self {
+			open: self.open,
+			high: self.high.max(other.high()),
+			low: self.low.min(other.low()),
+			close: other.close(),
+			volume: self.volume + other.volume(),
+		}
+	}
+
+	/// Linearly interpolates candle between two candles `a` and `b`
+	///
+	/// `t` is the position between candles: `0.0` returns `a`, `1.0` returns `b`, `0.5` returns the midpoint.
+	///
+	/// Every field (`open`, `high`, `low`, `close` and `volume`) is interpolated independently:
+	///
+	/// `field` = `a.field` + \(`b.field` - `a.field`\) \* `t`
+	///
+	/// So when `t` is in range \[`0.0`; `1.0`\] and both candles are valid (`low` <= `open`, `close` <= `high`), the result is also valid.
+	/// Values of `t` outside of this range extrapolate and may produce `high` below `low`.
+	///
+	/// Useful for aligning two instruments with different bars' boundaries on a common time grid:
+	/// `t` = \(`timestamp` - `a.timestamp`\) / \(`b.timestamp` - `a.timestamp`\).
+	///
+	/// ```
+	/// use yata::prelude::*;
+	///
+	/// let a = Candle { open: 1.0, high: 4.0, low: 1.0, close: 3.0, volume: 10.0 };
+	/// let b = Candle { open: 3.0, high: 8.0, low: 3.0, close: 7.0, volume: 20.0 };
+	///
+	/// let c = Candle::interpolate(&a, &b, 0.5);
+	/// assert_eq!(c, Candle { open: 2.0, high: 6.0, low: 2.0, close: 5.0, volume: 15.0 });
+	/// ```
+	#[must_use]
+	pub fn interpolate<A, B>(a: &A, b: &B, t: ValueType) -> Self
+	where
+		A: OHLCV + ?Sized,
+		B: OHLCV + ?Sized,
+	{
+		let lerp = |a: ValueType, b: ValueType| (b - a).mul_add(t, a);
+
+		Self {
+			open: lerp(a.open(), b.open()),
+			high: lerp(a.high(), b.high()),
+			low: lerp(a.low(), b.low()),
+			close: lerp(a.close(), b.close()),
+			volume: lerp(a.volume(), b.volume()),
+		}
+	}
 }
 
 /// Just an alias for [Candle]
@@ -223,19 +289,124 @@ impl<T: OHLCV> std::ops::Add<T> for Candle {
 	type Output = Self;
 
 	fn add(self, rhs: T) -> Self::Output {
-		Self {
-			high: self.high.max(rhs.high()),
-			low: self.low.min(rhs.low()),
-			close: rhs.close(),
-			volume: self.volume + rhs.volume(),
-			..self
-		}
+		self.merge(&rhs)
+	}
+}
+
+/// Degenerate candle synthesized from a single value
+///
+/// Lets any [`OHLCV`]-based method or indicator consume a timeseries of plain [`ValueType`] values (f.e. close prices only):
+/// `open`, `high`, `low` and `close` are all equal to the value and `volume` is `0.0`.
+///
+/// # Limitations
+///
+/// There is no intrabar range: `high` - `low` is always `0.0` and there are no gaps between candles except the change of the value itself.
+/// So range-based methods and indicators (f.e. [`TR`](crate::methods::TR) or [`ParabolicSAR`](crate::indicators::ParabolicSAR))
+/// see only close-to-close moves, and volume-based ones (f.e. [`ADI`](crate::methods::ADI) or [`MoneyFlowIndex`](crate::indicators::MoneyFlowIndex))
+/// see no volume at all.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::SyntheticCandle;
+/// use yata::indicators::DonchianChannel;
+///
+/// let closes = [1.0, 2.0, 3.0, 2.5, 2.0];
+///
+/// let candles: Vec<_> = closes.iter().copied().map(SyntheticCandle).collect();
+/// let results = DonchianChannel::default().over(&candles).unwrap();
+///
+/// assert_eq!(results.len(), closes.len());
+///
+/// let candle: Candle = SyntheticCandle(2.0).into();
+/// assert_eq!(candle, Candle { open: 2.0, high: 2.0, low: 2.0, close: 2.0, volume: 0.0 });
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SyntheticCandle(pub ValueType);
+
+impl OHLCV for SyntheticCandle {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.0
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.0
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.0
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.0
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		0.0
+	}
+}
+
+impl From<ValueType> for SyntheticCandle {
+	fn from(value: ValueType) -> Self {
+		Self(value)
+	}
+}
+
+impl From<SyntheticCandle> for Candle {
+	fn from(candle: SyntheticCandle) -> Self {
+		Self::from(&candle)
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::Source;
+	use super::{Candle, Source, SyntheticCandle};
+	use crate::core::{Method, ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::Highest;
+
+	#[test]
+	fn test_candle_merge() {
+		let first = Candle {
+			open: 10.0,
+			high: 12.0,
+			low: 8.0,
+			close: 11.0,
+			volume: 100.0,
+		};
+		let second = Candle {
+			open: 11.5,
+			high: 13.0,
+			low: 9.0,
+			close: 12.5,
+			volume: 50.0,
+		};
+
+		let merged = first.merge(&second);
+
+		assert_eq_float(10.0, merged.open);
+		assert_eq_float(13.0, merged.high);
+		assert_eq_float(8.0, merged.low);
+		assert_eq_float(12.5, merged.close);
+		assert_eq_float(150.0, merged.volume);
+
+		let reversed = second.merge(&first);
+
+		assert_eq_float(11.5, reversed.open);
+		assert_eq_float(13.0, reversed.high);
+		assert_eq_float(8.0, reversed.low);
+		assert_eq_float(11.0, reversed.close);
+		assert_eq_float(150.0, reversed.volume);
+
+		assert_eq!(merged, first + second);
+	}
 
 	#[test]
 	fn test_source_to_string_str() {
@@ -302,4 +473,71 @@ mod tests {
 
 		assert!(src.is_err());
 	}
+
+	#[test]
+	fn test_synthetic_candle() {
+		let closes: Vec<ValueType> = RandomCandles::new().take(300).map(|x| x.close).collect();
+
+		let mut highest_high = Highest::new(14, &SyntheticCandle(closes[0]).high()).unwrap();
+		let mut highest_close = Highest::new(14, &closes[0]).unwrap();
+
+		for &close in &closes {
+			let candle = SyntheticCandle::from(close);
+
+			assert_eq!(candle.open().to_bits(), close.to_bits());
+			assert_eq!(candle.low().to_bits(), close.to_bits());
+			assert_eq!(candle.close().to_bits(), close.to_bits());
+			assert_eq_float(0.0, candle.volume());
+
+			assert_eq_float(
+				highest_close.next(&close),
+				highest_high.next(&candle.high()),
+			);
+		}
+
+		let candle: Candle = SyntheticCandle(3.0).into();
+		let expected: Candle = (3.0, 3.0, 3.0, 3.0, 0.0).into();
+		assert_eq!(candle, expected);
+	}
+
+	#[test]
+	fn test_candle_interpolate() {
+		let a = Candle {
+			open: 10.0,
+			high: 12.0,
+			low: 8.0,
+			close: 11.0,
+			volume: 100.0,
+		};
+		let b = Candle {
+			open: 11.5,
+			high: 13.0,
+			low: 9.0,
+			close: 12.5,
+			volume: 50.0,
+		};
+
+		let mid = Candle::interpolate(&a, &b, 0.5);
+		assert_eq_float(11.75, mid.close);
+		assert_eq_float(10.75, mid.open);
+		assert_eq_float(12.5, mid.high);
+		assert_eq_float(8.5, mid.low);
+		assert_eq_float(75.0, mid.volume);
+
+		assert_eq!(Candle::interpolate(&a, &b, 0.0), a);
+		assert_eq_float(b.close, Candle::interpolate(&a, &b, 1.0).close);
+
+		let random: Vec<_> = RandomCandles::new().take(100).collect();
+
+		for candles in random.windows(2) {
+			for i in 0..=10 {
+				let t = i as ValueType / 10.0;
+				let c = Candle::interpolate(&candles[0], &candles[1], t);
+
+				assert!(c.low <= c.open.min(c.close) && c.high >= c.open.max(c.close));
+				assert!(c.close >= candles[0].close.min(candles[1].close));
+				assert!(c.close <= candles[0].close.max(candles[1].close));
+			}
+		}
+	}
 }
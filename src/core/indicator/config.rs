@@ -1,5 +1,5 @@
-use super::{IndicatorInstance, IndicatorResult};
-use crate::core::{Error, OHLCV};
+use super::{IndicatorInstance, IndicatorResult, Parameter};
+use crate::core::{Error, Source, ValueType, OHLCV};
 
 /// Each indicator has it's own **Configuration** with parameters
 ///
@@ -26,11 +26,85 @@ pub trait IndicatorConfig: Clone {
 	/// Initializes the **State** based on current **Configuration**
 	fn init<T: OHLCV>(self, initial_value: &T) -> Result<Self::Instance, Error>;
 
+	/// Initializes the **State** based on current **Configuration** and warms it up over the whole `candles` history
+	///
+	/// The **State** is initialized on the first candle and then evaluated over every candle of the history, so it is ready to
+	/// evaluate the next candle right after the history. Results produced while warming up are dropped.
+	///
+	/// Indicators which can warm up more efficiently than candle-by-candle may override this method.
+	///
+	/// Returns [`Error::InvalidCandles`](crate::core::Error::InvalidCandles) if `candles` is empty.
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::Trix;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+	/// let mut state = Trix::default().init_from(&candles[..99]).unwrap();
+	/// let result = state.next(&candles[99]);
+	/// println!("{:?}", result);
+	/// ```
+	fn init_from<T: OHLCV>(self, candles: &[T]) -> Result<Self::Instance, Error> {
+		let first = candles.first().ok_or(Error::InvalidCandles)?;
+		let mut state = self.init(first)?;
+
+		for candle in candles {
+			let _ = state.next(candle);
+		}
+
+		Ok(state)
+	}
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str {
 		Self::NAME
 	}
 
+	/// Returns count of the first results which are affected by the initial value and so may differ from the results evaluated over a longer history
+	///
+	/// Default implementation returns `None`, which means the indicator does not describe its warmup.
+	///
+	/// See also [`run_trimmed`](crate::helpers::run_trimmed).
+	fn warmup_period(&self) -> Option<usize> {
+		None
+	}
+
+	/// Returns descriptions of the **Configuration** parameters with their current values and bounds
+	///
+	/// Names of the parameters are the same as accepted by [`set`](IndicatorConfig::set).
+	///
+	/// Default implementation returns `None`, which means the indicator does not describe its parameters.
+	fn parameters(&self) -> Option<Vec<Parameter>> {
+		None
+	}
+
+	/// Returns JSON-Schema-like description of the **Configuration** built from [`parameters`](IndicatorConfig::parameters)
+	///
+	/// Current values of the parameters are used as the defaults.
+	/// Returns `None` if the indicator does not describe its parameters.
+	///
+	/// Available only with `json_schema` feature.
+	/// ```
+	/// # #[cfg(feature = "json_schema")] {
+	/// use yata::prelude::*;
+	/// use yata::indicators::{PivotReversalStrategy, Trix};
+	///
+	/// let schema = PivotReversalStrategy::default().json_schema().unwrap();
+	///
+	/// assert_eq!(schema["title"], "PivotReversalStrategy");
+	/// assert_eq!(schema["properties"]["left"]["type"], "integer");
+	/// assert_eq!(schema["properties"]["left"]["default"], 4);
+	///
+	/// assert!(Trix::default().json_schema().is_none());
+	/// # }
+	/// ```
+	#[cfg(feature = "json_schema")]
+	fn json_schema(&self) -> Option<serde_json::Value> {
+		let parameters = self.parameters()?;
+
+		Some(super::parameters::json_schema(Self::NAME, &parameters))
+	}
+
 	/// Creates an `IndicatorInstance` function from this `IndicatorConfig`.
 	fn init_fn<'a, T: OHLCV>(
 		self,
@@ -72,3 +146,32 @@ pub trait IndicatorConfig: Clone {
 		Ok(IndicatorInstance::over(&mut state, inputs))
 	}
 }
+
+/// Indicator **Configuration** which reads a single price from each candle
+///
+/// Such configurations has a `source` parameter of type [`Source`], which selects the price.
+/// So, f.e., [`RelativeStrengthIndex`](crate::indicators::RelativeStrengthIndex) may be evaluated over [`TP`](crate::core::Source::TP) instead of the close price.
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{CandleSource, Source};
+/// use yata::indicators::RelativeStrengthIndex;
+///
+/// let candle = Candle { open: 10.0, high: 14.0, low: 9.0, close: 13.0, volume: 1.0 };
+///
+/// let mut rsi = RelativeStrengthIndex::default();
+/// assert_eq!(rsi.price(&candle), 13.0);
+///
+/// rsi.source = Source::TP;
+/// assert_eq!(rsi.price(&candle), 12.0);
+/// ```
+pub trait CandleSource {
+	/// Returns the [`Source`] of the price
+	fn source(&self) -> Source;
+
+	/// Extracts the price from the `candle` according to the [`Source`]
+	#[inline]
+	fn price<T: OHLCV + ?Sized>(&self, candle: &T) -> ValueType {
+		candle.source(self.source())
+	}
+}
@@ -2,10 +2,27 @@ use super::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::core::{Error, OHLCV};
 
 /// Dynamically dispatchable [`IndicatorConfig`](crate::core::IndicatorConfig)
-pub trait IndicatorConfigDyn<T: OHLCV> {
+///
+/// Boxed configurations are cloneable through [`IndicatorConfigDynClone`], which is implemented automatically
+/// for every `Clone` type.
+pub trait IndicatorConfigDyn<T: OHLCV>: IndicatorConfigDynClone<T> {
 	/// Dynamically initializes the **State** based on the current **Configuration**
 	fn init(&self, initial_value: &T) -> Result<Box<dyn IndicatorInstanceDyn<T>>, Error>;
 
+	/// Dynamically initializes the **State** and warms it up over the whole `candles` history
+	///
+	/// See more at [`IndicatorConfig::init_from`](crate::core::IndicatorConfig::init_from)
+	fn init_from(&self, candles: &[T]) -> Result<Box<dyn IndicatorInstanceDyn<T>>, Error> {
+		let first = candles.first().ok_or(Error::InvalidCandles)?;
+		let mut state = self.init(first)?;
+
+		for candle in candles {
+			let _ = state.next(candle);
+		}
+
+		Ok(state)
+	}
+
 	/// Evaluates dynamically dispatched [`IndicatorConfig`](crate::core::IndicatorConfig)  over series of OHLC and returns series of `IndicatorResult`s
 	/// ```
 	/// use yata::prelude::dd::*;
@@ -33,6 +50,41 @@ pub trait IndicatorConfigDyn<T: OHLCV> {
 	fn size(&self) -> (u8, u8);
 }
 
+/// Helper trait, which makes `Box<dyn IndicatorConfigDyn<T>>` cloneable
+///
+/// It is implemented for every [`IndicatorConfigDyn`] which is `Clone`, so it never needs to be implemented manually.
+/// Boxed configurations may be stored in collections and copied f.e. for parameters sweeps.
+/// ```
+/// use yata::core::Candle;
+/// use yata::prelude::dd::*;
+/// use yata::indicators::Trix;
+///
+/// let config: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(Trix::default());
+/// let mut copy = config.clone();
+///
+/// copy.set("period1", String::from("20")).unwrap();
+/// ```
+pub trait IndicatorConfigDynClone<T: OHLCV> {
+	/// Clones the **Configuration** into a new box
+	fn clone_box(&self) -> Box<dyn IndicatorConfigDyn<T>>;
+}
+
+impl<T, C> IndicatorConfigDynClone<T> for C
+where
+	T: OHLCV,
+	C: IndicatorConfigDyn<T> + Clone + 'static,
+{
+	fn clone_box(&self) -> Box<dyn IndicatorConfigDyn<T>> {
+		Box::new(self.clone())
+	}
+}
+
+impl<T: OHLCV> Clone for Box<dyn IndicatorConfigDyn<T>> {
+	fn clone(&self) -> Self {
+		self.clone_box()
+	}
+}
+
 impl<T, I, C> IndicatorConfigDyn<T> for C
 where
 	T: OHLCV,
@@ -44,6 +96,11 @@ where
 		Ok(Box::new(instance))
 	}
 
+	fn init_from(&self, candles: &[T]) -> Result<Box<dyn IndicatorInstanceDyn<T>>, Error> {
+		let instance = IndicatorConfig::init_from(self.clone(), candles)?;
+		Ok(Box::new(instance))
+	}
+
 	fn over(&self, inputs: &dyn AsRef<[T]>) -> Result<Vec<IndicatorResult>, Error> {
 		IndicatorConfig::over(self.clone(), inputs)
 	}
@@ -68,6 +125,7 @@ where
 /// Dynamically dispatchable [`IndicatorInstance`](crate::core::IndicatorInstance)
 pub trait IndicatorInstanceDyn<T: OHLCV> {
 	/// Evaluates given candle and returns [`IndicatorResult`](crate::core::IndicatorResult)
+	#[must_use = "indicator's result should be used, otherwise just its state is updated"]
 	fn next(&mut self, candle: &T) -> IndicatorResult;
 
 	/// Evaluates the **State** over the given sequence of candles and returns sequence of `IndicatorResult`s.
@@ -94,6 +152,20 @@ pub trait IndicatorInstanceDyn<T: OHLCV> {
 	/// See more at [`IndicatorConfig`](crate::core::IndicatorConfig::size)
 	fn size(&self) -> (u8, u8);
 
+	/// Returns names of the indicator's raw values.
+	///
+	/// See more at [`IndicatorInstance`](crate::core::IndicatorInstance::value_names)
+	fn value_names(&self) -> &'static [&'static str] {
+		&[]
+	}
+
+	/// Returns names of the indicator's signals.
+	///
+	/// See more at [`IndicatorInstance`](crate::core::IndicatorInstance::signal_names)
+	fn signal_names(&self) -> &'static [&'static str] {
+		&[]
+	}
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str;
 }
@@ -119,7 +191,54 @@ where
 		IndicatorInstance::size(self)
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		IndicatorInstance::value_names(self)
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		IndicatorInstance::signal_names(self)
+	}
+
 	fn name(&self) -> &'static str {
 		IndicatorInstance::name(self)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::IndicatorConfigDyn;
+	use crate::core::{Action, Candle, IndicatorResult};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::PivotReversalStrategy;
+
+	fn signals(results: &[IndicatorResult]) -> Vec<Action> {
+		results.iter().flat_map(|r| r.signals().to_vec()).collect()
+	}
+
+	#[test]
+	fn test_clone_box() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+
+		let original: Box<dyn IndicatorConfigDyn<Candle>> =
+			Box::new(PivotReversalStrategy::default());
+		let mut copy = original.clone();
+
+		copy.set("left", String::from("10")).unwrap();
+		copy.set("right", String::from("1")).unwrap();
+
+		let mutated: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(PivotReversalStrategy {
+			left: 10,
+			right: 1,
+			..PivotReversalStrategy::default()
+		});
+
+		let original_signals = signals(&original.over(&candles).unwrap());
+		let copy_signals = signals(&copy.over(&candles).unwrap());
+
+		let default: Box<dyn IndicatorConfigDyn<Candle>> =
+			Box::new(PivotReversalStrategy::default());
+		assert_eq!(original_signals, signals(&default.over(&candles).unwrap()));
+		assert_eq!(copy_signals, signals(&mutated.over(&candles).unwrap()));
+		assert_ne!(original_signals, copy_signals);
+	}
+}
@@ -1,5 +1,5 @@
 use super::{IndicatorConfig, IndicatorResult};
-use crate::core::OHLCV;
+use crate::core::{Error, OHLCV};
 
 /// Base trait for implementing indicators **State**
 pub trait IndicatorInstance: Sized {
@@ -10,8 +10,24 @@ pub trait IndicatorInstance: Sized {
 	fn config(&self) -> &Self::Config;
 
 	/// Evaluates given candle and returns [`IndicatorResult`](crate::core::IndicatorResult)
+	#[must_use = "indicator's result should be used, otherwise just its state is updated"]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult;
 
+	/// Evaluates given candle and returns [`IndicatorResult`](crate::core::IndicatorResult), if all the candle's values are finite numbers
+	///
+	/// Available only with `strict` feature.
+	///
+	/// When any of the candle's values is `NaN` or infinite (see [`OHLCV::is_finite`](crate::core::OHLCV::is_finite)),
+	/// the **State** stays untouched and [`Error::InvalidCandles`](crate::core::Error::InvalidCandles) is returned.
+	#[cfg(feature = "strict")]
+	fn next_checked<T: OHLCV>(&mut self, candle: &T) -> Result<IndicatorResult, Error> {
+		if candle.is_finite() {
+			Ok(self.next(candle))
+		} else {
+			Err(Error::InvalidCandles)
+		}
+	}
+
 	/// Evaluates the **State** over the given sequence of candles and returns sequence of `IndicatorResult`s.
 	/// ```
 	/// use yata::prelude::*;
@@ -35,6 +51,28 @@ pub trait IndicatorInstance: Sized {
 		inputs_ref.iter().map(|x| self.next(x)).collect()
 	}
 
+	/// Evaluates the **State** over the given slice of candles and returns a contiguous buffer of `IndicatorResult`s.
+	///
+	/// Results are exactly the same as of calling [`next`](IndicatorInstance::next) on every candle one by one.
+	/// Default implementation does just that, but instances may override it to process the whole batch more efficiently.
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::PivotReversalStrategy;
+	///
+	/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+	/// let mut state = PivotReversalStrategy::default().init(&candles[0]).unwrap();
+	///
+	/// let results = state.next_batch(&candles);
+	/// assert_eq!(results.len(), candles.len());
+	/// ```
+	fn next_batch<T: OHLCV>(&mut self, candles: &[T]) -> Vec<IndicatorResult> {
+		let mut results = Vec::with_capacity(candles.len());
+		results.extend(candles.iter().map(|candle| self.next(candle)));
+
+		results
+	}
+
 	/// Returns count of indicator's raw values and count of indicator's signals.
 	///
 	/// See more at [`IndicatorConfig`](crate::core::IndicatorConfig::size)
@@ -42,6 +80,33 @@ pub trait IndicatorInstance: Sized {
 		self.config().size()
 	}
 
+	/// Returns names of the indicator's raw values in the same order as they appear in [`IndicatorResult`](crate::core::IndicatorResult)
+	///
+	/// When overridden, length of the slice must be equal to the count of raw values returned by [`size`](IndicatorInstance::size).
+	/// Default implementation returns an empty slice, which means the raw values are not named.
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::helpers::RandomCandles;
+	/// use yata::indicators::MACD;
+	///
+	/// let mut candles = RandomCandles::new();
+	/// let macd = MACD::default().init(&candles.first()).unwrap();
+	///
+	/// assert_eq!(macd.value_names(), &["macd", "signal_line"]);
+	/// assert_eq!(macd.signal_names(), &["signal_line_cross", "zero_cross"]);
+	/// ```
+	fn value_names(&self) -> &'static [&'static str] {
+		&[]
+	}
+
+	/// Returns names of the indicator's signals in the same order as they appear in [`IndicatorResult`](crate::core::IndicatorResult)
+	///
+	/// When overridden, length of the slice must be equal to the count of signals returned by [`size`](IndicatorInstance::size).
+	/// Default implementation returns an empty slice, which means the signals are not named.
+	fn signal_names(&self) -> &'static [&'static str] {
+		&[]
+	}
+
 	/// Returns a name of the indicator
 	fn name(&self) -> &'static str {
 		Self::Config::NAME
@@ -58,3 +123,57 @@ pub trait IndicatorInstance: Sized {
 		Box::new(f)
 	}
 }
+
+/// Indicator **State**, which may be re-parameterized on the fly without losing all the accumulated state
+///
+/// Useful for adaptive strategies, f.e. to widen some period during high volatility.
+///
+/// Every implementation must document which parts of the **State** survive reconfiguration.
+pub trait ReconfigurableInstance: IndicatorInstance {
+	/// Replaces the **Configuration** with the new one and rebuilds internal methods whose parameters changed, preserving what it can
+	///
+	/// If the new **Configuration** is invalid, returns [`Error::WrongConfig`](crate::core::Error::WrongConfig) and leaves the **State** untouched.
+	fn reconfigure(&mut self, cfg: Self::Config) -> Result<(), Error>;
+}
+
+#[cfg(all(test, feature = "strict"))]
+mod tests {
+	use super::IndicatorInstance;
+	use crate::core::{Candle, Error, IndicatorConfig, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::MACD;
+
+	#[test]
+	fn test_next_checked() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+		let mut checked = MACD::default().init(&candles[0]).unwrap();
+		let mut instance = MACD::default().init(&candles[0]).unwrap();
+
+		for candle in &candles {
+			let (a, b) = (checked.next_checked(candle).unwrap(), instance.next(candle));
+			assert_eq!(a.signals(), b.signals());
+		}
+	}
+
+	#[test]
+	fn test_next_checked_nan() {
+		let candle = RandomCandles::new().first();
+		let mut instance = MACD::default().init(&candle).unwrap();
+		let mut untouched = MACD::default().init(&candle).unwrap();
+
+		let nan_candle = Candle {
+			close: ValueType::NAN,
+			..candle
+		};
+
+		assert!(matches!(
+			instance.next_checked(&nan_candle),
+			Err(Error::InvalidCandles)
+		));
+		// state is not poisoned
+		assert_eq!(
+			instance.next(&candle).values(),
+			untouched.next(&candle).values()
+		);
+	}
+}
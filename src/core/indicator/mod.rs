@@ -7,9 +7,11 @@
 mod config;
 mod dd;
 mod instance;
+mod parameters;
 mod result;
 
 pub use config::*;
 pub use dd::*;
 pub use instance::*;
+pub use parameters::{Parameter, ParameterValue};
 pub use result::*;
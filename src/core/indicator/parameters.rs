@@ -0,0 +1,126 @@
+use crate::core::ValueType;
+
+/// Value of an indicator **Configuration** parameter
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterValue {
+	/// Non-negative integer value, f.e. a period
+	Integer(u64),
+	/// Floating point value
+	Float(ValueType),
+	/// Any other value in its string representation (f.e. [`Source`](crate::core::Source) or [`MA`](crate::helpers::MA)),
+	/// which can be passed to [`IndicatorConfig::set`](crate::core::IndicatorConfig::set)
+	String(String),
+}
+
+/// Describes a single parameter of an indicator **Configuration**: its name, current value and bounds
+///
+/// See [`IndicatorConfig::parameters`](crate::core::IndicatorConfig::parameters).
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::{Parameter, ParameterValue};
+///
+/// let param = Parameter::integer("period", 14_u8).min(2.0);
+///
+/// assert_eq!(param.name, "period");
+/// assert_eq!(param.value, ParameterValue::Integer(14));
+/// assert_eq!(param.min, Some(2.0));
+/// assert_eq!(param.max, None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+	/// Name of the parameter, the same as accepted by [`IndicatorConfig::set`](crate::core::IndicatorConfig::set)
+	pub name: &'static str,
+	/// Current value of the parameter
+	pub value: ParameterValue,
+	/// Inclusive lower bound of the parameter
+	pub min: Option<ValueType>,
+	/// Inclusive upper bound of the parameter
+	pub max: Option<ValueType>,
+}
+
+impl Parameter {
+	/// Creates a description of an integer parameter
+	pub fn integer<T: Into<u64>>(name: &'static str, value: T) -> Self {
+		Self::new(name, ParameterValue::Integer(value.into()))
+	}
+
+	/// Creates a description of a floating point parameter
+	#[must_use]
+	pub const fn float(name: &'static str, value: ValueType) -> Self {
+		Self::new(name, ParameterValue::Float(value))
+	}
+
+	/// Creates a description of a parameter, represented by a string
+	pub fn string<T: ToString>(name: &'static str, value: &T) -> Self {
+		Self::new(name, ParameterValue::String(value.to_string()))
+	}
+
+	const fn new(name: &'static str, value: ParameterValue) -> Self {
+		Self {
+			name,
+			value,
+			min: None,
+			max: None,
+		}
+	}
+
+	/// Sets inclusive lower bound of the parameter
+	#[must_use]
+	pub const fn min(mut self, min: ValueType) -> Self {
+		self.min = Some(min);
+		self
+	}
+
+	/// Sets inclusive upper bound of the parameter
+	#[must_use]
+	pub const fn max(mut self, max: ValueType) -> Self {
+		self.max = Some(max);
+		self
+	}
+
+	/// Returns JSON-Schema-like description of the parameter, where current value is used as the default one
+	#[cfg(feature = "json_schema")]
+	#[must_use]
+	pub fn json_schema(&self) -> serde_json::Value {
+		let (kind, default) = match &self.value {
+			ParameterValue::Integer(value) => ("integer", serde_json::json!(value)),
+			ParameterValue::Float(value) => ("number", serde_json::json!(value)),
+			ParameterValue::String(value) => ("string", serde_json::json!(value)),
+		};
+
+		let mut schema = serde_json::json!({
+			"type": kind,
+			"default": default,
+		});
+
+		if let Some(min) = self.min {
+			schema["minimum"] = serde_json::json!(min);
+		}
+
+		if let Some(max) = self.max {
+			schema["maximum"] = serde_json::json!(max);
+		}
+
+		schema
+	}
+}
+
+/// Returns JSON-Schema-like description of the indicator **Configuration** with the given `title` and `parameters`
+#[cfg(feature = "json_schema")]
+pub(super) fn json_schema(title: &str, parameters: &[Parameter]) -> serde_json::Value {
+	let properties: serde_json::Map<_, _> = parameters
+		.iter()
+		.map(|param| (param.name.to_string(), param.json_schema()))
+		.collect();
+	let required: Vec<_> = parameters.iter().map(|param| param.name).collect();
+
+	serde_json::json!({
+		"title": title,
+		"type": "object",
+		"properties": properties,
+		"required": required,
+		"additionalProperties": false,
+	})
+}
@@ -7,8 +7,17 @@ use serde::{Deserialize, Serialize};
 /// Every `Indicator` proceed an input of [`OHLCV`](crate::core::OHLCV) and returns an `IndicatorResult` which consist of some returned raw values and some calculated signals.
 ///
 /// `Indicator` may return up to 4 signals and 4 raw values at each step
+///
+/// When `serde` feature is enabled, `IndicatorResult` serializes only the actual values and signals without any padding:
+/// ```json
+/// {"values":[1.0],"signals":[{"Buy":255},{"Sell":255},"None"]}
+/// ```
 #[derive(Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(into = "IndicatorResultRepr", try_from = "IndicatorResultRepr")
+)]
 #[allow(clippy::use_self)]
 pub struct IndicatorResult {
 	signals: [Action; IndicatorResult::SIZE],
@@ -99,6 +108,140 @@ impl IndicatorResult {
 			length,
 		}
 	}
+
+	/// Compares current result with the `prev` one and returns a [`ResultDiff`] of changed raw values and signals
+	///
+	/// Raw value is considered changed when it differs from the previous one by more than [`ResultDiff::EPSILON`].
+	/// Signal is considered changed when it is not equal to the previous one.
+	///
+	/// See also [`diff_with_epsilon`](IndicatorResult::diff_with_epsilon).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::{Action, IndicatorResult};
+	///
+	/// let prev = IndicatorResult::new(&[1.0, 2.0], &[Action::None]);
+	/// let next = IndicatorResult::new(&[1.0, 2.5], &[Action::BUY_ALL]);
+	///
+	/// let diff = next.diff(&prev);
+	/// assert!(diff.changed_values().eq([1]));
+	/// assert!(diff.changed_signals().eq([0]));
+	/// ```
+	#[must_use]
+	pub fn diff(&self, prev: &Self) -> ResultDiff {
+		self.diff_with_epsilon(prev, ResultDiff::EPSILON)
+	}
+
+	/// The same as [`diff`](IndicatorResult::diff), but with custom `epsilon` tolerance for raw values
+	#[must_use]
+	pub fn diff_with_epsilon(&self, prev: &Self, epsilon: ValueType) -> ResultDiff {
+		let mut diff = ResultDiff::default();
+
+		let values_length = self.length.0.max(prev.length.0) as usize;
+		for index in 0..values_length {
+			let changed = match (self.values().get(index), prev.values().get(index)) {
+				(Some(&a), Some(&b)) => a.is_nan() != b.is_nan() || (a - b).abs() > epsilon,
+				_ => true,
+			};
+
+			diff.values[index] = changed;
+		}
+
+		let signals_length = self.length.1.max(prev.length.1) as usize;
+		for index in 0..signals_length {
+			diff.signals[index] = self.signals().get(index) != prev.signals().get(index);
+		}
+
+		diff
+	}
+}
+
+/// Represents a difference between two consecutive [`IndicatorResult`]s
+///
+/// Can be obtained using [`IndicatorResult::diff`].
+///
+/// If results have different count of raw values or signals, then all the indices present in only one of them are reported as changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResultDiff {
+	values: [bool; IndicatorResult::SIZE],
+	signals: [bool; IndicatorResult::SIZE],
+}
+
+impl ResultDiff {
+	/// Default tolerance for comparing raw values
+	pub const EPSILON: ValueType = 1e-10;
+
+	/// Returns an iterator over indices of changed raw values
+	pub fn changed_values(&self) -> impl Iterator<Item = usize> + '_ {
+		self.values
+			.iter()
+			.enumerate()
+			.filter_map(|(index, &changed)| changed.then_some(index))
+	}
+
+	/// Returns an iterator over indices of changed signals
+	pub fn changed_signals(&self) -> impl Iterator<Item = usize> + '_ {
+		self.signals
+			.iter()
+			.enumerate()
+			.filter_map(|(index, &changed)| changed.then_some(index))
+	}
+
+	/// Checks if raw value at given index has changed
+	#[must_use]
+	pub fn value_changed(&self, index: usize) -> bool {
+		self.values.get(index).copied().unwrap_or_default()
+	}
+
+	/// Checks if signal at given index has changed
+	#[must_use]
+	pub fn signal_changed(&self, index: usize) -> bool {
+		self.signals.get(index).copied().unwrap_or_default()
+	}
+
+	/// Checks if nothing has changed
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		!self.values.iter().chain(self.signals.iter()).any(|&x| x)
+	}
+}
+
+/// Serialization representation of [`IndicatorResult`] without padding
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct IndicatorResultRepr {
+	values: Vec<ValueType>,
+	signals: Vec<Action>,
+}
+
+#[cfg(feature = "serde")]
+impl From<IndicatorResult> for IndicatorResultRepr {
+	fn from(result: IndicatorResult) -> Self {
+		Self {
+			values: result.values().to_vec(),
+			signals: result.signals().to_vec(),
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<IndicatorResultRepr> for IndicatorResult {
+	type Error = String;
+
+	fn try_from(repr: IndicatorResultRepr) -> Result<Self, Self::Error> {
+		if repr.values.len() > Self::SIZE || repr.signals.len() > Self::SIZE {
+			return Err(format!(
+				"IndicatorResult may contain up to {} values and {} signals, got {} and {}",
+				Self::SIZE,
+				Self::SIZE,
+				repr.values.len(),
+				repr.signals.len()
+			));
+		}
+
+		Ok(Self::new(&repr.values, &repr.signals))
+	}
 }
 
 impl fmt::Debug for IndicatorResult {
@@ -123,3 +266,75 @@ impl fmt::Debug for IndicatorResult {
 		)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::IndicatorResult;
+	use crate::core::{Action, ValueType};
+	#[cfg(feature = "serde")]
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_indicator_result_serde() {
+		let result =
+			IndicatorResult::new(&[1.5], &[Action::BUY_ALL, Action::Sell(10), Action::None]);
+
+		let json = serde_json::to_string(&result).unwrap();
+		assert_eq!(
+			json,
+			r#"{"values":[1.5],"signals":[{"Buy":255},{"Sell":10},"None"]}"#
+		);
+
+		let restored: IndicatorResult = serde_json::from_str(&json).unwrap();
+		assert_eq!(restored.size(), (1, 3));
+		assert_eq_float(1.5, restored.value(0));
+		assert_eq!(restored.signals(), result.signals());
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_indicator_result_serde_too_long() {
+		let json = r#"{"values":[1.0,2.0,3.0,4.0,5.0],"signals":[]}"#;
+
+		assert!(serde_json::from_str::<IndicatorResult>(json).is_err());
+	}
+
+	#[test]
+	fn test_indicator_result_diff() {
+		let prev = IndicatorResult::new(
+			&[1.0, 2.0, 3.0],
+			&[Action::None, Action::BUY_ALL, Action::Sell(10)],
+		);
+		let next = IndicatorResult::new(
+			&[1.0, 2.0 + 1e-12, 3.5],
+			&[Action::BUY_ALL, Action::BUY_ALL, Action::Sell(20)],
+		);
+
+		let diff = next.diff(&prev);
+		assert!(!diff.is_empty());
+		assert!(diff.changed_values().eq([2]));
+		assert!(diff.changed_signals().eq([0, 2]));
+		assert!(!diff.value_changed(1));
+		assert!(diff.signal_changed(0));
+
+		assert!(next.diff(&next).is_empty());
+
+		let diff = next.diff_with_epsilon(&prev, 1.0);
+		assert_eq!(diff.changed_values().count(), 0);
+	}
+
+	#[test]
+	fn test_indicator_result_diff_lengths() {
+		let prev = IndicatorResult::new(&[1.0], &[]);
+		let next = IndicatorResult::new(&[1.0, ValueType::NAN], &[Action::None]);
+
+		let diff = next.diff(&prev);
+		assert!(diff.changed_values().eq([1]));
+		assert!(diff.changed_signals().eq([0]));
+
+		let nan = IndicatorResult::new(&[ValueType::NAN], &[]);
+		assert!(nan.diff(&nan).is_empty());
+		assert!(nan.diff(&IndicatorResult::new(&[1.0], &[])).value_changed(0));
+	}
+}
@@ -1,5 +1,8 @@
+#[cfg(feature = "strict")]
+use super::ValueType;
 use super::{Error, Sequence};
 use crate::helpers::{WithHistory, WithLastValue};
+use std::borrow::Borrow;
 use std::fmt;
 use std::mem::{align_of, size_of};
 
@@ -61,6 +64,31 @@ type BoxedFnMethod<'a, M> = Box<dyn FnMut(&'a <M as Method>::Input) -> <M as Met
 /// assert_eq!(s.as_slice(), &[1., 1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5, 9.5]);
 /// ```
 ///
+/// ### Output values should be used
+///
+/// [`Method::next`] is marked as `#[must_use]`, so dropping its output value produces a warning.
+/// If you need just to update method's state (f.e. while warming it up), explicitly ignore the output with `let _ =`:
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use yata::methods::SMA;
+/// use yata::prelude::*;
+///
+/// let mut ma = SMA::new(2, &1.0).unwrap();
+///
+/// ma.next(&2.0); // error: unused return value of `Method::next` that must be used
+/// ```
+///
+/// ```
+/// #![deny(unused_must_use)]
+/// use yata::methods::SMA;
+/// use yata::prelude::*;
+///
+/// let mut ma = SMA::new(2, &1.0).unwrap();
+///
+/// let _ = ma.next(&2.0);
+/// assert_eq!(ma.next(&3.0), 2.5);
+/// ```
+///
 /// # Be advised
 /// There is no `reset` method on the trait. If you need reset a state of the `Method` instance, you should just create a new one.
 pub trait Method {
@@ -77,8 +105,26 @@ pub trait Method {
 		Self: Sized;
 
 	/// Generates next output value based on the given input `value`
+	#[must_use = "method's output value should be used, otherwise just its state is updated"]
 	fn next(&mut self, value: &Self::Input) -> Self::Output;
 
+	/// Generates next output value based on the given input `value`, if the `value` is a finite number
+	///
+	/// Available only with `strict` feature.
+	///
+	/// When `value` is `NaN` or infinite, the state of the method stays untouched and [`Error::InvalidCandles`] is returned.
+	#[cfg(feature = "strict")]
+	fn next_checked(&mut self, value: &ValueType) -> Result<Self::Output, Error>
+	where
+		Self: Method<Input = ValueType> + Sized,
+	{
+		if value.is_finite() {
+			Ok(self.next(value))
+		} else {
+			Err(Error::InvalidCandles)
+		}
+	}
+
 	/// Creates an instance of the method with given `parameters` and initial `value`, wrapped by historical data holder
 	fn with_history(
 		parameters: Self::Params,
@@ -103,6 +149,28 @@ pub trait Method {
 		WithLastValue::new(parameters, initial_value)
 	}
 
+	/// Returns a human readable label of the method's output at the `index`, f.e. for plotting
+	///
+	/// Methods with a tuple output have a label for every element of the tuple.
+	/// Returns `None` when there is no output at the `index`.
+	///
+	/// Default implementation describes a single output named `"value"`.
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::methods::{CandleStats, SMA};
+	///
+	/// let sma = SMA::new(3, &1.0).unwrap();
+	/// assert_eq!(sma.output_name(0), Some("value"));
+	/// assert_eq!(sma.output_name(1), None);
+	///
+	/// let stats = CandleStats::new(3, &Candle::default()).unwrap();
+	/// assert_eq!(stats.output_name(2), Some("lower_shadow"));
+	/// ```
+	fn output_name(&self, index: usize) -> Option<&'static str> {
+		(index == 0).then_some("value")
+	}
+
 	/// Returns a name of the method
 	fn name(&self) -> &str {
 		let parts = std::any::type_name::<Self>().split("::");
@@ -154,6 +222,34 @@ pub trait Method {
 		inputs.call(self)
 	}
 
+	/// Converts the `Method` into a lazy iterator of output values over the given `inputs` iterator.
+	///
+	/// Unlike [`over`](Method::over) it does not collect anything, so it may be used over streaming data.
+	/// `inputs` may yield either values or references to values.
+	///
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let s = [1., 2., 3., 4., 5.];
+	/// let ma = SMA::new(2, &s[0]).unwrap();
+	///
+	/// let result: Vec<_> = ma.into_iter_over(s.iter().copied()).collect();
+	/// assert_eq!(result, [1., 1.5, 2.5, 3.5, 4.5]);
+	/// ```
+	#[inline]
+	fn into_iter_over<I>(self, inputs: I) -> MethodIter<Self, I::IntoIter>
+	where
+		I: IntoIterator,
+		I::Item: Borrow<Self::Input>,
+		Self: Sized,
+	{
+		MethodIter {
+			method: self,
+			inputs: inputs.into_iter(),
+		}
+	}
+
 	/// Applies method to the sequence in-place.
 	fn apply<T, S>(&mut self, sequence: &mut S)
 	where
@@ -163,6 +259,45 @@ pub trait Method {
 		sequence.apply(self);
 	}
 
+	/// Iterates the `Method` over the given `inputs` slice and writes output values into the caller-owned `out` buffer.
+	///
+	/// Unlike [`over`](Method::over) it does not allocate anything, so the same buffer may be reused for many batches.
+	///
+	/// Output value for `inputs[i]` is written to `out[i]`. When `out` is longer than `inputs`, the rest of `out` stays untouched.
+	///
+	/// # Panics
+	///
+	/// Panics if `out` is shorter than `inputs`. In this case none of the `inputs` is processed, so the method's state stays unchanged.
+	///
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let s = [1., 2., 3., 4., 5.];
+	/// let mut out = [0.; 5];
+	/// let mut ma = SMA::new(2, &s[0]).unwrap();
+	///
+	/// ma.process_into(&s, &mut out);
+	/// assert_eq!(out, [1., 1.5, 2.5, 3.5, 4.5]);
+	/// ```
+	#[inline]
+	fn process_into(&mut self, inputs: &[Self::Input], out: &mut [Self::Output])
+	where
+		Self::Input: Sized,
+		Self: Sized,
+	{
+		assert!(
+			out.len() >= inputs.len(),
+			"Output buffer is too small: {} < {}",
+			out.len(),
+			inputs.len()
+		);
+
+		for (input, output) in inputs.iter().zip(out.iter_mut()) {
+			*output = self.next(input);
+		}
+	}
+
 	/// Creates new `Method` instance and iterates it over the given `inputs` slice and returns `Vec` of output values.
 	///
 	/// # Guarantees
@@ -229,3 +364,183 @@ pub trait Method {
 		Ok(instance.into_fn())
 	}
 }
+
+/// Trait for methods which may buffer partial output
+///
+/// Some methods (f.e. [`CollapseTimeframe`](crate::methods::CollapseTimeframe) or [`Renko`](crate::methods::Renko))
+/// produce output only after several inputs, so at the end of the timeseries there may be some unfinished bar or brick.
+/// [`BufferedMethod::finalize`] flushes it.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::CollapseTimeframe;
+///
+/// let candles: [Candle; 3] = [
+///     (10.0, 15.0, 5.0, 12.0, 1000.0).into(),
+///     (12.1, 17.0, 6.0, 13.0, 2000.0).into(),
+///     (13.0, 14.0, 9.0, 11.0, 500.0).into(),
+/// ];
+///
+/// let mut collapser = CollapseTimeframe::new(2, &candles[0]).unwrap();
+/// let collapsed: Vec<_> = candles.iter().filter_map(|candle| collapser.next(candle)).collect();
+/// assert_eq!(collapsed.len(), 1);
+///
+/// // the last candle is still buffered
+/// let pending = collapser.finalize();
+/// assert_eq!(pending.len(), 1);
+/// assert_eq!(pending[0].unwrap().close(), 11.0);
+///
+/// assert!(collapser.finalize().is_empty());
+/// ```
+pub trait BufferedMethod: Method {
+	/// Flushes any pending partial output at the end of the timeseries
+	///
+	/// Returns an empty [`Vec`] if there is nothing pending. Pending output is flushed only once,
+	/// so the second call in a row always returns an empty [`Vec`].
+	fn finalize(&mut self) -> Vec<Self::Output>;
+}
+
+/// Lazy iterator of [`Method`] output values over the iterator of input values
+///
+/// Created by [`Method::into_iter_over`].
+#[derive(Debug, Clone)]
+pub struct MethodIter<M, I> {
+	method: M,
+	inputs: I,
+}
+
+impl<M, I> MethodIter<M, I> {
+	/// Returns a reference to the inner method
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+
+	/// Returns the inner method in its current state
+	pub fn into_method(self) -> M {
+		self.method
+	}
+}
+
+impl<M, I> Iterator for MethodIter<M, I>
+where
+	M: Method,
+	I: Iterator,
+	I::Item: Borrow<M::Input>,
+{
+	type Item = M::Output;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let input = self.inputs.next()?;
+		Some(self.method.next(input.borrow()))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inputs.size_hint()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Method;
+	use crate::core::{Action, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{Cross, EMA};
+
+	#[test]
+	fn test_process_into() {
+		let src: Vec<ValueType> = RandomCandles::new().take(300).map(|x| x.close).collect();
+
+		let mut method = EMA::new(10, &src[0]).unwrap();
+		let mut reference = EMA::new(10, &src[0]).unwrap();
+
+		let mut out = vec![ValueType::NAN; src.len() + 10];
+		method.process_into(&src, &mut out);
+
+		for (x, &value) in src.iter().zip(&out) {
+			assert_eq_float(reference.next(x), value);
+		}
+
+		assert!(out[src.len()..].iter().all(|x| x.is_nan()));
+	}
+
+	#[test]
+	fn test_process_into_non_float() {
+		let src: Vec<(ValueType, ValueType)> = RandomCandles::new()
+			.take(300)
+			.map(|x| (x.close, x.open))
+			.collect();
+
+		let mut method = Cross::new((), &src[0]).unwrap();
+		let mut reference = Cross::new((), &src[0]).unwrap();
+
+		let mut out = vec![Action::None; src.len()];
+		method.process_into(&src, &mut out);
+
+		for (x, &value) in src.iter().zip(&out) {
+			assert_eq!(reference.next(x), value);
+		}
+	}
+
+	#[test]
+	fn test_into_iter_over() {
+		let src: Vec<ValueType> = RandomCandles::new().take(300).map(|x| x.close).collect();
+
+		let method = EMA::new(10, &src[0]).unwrap();
+		let mut reference = EMA::new(10, &src[0]).unwrap();
+
+		let result: Vec<_> = method.into_iter_over(src.iter().copied()).collect();
+		assert_eq!(result.len(), src.len());
+
+		for (x, &value) in src.iter().zip(&result) {
+			assert_eq_float(reference.next(x), value);
+		}
+
+		// references are accepted too and the method state is kept
+		let mut iter = EMA::new(10, &src[0]).unwrap().into_iter_over(&src);
+		assert_eq!(iter.by_ref().take(100).count(), 100);
+		let mut reference = EMA::new(10, &src[0]).unwrap();
+		reference.over(&src[..100]);
+		assert_eq_float(reference.next(&src[100]), iter.next().unwrap());
+	}
+
+	#[test]
+	#[should_panic(expected = "Output buffer is too small")]
+	fn test_process_into_small_buffer() {
+		let src = [1.0, 2.0, 3.0];
+		let mut out = [0.0; 2];
+
+		let mut method = EMA::new(2, &src[0]).unwrap();
+		method.process_into(&src, &mut out);
+	}
+}
+
+#[cfg(all(test, feature = "strict"))]
+mod strict_tests {
+	use super::Method;
+	use crate::core::{Error, ValueType};
+	use crate::helpers::assert_eq_float;
+	use crate::methods::SMA;
+
+	#[test]
+	fn test_next_checked() {
+		let mut method = SMA::new(2, &1.0).unwrap();
+
+		assert_eq_float(2.0, method.next_checked(&3.0).unwrap());
+	}
+
+	#[test]
+	fn test_next_checked_nan() {
+		let mut method = SMA::new(2, &1.0).unwrap();
+
+		assert!(matches!(
+			method.next_checked(&ValueType::NAN),
+			Err(Error::InvalidCandles)
+		));
+		// state is not poisoned
+		assert_eq_float(1.0, method.next(&1.0));
+	}
+}
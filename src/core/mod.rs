@@ -10,16 +10,18 @@ mod method;
 mod moving_average;
 mod ohlcv;
 mod sequence;
+mod series;
 mod window;
 
 pub use action::Action;
 pub use candles::*;
 pub use errors::Error;
 pub use indicator::*;
-pub use method::Method;
+pub use method::{BufferedMethod, Method, MethodIter};
 pub use moving_average::*;
-pub use ohlcv::OHLCV;
+pub use ohlcv::{Timestamped, OHLCV};
 pub use sequence::*;
+pub use series::CandleSeries;
 pub use window::Window;
 
 /// Main value type for calculations
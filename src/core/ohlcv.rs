@@ -234,6 +234,29 @@ pub trait OHLCV: 'static {
 			&& (self.volume().is_nan() || self.volume() >= 0.0)
 	}
 
+	/// Checks if all the candle's values (including `volume`) are finite numbers (not `NaN` and not infinite)
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::prelude::*;
+	/// use yata::core::ValueType;
+	///
+	/// let candle = Candle { open: 1.0, high: 2.0, low: 0.5, close: 1.5, volume: 10.0 };
+	/// assert!(candle.is_finite());
+	///
+	/// let candle = Candle { close: ValueType::NAN, ..candle };
+	/// assert!(!candle.is_finite());
+	/// ```
+	#[inline]
+	fn is_finite(&self) -> bool {
+		self.open().is_finite()
+			&& self.high().is_finite()
+			&& self.low().is_finite()
+			&& self.close().is_finite()
+			&& self.volume().is_finite()
+	}
+
 	/// Returns [`Source`] field value of the candle.
 	///
 	/// # Examples
@@ -336,3 +359,56 @@ impl OHLCV for [ValueType; 5] {
 		self[4]
 	}
 }
+
+/// Trait for timeseries data items which have a timestamp
+///
+/// Units of the timestamp (seconds, milliseconds, etc...) are up to the user, but they must be the same for the whole timeseries.
+///
+/// It has already implemented for a tuple of a timestamp and any [`OHLCV`]:
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Timestamped;
+///
+/// //         timestamp  open high low  close volume
+/// let row = (60,       (2.0, 5.0, 1.0, 4.0,  10.0));
+/// assert_eq!(row.timestamp(), 60);
+/// assert_eq!(row.close(), 4.0);
+/// ```
+pub trait Timestamped {
+	/// Should return a timestamp of the item
+	fn timestamp(&self) -> i64;
+}
+
+impl<T> Timestamped for (i64, T) {
+	#[inline]
+	fn timestamp(&self) -> i64 {
+		self.0
+	}
+}
+
+impl<T: OHLCV> OHLCV for (i64, T) {
+	#[inline]
+	fn open(&self) -> ValueType {
+		self.1.open()
+	}
+
+	#[inline]
+	fn high(&self) -> ValueType {
+		self.1.high()
+	}
+
+	#[inline]
+	fn low(&self) -> ValueType {
+		self.1.low()
+	}
+
+	#[inline]
+	fn close(&self) -> ValueType {
+		self.1.close()
+	}
+
+	#[inline]
+	fn volume(&self) -> ValueType {
+		self.1.volume()
+	}
+}
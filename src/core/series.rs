@@ -0,0 +1,215 @@
+use crate::core::{Candle, Error, IndicatorConfig, IndicatorResult, Source, ValueType, OHLCV};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Series of candles with bulk operations over them
+///
+/// It is just a wrapper around `Vec` of candles, which helps to extract columns of values from candles
+/// and to evaluate indicators over the whole series.
+///
+/// Since it implements `AsRef<[T]>`, it is also a [`Sequence`](crate::core::Sequence).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::{CandleSeries, Source};
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::Trix;
+///
+/// let series: CandleSeries = RandomCandles::new().take(100).collect();
+///
+/// let closes = series.closes();
+/// let typical_prices = series.source(Source::TP);
+/// let results = series.run(Trix::default()).unwrap();
+///
+/// assert_eq!(closes.len(), 100);
+/// assert_eq!(typical_prices.len(), 100);
+/// assert_eq!(results.len(), 100);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandleSeries<T: OHLCV = Candle>(Vec<T>);
+
+impl<T: OHLCV> CandleSeries<T> {
+	/// Creates new empty series
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(Vec::new())
+	}
+
+	/// Returns count of candles in the series
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Checks if the series has no candles
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Appends the `candle` to the end of the series
+	pub fn push(&mut self, candle: T) {
+		self.0.push(candle);
+	}
+
+	/// Returns an iterator over candles of the series
+	pub fn iter(&self) -> std::slice::Iter<'_, T> {
+		self.0.iter()
+	}
+
+	/// Unwraps the series into the inner `Vec` of candles
+	#[must_use]
+	pub fn into_inner(self) -> Vec<T> {
+		self.0
+	}
+
+	/// Returns values of the given `source` for every candle of the series
+	#[must_use]
+	pub fn source(&self, source: Source) -> Vec<ValueType> {
+		self.0.iter().map(|candle| candle.source(source)).collect()
+	}
+
+	/// Returns `open` values of the series
+	#[must_use]
+	pub fn opens(&self) -> Vec<ValueType> {
+		self.0.iter().map(OHLCV::open).collect()
+	}
+
+	/// Returns `high` values of the series
+	#[must_use]
+	pub fn highs(&self) -> Vec<ValueType> {
+		self.0.iter().map(OHLCV::high).collect()
+	}
+
+	/// Returns `low` values of the series
+	#[must_use]
+	pub fn lows(&self) -> Vec<ValueType> {
+		self.0.iter().map(OHLCV::low).collect()
+	}
+
+	/// Returns `close` values of the series
+	#[must_use]
+	pub fn closes(&self) -> Vec<ValueType> {
+		self.0.iter().map(OHLCV::close).collect()
+	}
+
+	/// Returns `volume` values of the series
+	#[must_use]
+	pub fn volumes(&self) -> Vec<ValueType> {
+		self.0.iter().map(OHLCV::volume).collect()
+	}
+
+	/// Evaluates indicator `config` over the whole series and returns sequence of `IndicatorResult`s
+	///
+	/// The indicator is initialized on the first candle of the series.
+	/// Returns an empty `Vec` when the series is empty.
+	///
+	/// See also [`IndicatorConfig::over`].
+	pub fn run<C: IndicatorConfig>(&self, config: C) -> Result<Vec<IndicatorResult>, Error> {
+		config.over(&self.0)
+	}
+}
+
+impl<T: OHLCV> AsRef<[T]> for CandleSeries<T> {
+	fn as_ref(&self) -> &[T] {
+		&self.0
+	}
+}
+
+impl<T: OHLCV> AsMut<[T]> for CandleSeries<T> {
+	fn as_mut(&mut self) -> &mut [T] {
+		&mut self.0
+	}
+}
+
+impl<T: OHLCV> From<Vec<T>> for CandleSeries<T> {
+	fn from(candles: Vec<T>) -> Self {
+		Self(candles)
+	}
+}
+
+impl<T: OHLCV> FromIterator<T> for CandleSeries<T> {
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		Self(iter.into_iter().collect())
+	}
+}
+
+impl<T: OHLCV> IntoIterator for CandleSeries<T> {
+	type Item = T;
+	type IntoIter = std::vec::IntoIter<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, T: OHLCV> IntoIterator for &'a CandleSeries<T> {
+	type Item = &'a T;
+	type IntoIter = std::slice::Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CandleSeries;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, Source, OHLCV};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{MACD, RSI};
+
+	#[test]
+	fn test_candle_series_columns() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+		let series = CandleSeries::from(candles.clone());
+
+		assert_eq!(series.len(), 100);
+
+		let opens: Vec<_> = candles.iter().map(|c| c.open).collect();
+		let highs: Vec<_> = candles.iter().map(|c| c.high).collect();
+		let lows: Vec<_> = candles.iter().map(|c| c.low).collect();
+		let closes: Vec<_> = candles.iter().map(|c| c.close).collect();
+		let volumes: Vec<_> = candles.iter().map(|c| c.volume).collect();
+		let tps: Vec<_> = candles.iter().map(OHLCV::tp).collect();
+
+		assert_eq!(series.opens(), opens);
+		assert_eq!(series.highs(), highs);
+		assert_eq!(series.lows(), lows);
+		assert_eq!(series.closes(), closes);
+		assert_eq!(series.volumes(), volumes);
+
+		assert_eq!(series.source(Source::Open), opens);
+		assert_eq!(series.source(Source::High), highs);
+		assert_eq!(series.source(Source::Low), lows);
+		assert_eq!(series.source(Source::Close), closes);
+		assert_eq!(series.source(Source::Volume), volumes);
+		assert_eq!(series.source(Source::TP), tps);
+
+		assert_eq!(series.into_inner(), candles);
+	}
+
+	#[test]
+	fn test_candle_series_run() {
+		let series: CandleSeries = RandomCandles::new().take(200).collect();
+
+		let results = series.run(MACD::default()).unwrap();
+		let mut instance = MACD::default().init(&series.as_ref()[0]).unwrap();
+		let expected: Vec<_> = series.iter().map(|c| instance.next(c)).collect();
+		assert_eq!(format!("{results:?}"), format!("{expected:?}"));
+
+		let results = series.run(RSI::default()).unwrap();
+		let mut instance = RSI::default().init(&series.as_ref()[0]).unwrap();
+		let expected: Vec<_> = series.iter().map(|c| instance.next(c)).collect();
+		assert_eq!(format!("{results:?}"), format!("{expected:?}"));
+
+		assert!(CandleSeries::<Candle>::new()
+			.run(MACD::default())
+			.unwrap()
+			.is_empty());
+	}
+}
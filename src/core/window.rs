@@ -59,6 +59,7 @@ pub struct Window<T> {
 	index: PeriodType,
 	size: PeriodType,
 	s_1: PeriodType,
+	filled: PeriodType,
 }
 
 impl<T> Window<T> {
@@ -80,6 +81,7 @@ impl<T> Window<T> {
 			index: 0,
 			size,
 			s_1: size.saturating_sub(1),
+			filled: 0,
 		}
 	}
 
@@ -92,6 +94,8 @@ impl<T> Window<T> {
 	/// This method will panic if length of the slice is greater or equal to [`PeriodType::MAX`].
 	/// This method will also panic if provided `index` is greater or equal to slice's length.
 	///
+	/// The `Window` created this way is considered to be already full (see [`Window::push_checked`]).
+	///
 	/// [`PeriodType::MAX`]: crate::core::PeriodType
 	#[must_use]
 	pub fn from_parts(slice: Box<[T]>, index: PeriodType) -> Self {
@@ -111,6 +115,7 @@ impl<T> Window<T> {
 			index,
 			size,
 			s_1: size.saturating_sub(1),
+			filled: size,
 		}
 	}
 
@@ -122,6 +127,54 @@ impl<T> Window<T> {
 			index: 0,
 			size: 0,
 			s_1: 0,
+			filled: 0,
+		}
+	}
+
+	/// Overwrites every `Window`'s slot with `value` and resets the head without reallocating.
+	///
+	/// After that the `Window` is considered to be warming up again (see [`Window::push_checked`]).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 1);
+	///
+	/// w.push(2);
+	/// w.push(3);
+	/// w.fill(7);
+	///
+	/// let p: Vec<_> = w.iter().copied().collect();
+	/// assert_eq!(p, [7, 7, 7]);
+	/// ```
+	pub fn fill(&mut self, value: T)
+	where
+		T: Clone,
+	{
+		self.buf.fill(value);
+		self.index = 0;
+		self.filled = 0;
+	}
+
+	/// Re-seeds the `Window` with `value`, changing it's size to `length` if needed.
+	///
+	/// The buffer is reallocated only if `length` differs from the current `Window`'s length.
+	///
+	/// # Panics
+	///
+	/// When in development mode, this method may panic if `length` is equal to [`PeriodType::MAX`]
+	///
+	/// [`PeriodType::MAX`]: crate::core::PeriodType
+	pub fn reset_with(&mut self, length: PeriodType, value: T)
+	where
+		T: Clone,
+	{
+		if length == self.size {
+			self.fill(value);
+		} else {
+			*self = Self::new(length, value);
 		}
 	}
 
@@ -155,6 +208,51 @@ impl<T> Window<T> {
 		old_value
 	}
 
+	/// Pushes the `value` into the `Window` and reports the `Window`'s capacity state.
+	///
+	/// Only values pushed by this method are counted to fill the `Window`, so it should not be mixed with [`Window::push`].
+	///
+	/// Returns a tuple of:
+	/// * the genuinely evicted value: `None` while the `Window` is warming up
+	///   (initial seed values are not considered as evicted ones);
+	/// * `true` if the `Window` is full after the push.
+	///
+	/// # Panics
+	///
+	/// This method panics if try to push into empty `Window` (when `size` = `0`).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use yata::core::Window;
+	///
+	/// let mut w = Window::new(3, 0);
+	///
+	/// assert_eq!(w.push_checked(1), (None, false));
+	/// assert_eq!(w.push_checked(2), (None, false));
+	/// assert_eq!(w.push_checked(3), (None, true));
+	/// assert_eq!(w.push_checked(4), (Some(1), true));
+	/// assert_eq!(w.push_checked(5), (Some(2), true));
+	/// ```
+	#[inline]
+	pub fn push_checked(&mut self, value: T) -> (Option<T>, bool) {
+		let was_full = self.is_full();
+		let old_value = self.push(value);
+		self.filled += (!was_full) as PeriodType;
+
+		(was_full.then_some(old_value), self.is_full())
+	}
+
+	/// Checks if the `Window` is full: there were at least `length` values pushed by [`Window::push_checked`] since it's creation
+	/// (or since the last [`fill`](Window::fill)).
+	///
+	/// `Window`s created by [`Window::from_parts`] or deserialized ones are always considered full.
+	#[must_use]
+	#[inline]
+	pub const fn is_full(&self) -> bool {
+		self.filled == self.size
+	}
+
 	/// Returns an iterator over the `Window`'s values (by copy) (from the newest to the oldest).
 	///
 	/// # Examples
@@ -304,9 +402,9 @@ impl<T> std::ops::Index<PeriodType> for Window<T> {
 	type Output = T;
 
 	fn index(&self, index: PeriodType) -> &Self::Output {
-		let buf_index = self
-			.slice_index(index)
-			.unwrap_or_else(|| panic!("Window index {index} is out of range")) as usize;
+		let buf_index =
+			self.slice_index(index)
+				.unwrap_or_else(|| panic!("Window index {index} is out of range")) as usize;
 
 		if cfg!(feature = "unsafe_performance") {
 			unsafe { self.buf.get_unchecked(buf_index) }
@@ -470,6 +568,25 @@ struct SerializableWindow<T> {
 	index: PeriodType,
 }
 
+/// Serializes `Window`'s values in the logical order (from the oldest value to the newest) regardless of the internal buffer layout
+#[cfg(feature = "serde")]
+struct LogicalOrder<'a, T>(&'a Window<T>);
+
+#[cfg(feature = "serde")]
+impl<T> Serialize for LogicalOrder<'_, T>
+where
+	T: Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_seq(self.0.iter_rev())
+	}
+}
+
+/// `Window` is serialized as its values in the logical order (from the oldest value to the newest) with zero `index`,
+/// so the deserialized `Window` is a fresh ring which iterates identically to the original one.
 #[cfg(feature = "serde")]
 impl<T> Serialize for Window<T>
 where
@@ -479,9 +596,11 @@ where
 	where
 		S: Serializer,
 	{
+		let index: PeriodType = 0;
+
 		let mut s = serializer.serialize_struct("Window", 2)?;
-		s.serialize_field("buf", &self.buf)?;
-		s.serialize_field("index", &self.index)?;
+		s.serialize_field("buf", &LogicalOrder(self))?;
+		s.serialize_field("index", &index)?;
 		s.end()
 	}
 }
@@ -503,6 +622,10 @@ where
 		let buf = w.buf;
 		let index = w.index;
 
+		if buf.is_empty() && index == 0 {
+			return Ok(Self::empty());
+		}
+
 		if buf.len() > PeriodType::MAX as usize - 1 {
 			let max_length = PeriodType::MAX as usize - 1;
 			let error = SerdeError::custom(format!(
@@ -637,6 +760,98 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_fill() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+		let seed = RandomCandles::new().nth(500).unwrap();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+
+			for &c in &data[..(length as usize)] {
+				w.push(c);
+			}
+
+			w.fill(seed);
+
+			assert_eq!(w.len(), length);
+			assert!(w.iter().all(|&x| x == seed));
+			assert_eq!(*w.newest(), seed);
+			assert_eq!(*w.oldest(), seed);
+
+			for &c in &data[..(length as usize)] {
+				assert_eq!(w.push(c), seed);
+			}
+		}
+	}
+
+	#[test]
+	fn test_reset_with() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+		let seed = RandomCandles::new().nth(500).unwrap();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+			for &c in &data {
+				w.push(c);
+			}
+
+			let new_length = 255 - length;
+			w.reset_with(new_length, seed);
+
+			assert_eq!(w.len(), new_length);
+			assert_eq!(w.iter().count(), new_length as usize);
+			assert!(w.iter().all(|&x| x == seed));
+
+			w.reset_with(new_length, data[0]);
+			assert!(w.iter().all(|&x| x == data[0]));
+		}
+	}
+
+	#[test]
+	fn test_push_checked() {
+		let data: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for length in 1..255 {
+			let mut w = Window::new(length, data[0]);
+			assert!(!w.is_full());
+
+			data.iter().enumerate().for_each(|(i, &c)| {
+				let (evicted, full) = w.push_checked(c);
+
+				assert_eq!(full, i + 1 >= length as usize);
+				assert_eq!(full, w.is_full());
+
+				if i < length as usize {
+					assert_eq!(evicted, None);
+				} else {
+					assert_eq!(evicted, Some(data[i - length as usize]));
+				}
+			});
+
+			w.fill(data[0]);
+			assert!(!w.is_full());
+			assert_eq!(w.push_checked(data[1]), (None, length == 1));
+		}
+	}
+
+	#[test]
+	fn test_push_checked_mixed() {
+		let mut w = Window::new(3, 0);
+
+		// values pushed by `push` are not counted
+		w.push(1);
+		assert_eq!(w.push_checked(2), (None, false));
+		w.push(3);
+		assert!(!w.is_full());
+		assert_eq!(w.push_checked(4), (None, false));
+		assert_eq!(w.push_checked(5), (None, true));
+		assert_eq!(w.push_checked(6), (Some(3), true));
+
+		let w2 = Window::from(vec![1, 2, 3]);
+		assert!(w2.is_full());
+	}
+
 	#[test]
 	fn test_index() {
 		let data: Vec<_> = RandomCandles::new().take(300).collect();
@@ -659,4 +874,55 @@ mod tests {
 			});
 		}
 	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_serde_logical_order() {
+		for length in 1..20 {
+			for pushes in 0..50 {
+				let mut w = Window::new(length, 0);
+
+				for i in 1..=pushes {
+					w.push(i);
+				}
+
+				let json = serde_json::to_string(&w).unwrap();
+				let mut restored: Window<i32> = serde_json::from_str(&json).unwrap();
+
+				assert!(w.iter().eq(restored.iter()));
+				assert!(w.iter_rev().eq(restored.iter_rev()));
+				assert!(restored.is_full());
+
+				// both windows keep working identically after the round-trip
+				for i in 100..130 {
+					assert_eq!(w.push(i), restored.push(i));
+				}
+
+				assert!(w.iter().eq(restored.iter()));
+			}
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_serde_format() {
+		let mut w = Window::new(3, 0);
+		w.push(1);
+		w.push(2);
+		w.push(3);
+		w.push(4);
+
+		assert_eq!(
+			serde_json::to_string(&w).unwrap(),
+			r#"{"buf":[2,3,4],"index":0}"#
+		);
+
+		// raw buffer layout with non-zero index is still supported
+		let restored: Window<i32> = serde_json::from_str(r#"{"buf":[4,2,3],"index":1}"#).unwrap();
+		assert!(w.iter().eq(restored.iter()));
+
+		let empty: Window<i32> =
+			serde_json::from_str(&serde_json::to_string(&Window::<i32>::empty()).unwrap()).unwrap();
+		assert_eq!(empty.len(), 0);
+	}
 }
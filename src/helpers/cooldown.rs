@@ -0,0 +1,186 @@
+use crate::core::{Action, Error, IndicatorInstance, IndicatorResult, PeriodType, OHLCV};
+
+/// Signal cooldown wrapper for indicator instances preventing over-trading
+///
+/// Calls inner [`IndicatorInstance::next`] on every candle. After the signal at the `signal` index passes,
+/// any further signals of the same direction are suppressed (replaced with [`Action::None`]) for the next `bars` candles.
+///
+/// Signals of the opposite direction are never suppressed. When such signal passes, cooldown restarts for its direction.
+/// Suppressed signals do not prolong the cooldown. Other signals and all the values are returned as is.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{Cooldown, RandomCandles};
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let instance = MACD::default().init(&candles[0]).unwrap();
+///
+/// // no repeated signal line crosses of the same direction within 5 candles
+/// let mut cooldown = Cooldown::new(instance, 0, 5).unwrap();
+///
+/// for candle in &candles {
+///     println!("{:?}", cooldown.next(candle));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cooldown<I: IndicatorInstance> {
+	instance: I,
+	signal: usize,
+	bars: PeriodType,
+	remaining: PeriodType,
+	direction: i8,
+}
+
+impl<I: IndicatorInstance> Cooldown<I> {
+	/// Creates new `Cooldown` wrapper over the indicator `instance` for the signal at the `signal` index
+	///
+	/// `bars` must be > `0`. `instance` should have the signal at the `signal` index.
+	pub fn new(instance: I, signal: usize, bars: PeriodType) -> Result<Self, Error> {
+		if bars == 0 || signal >= instance.size().1 as usize {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			instance,
+			signal,
+			bars,
+			remaining: 0,
+			direction: 0,
+		})
+	}
+
+	/// Advances inner indicator instance and returns its result with repeated signals suppressed during the cooldown
+	pub fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let result = self.instance.next(candle);
+		let direction = result.signal(self.signal).analog();
+
+		let in_cooldown = self.remaining > 0;
+		self.remaining = self.remaining.saturating_sub(1);
+
+		if direction == 0 {
+			return result;
+		}
+
+		if in_cooldown && direction == self.direction {
+			let mut signals = result.signals().to_vec();
+			signals[self.signal] = Action::None;
+
+			return IndicatorResult::new(result.values(), &signals);
+		}
+
+		self.direction = direction;
+		self.remaining = self.bars;
+
+		result
+	}
+
+	/// Returns count of the next candles, during which signals of the last passed direction are suppressed
+	pub const fn remaining(&self) -> PeriodType {
+		self.remaining
+	}
+
+	/// Returns a reference to the inner indicator instance
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Returns the inner indicator instance in its current state
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Cooldown;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{ElderImpulse, MACD};
+
+	#[test]
+	fn test_cooldown_repeated_signals() {
+		let candles: Vec<_> = (0..200)
+			.map(|x| {
+				let close = ValueType::powi(1.05, x);
+				Candle {
+					open: close,
+					high: close,
+					low: close,
+					close,
+					volume: 1.0,
+				}
+			})
+			.collect();
+
+		for bars in [1, 2, 5, 10] {
+			let mut instance = ElderImpulse::default().init(&candles[0]).unwrap();
+
+			// warm up, so the indicator returns buy signal on every candle of the uptrend
+			for candle in &candles[..50] {
+				let _ = instance.next(candle);
+			}
+
+			let mut cooldown = Cooldown::new(instance, 0, bars).unwrap();
+
+			for (i, candle) in candles[50..].iter().enumerate() {
+				let signal = cooldown.next(candle).signal(0);
+
+				if i % (bars as usize + 1) == 0 {
+					assert_eq!(signal, Action::BUY_ALL, "bars={bars}, i={i}");
+				} else {
+					assert_eq!(signal, Action::None, "bars={bars}, i={i}");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_cooldown() {
+		let candles: Vec<_> = RandomCandles::new().take(500).collect();
+
+		for bars in [1, 3, 7, 20] {
+			let mut full = MACD::default().init(&candles[0]).unwrap();
+			let mut cooldown =
+				Cooldown::new(MACD::default().init(&candles[0]).unwrap(), 1, bars).unwrap();
+
+			let mut last_passed: Option<(usize, i8)> = None;
+
+			for (i, candle) in candles.iter().enumerate() {
+				let expected = full.next(candle);
+				let result = cooldown.next(candle);
+
+				// values and other signals stay untouched
+				assert_eq!(expected.values(), result.values());
+				assert_eq!(expected.signal(0), result.signal(0));
+
+				let direction = expected.signal(1).analog();
+				let suppressed = matches!(last_passed, Some((index, last)) if last == direction && i - index <= bars as usize);
+
+				if direction != 0 && !suppressed {
+					last_passed = Some((i, direction));
+					assert_eq!(result.signal(1), expected.signal(1));
+				} else if direction != 0 {
+					assert_eq!(result.signal(1), Action::None);
+				} else {
+					assert!(result.signal(1).is_none());
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_cooldown_wrong_params() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+		let instance = MACD::default().init(&candles[0]).unwrap();
+
+		assert!(Cooldown::new(instance.clone(), 0, 0).is_err());
+		assert!(Cooldown::new(instance.clone(), 2, 5).is_err());
+
+		let cooldown = Cooldown::new(instance, 1, 5).unwrap();
+		assert_eq!(cooldown.remaining(), 0);
+		assert_eq!(cooldown.instance().config().signal, MACD::default().signal);
+	}
+}
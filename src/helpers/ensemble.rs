@@ -0,0 +1,224 @@
+use crate::core::{Action, Error, IndicatorInstanceDyn, OHLCV};
+
+/// Combines signals of several indicators into a single consensus signal
+///
+/// Every candle is fed to all the indicators. Then the signal at the `signal` index of every indicator is tallied:
+/// * when at least `threshold` indicators return buy signals and there are more buy signals than sell ones, returns full buy signal;
+/// * when at least `threshold` indicators return sell signals and there are more sell signals than buy ones, returns full sell signal;
+/// * otherwise returns no signal.
+///
+/// Only the direction of each signal matters, not its strength.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::prelude::dd::*;
+/// use yata::helpers::{Ensemble, RandomCandles};
+/// use yata::indicators::{MACD, PercentagePriceOscillator, Trix};
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let configs: [Box<dyn IndicatorConfigDyn<Candle>>; 3] = [
+///     Box::new(MACD::default()),
+///     Box::new(PercentagePriceOscillator::default()),
+///     Box::new(Trix::default()),
+/// ];
+///
+/// let indicators = configs
+///     .iter()
+///     .map(|config| config.init(&candles[0]))
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// // at least 2 of 3 indicators must agree on their first signal
+/// let mut ensemble = Ensemble::new(indicators, 0, 2).unwrap();
+///
+/// for candle in &candles {
+///     println!("{:?}", ensemble.next(candle));
+/// }
+/// ```
+pub struct Ensemble<T: OHLCV> {
+	indicators: Vec<Box<dyn IndicatorInstanceDyn<T>>>,
+	signal: usize,
+	threshold: usize,
+}
+
+impl<T: OHLCV> Ensemble<T> {
+	/// Creates new `Ensemble` over the `indicators`, which tallies the signal at the `signal` index of every indicator
+	///
+	/// `threshold` is the minimum count of agreeing indicators to produce a signal and should be in \[`1`; count of `indicators`\].
+	/// Every indicator should have the signal at the `signal` index.
+	pub fn new(
+		indicators: Vec<Box<dyn IndicatorInstanceDyn<T>>>,
+		signal: usize,
+		threshold: usize,
+	) -> Result<Self, Error> {
+		if threshold == 0 || threshold > indicators.len() {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		if indicators
+			.iter()
+			.any(|indicator| signal >= indicator.size().1 as usize)
+		{
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			indicators,
+			signal,
+			threshold,
+		})
+	}
+
+	/// Returns the indicators of the ensemble
+	#[must_use]
+	pub fn indicators(&self) -> &[Box<dyn IndicatorInstanceDyn<T>>] {
+		&self.indicators
+	}
+
+	/// Returns the minimum count of agreeing indicators to produce a signal
+	#[must_use]
+	pub const fn threshold(&self) -> usize {
+		self.threshold
+	}
+
+	/// Evaluates all the indicators over the `candle` and returns the consensus signal
+	#[must_use = "consensus signal should be used, otherwise just indicators' states are updated"]
+	pub fn next(&mut self, candle: &T) -> Action {
+		let (mut buys, mut sells) = (0, 0);
+
+		for indicator in &mut self.indicators {
+			let action = indicator.next(candle).signal(self.signal);
+
+			if action.is_buy() {
+				buys += 1;
+			} else if action.is_sell() {
+				sells += 1;
+			}
+		}
+
+		if buys >= self.threshold && buys > sells {
+			Action::BUY_ALL
+		} else if sells >= self.threshold && sells > buys {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		}
+	}
+}
+
+impl<T: OHLCV> std::fmt::Debug for Ensemble<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let names: Vec<_> = self
+			.indicators
+			.iter()
+			.map(|indicator| indicator.name())
+			.collect();
+
+		f.debug_struct("Ensemble")
+			.field("indicators", &names)
+			.field("signal", &self.signal)
+			.field("threshold", &self.threshold)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Ensemble;
+	use crate::core::{
+		Action, Candle, Error, IndicatorConfig, IndicatorInstance, IndicatorInstanceDyn,
+		IndicatorResult, ValueType, OHLCV,
+	};
+	use crate::helpers::sign;
+
+	/// Buys when close is above the `level` and sells when it is below
+	#[derive(Debug, Clone, Copy)]
+	struct Stub {
+		level: ValueType,
+	}
+
+	impl IndicatorConfig for Stub {
+		type Instance = Self;
+
+		const NAME: &'static str = "Stub";
+
+		fn init<T: OHLCV>(self, _: &T) -> Result<Self::Instance, Error> {
+			Ok(self)
+		}
+
+		fn validate(&self) -> bool {
+			true
+		}
+
+		fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+			Err(Error::ParameterParse(name.to_string(), value))
+		}
+
+		fn size(&self) -> (u8, u8) {
+			(0, 1)
+		}
+	}
+
+	impl IndicatorInstance for Stub {
+		type Config = Self;
+
+		fn config(&self) -> &Self::Config {
+			self
+		}
+
+		fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+			let signal = Action::from(sign(candle.close() - self.level));
+
+			IndicatorResult::new(&[], &[signal])
+		}
+	}
+
+	fn stubs_ensemble(threshold: usize) -> Result<Ensemble<Candle>, Error> {
+		let indicators: Vec<Box<dyn IndicatorInstanceDyn<Candle>>> = vec![
+			Box::new(Stub { level: 1.0 }),
+			Box::new(Stub { level: 2.0 }),
+			Box::new(Stub { level: 3.0 }),
+		];
+
+		Ensemble::new(indicators, 0, threshold)
+	}
+
+	fn candle(close: ValueType) -> Candle {
+		Candle {
+			close,
+			..Candle::default()
+		}
+	}
+
+	#[test]
+	fn test_ensemble() {
+		let mut ensemble = stubs_ensemble(2).unwrap();
+
+		// 2 of 3 buy
+		assert_eq!(ensemble.next(&candle(2.5)), Action::BUY_ALL);
+		// 2 of 3 sell
+		assert_eq!(ensemble.next(&candle(1.5)), Action::SELL_ALL);
+		// all buy
+		assert_eq!(ensemble.next(&candle(3.5)), Action::BUY_ALL);
+		// 1 buy, 1 sell, 1 neutral
+		assert_eq!(ensemble.next(&candle(2.0)), Action::None);
+
+		let mut ensemble = stubs_ensemble(3).unwrap();
+
+		assert_eq!(ensemble.next(&candle(2.5)), Action::None);
+		assert_eq!(ensemble.next(&candle(3.5)), Action::BUY_ALL);
+		assert_eq!(ensemble.next(&candle(0.5)), Action::SELL_ALL);
+	}
+
+	#[test]
+	fn test_ensemble_wrong_parameters() {
+		assert!(stubs_ensemble(0).is_err());
+		assert!(stubs_ensemble(4).is_err());
+
+		let indicators: Vec<Box<dyn IndicatorInstanceDyn<Candle>>> =
+			vec![Box::new(Stub { level: 1.0 })];
+		assert!(Ensemble::new(indicators, 1, 1).is_err());
+	}
+}
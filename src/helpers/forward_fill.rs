@@ -0,0 +1,163 @@
+use crate::core::{Candle, Error, Timestamped, OHLCV};
+
+/// Iterator adapter which fills the gaps in timestamped candles sequence
+///
+/// When some of the expected candles are missing (timestamp difference between two consecutive candles is more than `interval`),
+/// it inserts synthetic flat candles for every missing slot. Every synthetic candle has `open`, `high`, `low` and `close` equal to the
+/// previous candle's `close` and `volume` equal to `0.0`.
+///
+/// Produces pairs of a timestamp and a [`Candle`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::ForwardFill;
+///
+/// let candles = [
+///     (0, Candle { close: 1.0, ..Candle::default() }),
+///     (60, Candle { close: 2.0, ..Candle::default() }),
+///     // candle with timestamp 120 is missing
+///     (180, Candle { close: 3.0, ..Candle::default() }),
+/// ];
+///
+/// let filled: Vec<_> = ForwardFill::new(candles.into_iter(), 60).unwrap().collect();
+///
+/// assert_eq!(filled.len(), 4);
+/// assert_eq!(filled[2].0, 120);
+/// assert_eq!(filled[2].1.close(), 2.0);
+/// assert_eq!(filled[2].1.volume(), 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ForwardFill<I, T>
+where
+	I: Iterator<Item = T>,
+	T: OHLCV + Timestamped,
+{
+	iter: I,
+	interval: i64,
+	last: Option<(i64, Candle)>,
+	pending: Option<T>,
+}
+
+impl<I, T> ForwardFill<I, T>
+where
+	I: Iterator<Item = T>,
+	T: OHLCV + Timestamped,
+{
+	/// Creates new `ForwardFill` adapter over the `iter` with the expected `interval` between candles' timestamps
+	///
+	/// `interval` must be > `0`
+	pub fn new(iter: I, interval: i64) -> Result<Self, Error> {
+		if interval <= 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			iter,
+			interval,
+			last: None,
+			pending: None,
+		})
+	}
+}
+
+impl<I, T> Iterator for ForwardFill<I, T>
+where
+	I: Iterator<Item = T>,
+	T: OHLCV + Timestamped,
+{
+	type Item = (i64, Candle);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let candle = match self.pending.take() {
+			Some(candle) => candle,
+			None => self.iter.next()?,
+		};
+
+		let item = match self.last {
+			Some((timestamp, last)) if timestamp + self.interval < candle.timestamp() => {
+				self.pending = Some(candle);
+
+				let close = last.close;
+				(
+					timestamp + self.interval,
+					Candle {
+						open: close,
+						high: close,
+						low: close,
+						close,
+						volume: 0.0,
+					},
+				)
+			}
+			_ => (candle.timestamp(), Candle::from(&candle)),
+		};
+
+		self.last = Some(item);
+
+		Some(item)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ForwardFill;
+	use crate::core::{Candle, OHLCV};
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_forward_fill_no_gaps() {
+		let candles: Vec<(i64, Candle)> = RandomCandles::new()
+			.take(100)
+			.zip((0..).map(|x| x * 60))
+			.map(|(candle, timestamp)| (timestamp, candle))
+			.collect();
+
+		let filled: Vec<_> = ForwardFill::new(candles.iter().copied(), 60)
+			.unwrap()
+			.collect();
+
+		assert_eq!(candles, filled);
+	}
+
+	#[test]
+	fn test_forward_fill_one_bar_gap() {
+		let candles: Vec<(i64, Candle)> = RandomCandles::new()
+			.take(10)
+			.zip([0, 60, 120, 180, 240, 360, 420, 480, 540, 600])
+			.map(|(candle, timestamp)| (timestamp, candle))
+			.collect();
+
+		let filled: Vec<_> = ForwardFill::new(candles.iter().copied(), 60)
+			.unwrap()
+			.collect();
+
+		assert_eq!(filled.len(), candles.len() + 1);
+		assert_eq!(&filled[..5], &candles[..5]);
+		assert_eq!(&filled[6..], &candles[5..]);
+
+		let (timestamp, synthetic) = filled[5];
+		let close = candles[4].1.close();
+
+		assert_eq!(timestamp, 300);
+		assert_eq!(
+			synthetic,
+			Candle {
+				open: close,
+				high: close,
+				low: close,
+				close,
+				volume: 0.0,
+			}
+		);
+	}
+
+	#[test]
+	fn test_forward_fill_wrong_interval() {
+		let candles: [(i64, Candle); 0] = [];
+
+		assert!(ForwardFill::new(candles.into_iter(), 0).is_err());
+		assert!(ForwardFill::new(candles.into_iter(), -60).is_err());
+	}
+}
@@ -0,0 +1,196 @@
+use crate::core::{Candle, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType, OHLCV};
+
+/// Price multipliers applied to the perturbed candle: one sharp rise and one sharp drop
+const PERTURBATIONS: [ValueType; 2] = [1.5, 0.5];
+
+/// Checks that indicator's results never depend on the candles which come after them (look-ahead bias)
+///
+/// First evaluates the indicator `cfg` over the `candles` one by one with [`IndicatorInstance::next`].
+/// Then evaluates it over every prefix of the `candles` using [`IndicatorInstance::next_batch`]
+/// with the last candle of the prefix perturbed (all the prices are scaled up and then down, the volume is doubled)
+/// and asserts that the results for all the previous candles are exactly the same as the ones of the first run.
+///
+/// Every run starts from an instance initialized with the original first candle, so the runs differ only
+/// by the single perturbed candle (the first one included).
+///
+/// Every prefix is evaluated from scratch, so the check takes `O(n^2)` time. A few hundreds of candles are usually enough.
+///
+/// The check can't detect:
+/// * results, which depend on the candle they are calculated for in a way that is not achievable in real time
+///   (e.g. a signal for the bar's `open` based on the same bar's `close`);
+/// * look-ahead, which is already baked into the `candles` (e.g. misaligned or repainted candles);
+/// * dependencies on the future candles which are not affected by the perturbations (e.g. on the count of candles only);
+/// * any state shared between separate indicator instances.
+///
+/// # Panics
+///
+/// Panics if indicator cannot be initialized with the provided `cfg` or if any result depends on the future candles.
+/// The panic message reports the index of the changed result, indices of its changed raw values and signals and the index of the perturbed candle.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::{assert_no_lookahead, RandomCandles};
+/// use yata::indicators::PivotReversalStrategy;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+///
+/// assert_no_lookahead(&PivotReversalStrategy::default(), &candles);
+/// ```
+pub fn assert_no_lookahead<T: OHLCV, C: IndicatorConfig>(cfg: &C, candles: &[T]) {
+	let mut candles: Vec<Candle> = candles.iter().map(Candle::from).collect();
+	let Some(&init) = candles.first() else {
+		return;
+	};
+
+	let mut state = init_instance(cfg, &init);
+	let expected: Vec<_> = candles.iter().map(|candle| state.next(candle)).collect();
+
+	for index in 0..candles.len() {
+		let original = candles[index];
+
+		for multiplier in PERTURBATIONS {
+			candles[index] = perturb(&original, multiplier);
+
+			let results = init_instance(cfg, &init).next_batch(&candles[..=index]);
+
+			for (i, (result, expected)) in results.iter().zip(&expected[..index]).enumerate() {
+				check_result(result, expected, i, index);
+			}
+		}
+
+		candles[index] = original;
+	}
+}
+
+fn init_instance<C: IndicatorConfig>(cfg: &C, candle: &Candle) -> C::Instance {
+	cfg.clone()
+		.init(candle)
+		.expect("Unable to initialize indicator")
+}
+
+fn perturb(candle: &Candle, multiplier: ValueType) -> Candle {
+	Candle {
+		open: candle.open * multiplier,
+		high: candle.high * multiplier,
+		low: candle.low * multiplier,
+		close: candle.close * multiplier,
+		volume: candle.volume * 2.0,
+	}
+}
+
+fn check_result(
+	result: &IndicatorResult,
+	expected: &IndicatorResult,
+	index: usize,
+	perturbed: usize,
+) {
+	let diff = result.diff_with_epsilon(expected, 0.0);
+
+	assert!(
+		diff.is_empty(),
+		"Look-ahead detected: result #{index} changed (values {:?}, signals {:?}) when candle #{perturbed} was perturbed",
+		diff.changed_values().collect::<Vec<_>>(),
+		diff.changed_signals().collect::<Vec<_>>(),
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::assert_no_lookahead;
+	use crate::core::{
+		Candle, Error, IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType, OHLCV,
+	};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{PivotReversalStrategy, MACD};
+
+	/// Returns the close price of the next candle when evaluated in batches
+	#[derive(Debug, Clone, Copy)]
+	struct Peeking;
+
+	impl IndicatorConfig for Peeking {
+		type Instance = Self;
+
+		const NAME: &'static str = "Peeking";
+
+		fn init<T: OHLCV>(self, _: &T) -> Result<Self::Instance, Error> {
+			Ok(self)
+		}
+
+		fn validate(&self) -> bool {
+			true
+		}
+
+		fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+			Err(Error::ParameterParse(name.to_string(), value))
+		}
+
+		fn size(&self) -> (u8, u8) {
+			(1, 0)
+		}
+	}
+
+	impl IndicatorInstance for Peeking {
+		type Config = Self;
+
+		fn config(&self) -> &Self::Config {
+			self
+		}
+
+		fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+			IndicatorResult::new(&[candle.close()], &[])
+		}
+
+		fn next_batch<T: OHLCV>(&mut self, candles: &[T]) -> Vec<IndicatorResult> {
+			candles
+				.iter()
+				.enumerate()
+				.map(|(i, candle)| {
+					let close: ValueType = candles.get(i + 1).unwrap_or(candle).close();
+					IndicatorResult::new(&[close], &[])
+				})
+				.collect()
+		}
+	}
+
+	#[test]
+	fn test_no_lookahead_pivot_reversal_strategy() {
+		let candles: Vec<_> = RandomCandles::new().take(150).collect();
+
+		assert_no_lookahead(&PivotReversalStrategy::default(), &candles);
+		assert_no_lookahead(
+			&PivotReversalStrategy {
+				left: 1,
+				right: 1,
+				memory: 3,
+			},
+			&candles,
+		);
+	}
+
+	#[test]
+	fn test_no_lookahead_macd() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		assert_no_lookahead(&MACD::default(), &candles);
+	}
+
+	#[test]
+	#[should_panic(
+		expected = "Look-ahead detected: result #0 changed (values [0], signals []) when candle #1"
+	)]
+	fn test_lookahead_detected() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+
+		assert_no_lookahead(&Peeking, &candles);
+	}
+
+	#[test]
+	fn test_no_lookahead_short() {
+		let candles: Vec<Candle> = RandomCandles::new().take(1).collect();
+
+		assert_no_lookahead(&Peeking, &candles);
+		assert_no_lookahead(&Peeking, &candles[..0]);
+		assert_no_lookahead(&MACD::default(), &candles);
+	}
+}
@@ -0,0 +1,224 @@
+use crate::core::{CandleSource, Error, Method, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use std::fmt::Debug;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adapter which runs any scalar [`Method`] as an indicator
+///
+/// Reads a single price from each candle according to the `source` and passes it to the inner method.
+/// The method's output is returned as the only raw value of [`IndicatorResult`]. There are no signals.
+///
+/// # 1 value
+///
+/// * `value` of the inner method
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Source;
+/// use yata::helpers::{MethodIndicator, RandomCandles};
+/// use yata::methods::SMA;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+///
+/// let mut config = MethodIndicator::<SMA>::new(3);
+/// config.source = Source::HL2;
+///
+/// let results = config.over(&candles).unwrap();
+/// assert_eq!(results[0].values().len(), 1);
+/// assert!(results[0].signals().is_empty());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MethodIndicator<M>
+where
+	M: Method<Input = ValueType, Output = ValueType>,
+	M::Params: Clone + Debug,
+{
+	/// Parameters of the inner method
+	pub params: M::Params,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl<M> MethodIndicator<M>
+where
+	M: Method<Input = ValueType, Output = ValueType>,
+	M::Params: Clone + Debug,
+{
+	/// Creates new `MethodIndicator` with the inner method's `params` over the [`Close`](crate::core::Source::Close) price
+	pub const fn new(params: M::Params) -> Self {
+		Self {
+			params,
+			source: Source::Close,
+		}
+	}
+}
+
+impl<M> CandleSource for MethodIndicator<M>
+where
+	M: Method<Input = ValueType, Output = ValueType>,
+	M::Params: Clone + Debug,
+{
+	fn source(&self) -> Source {
+		self.source
+	}
+}
+
+impl<M> IndicatorConfig for MethodIndicator<M>
+where
+	M: Method<Input = ValueType, Output = ValueType> + Clone,
+	M::Params: Clone + Debug,
+{
+	type Instance = MethodIndicatorInstance<M>;
+
+	const NAME: &'static str = "MethodIndicator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(Self::Instance {
+			method: M::new(cfg.params.clone(), &cfg.price(candle))?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		M::new(self.params.clone(), &0.0).is_ok()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 0)
+	}
+}
+
+/// **State** of [`MethodIndicator`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "M: Serialize, M::Params: Serialize",
+		deserialize = "M: Deserialize<'de>, M::Params: Deserialize<'de>"
+	))
+)]
+pub struct MethodIndicatorInstance<M>
+where
+	M: Method<Input = ValueType, Output = ValueType>,
+	M::Params: Clone + Debug,
+{
+	cfg: MethodIndicator<M>,
+
+	method: M,
+}
+
+impl<M> MethodIndicatorInstance<M>
+where
+	M: Method<Input = ValueType, Output = ValueType>,
+	M::Params: Clone + Debug,
+{
+	/// Returns a reference to the inner method
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<M> IndicatorInstance for MethodIndicatorInstance<M>
+where
+	M: Method<Input = ValueType, Output = ValueType> + Clone,
+	M::Params: Clone + Debug,
+{
+	type Config = MethodIndicator<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["value"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let value = self.method.next(&self.cfg.price(candle));
+
+		IndicatorResult::new(&[value], &[])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MethodIndicator;
+	use crate::core::{IndicatorConfig, IndicatorInstance, Method, Source, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{StDev, SMA};
+
+	#[test]
+	fn test_method_indicator_sma() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for length in 1..30 {
+			let config = MethodIndicator::<SMA>::new(length);
+			let mut sma = SMA::new(length, &candles[0].close).unwrap();
+
+			let results = config.over(&candles).unwrap();
+			assert_eq!(results.len(), candles.len());
+
+			for (candle, result) in candles.iter().zip(&results) {
+				assert_eq!(result.size(), (1, 0));
+				assert_eq_float(sma.next(&candle.close), result.value(0));
+			}
+		}
+	}
+
+	#[test]
+	fn test_method_indicator_source() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		let mut config = MethodIndicator::<StDev>::new(10);
+		config.set("source", "hl2".to_string()).unwrap();
+		assert_eq!(config.source, Source::HL2);
+
+		let mut instance = config.init(&candles[0]).unwrap();
+		let mut st_dev = StDev::new(10, &candles[0].hl2()).unwrap();
+
+		assert_eq!(instance.value_names(), &["value"]);
+		assert!(instance.signal_names().is_empty());
+
+		for candle in &candles {
+			assert_eq_float(st_dev.next(&candle.hl2()), instance.next(candle).value(0));
+		}
+	}
+
+	#[test]
+	fn test_method_indicator_wrong_params() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+
+		let mut config = MethodIndicator::<SMA>::new(0);
+		assert!(!config.validate());
+		assert!(config.clone().init(&candles[0]).is_err());
+		assert!(config.set("params", "10".to_string()).is_err());
+		assert!(config.set("source", "unknown".to_string()).is_err());
+	}
+}
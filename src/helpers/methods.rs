@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, MovingAverage, MovingAverageConstructor, PeriodType, ValueType};
 use crate::methods::{
-	LinReg, Vidya, DEMA, DMA, EMA, HMA, RMA, SMA, SMM, SWMA, TEMA, TMA, TRIMA, WMA, WSMA,
+	LinReg, Vidya, DEMA, DMA, EMA, HMA, RMA, SMA, SMM, SWMA, TEMA, TMA, TRIMA, WMA, WSMA, ZLEMA,
 };
 
 /// Default moving average constructor
@@ -59,6 +59,9 @@ pub enum MA {
 
 	/// [Variable Index Dynamic Average](crate::methods::Vidya)
 	Vidya(PeriodType),
+
+	/// [Zero Lag Exponential Moving Average](crate::methods::ZLEMA)
+	ZLEMA(PeriodType),
 }
 
 /// Default moving average instance for constructor
@@ -112,6 +115,9 @@ pub enum MAInstance {
 
 	/// [Variable Index Dynamic Average](crate::methods::Vidya)
 	Vidya(Vidya),
+
+	/// [Zero Lag Exponential Moving Average](crate::methods::ZLEMA)
+	ZLEMA(ZLEMA),
 }
 
 impl Method for MAInstance {
@@ -144,6 +150,7 @@ impl Method for MAInstance {
 			Self::TRIMA(i) => i.next(value),
 			Self::LinReg(i) => i.next(value),
 			Self::Vidya(i) => i.next(value),
+			Self::ZLEMA(i) => i.next(value),
 		}
 	}
 }
@@ -213,9 +220,13 @@ impl MovingAverageConstructor for MA {
 				Ok(Self::Instance::LinReg(instance))
 			}
 			Self::Vidya(length) => {
-				let instance = Vidya::new(length, &value)?;
+				let instance = Vidya::new((length, length), &value)?;
 				Ok(Self::Instance::Vidya(instance))
 			}
+			Self::ZLEMA(length) => {
+				let instance = ZLEMA::new(length, &value)?;
+				Ok(Self::Instance::ZLEMA(instance))
+			}
 		}
 	}
 
@@ -236,7 +247,8 @@ impl MovingAverageConstructor for MA {
 			| Self::SWMA(length)
 			| Self::TRIMA(length)
 			| Self::LinReg(length)
-			| Self::Vidya(length) => *length,
+			| Self::Vidya(length)
+			| Self::ZLEMA(length) => *length,
 		}
 	}
 
@@ -257,6 +269,7 @@ impl MovingAverageConstructor for MA {
 			Self::TRIMA(_) => 12,
 			Self::LinReg(_) => 13,
 			Self::Vidya(_) => 14,
+			Self::ZLEMA(_) => 15,
 		}
 	}
 }
@@ -285,6 +298,7 @@ impl FromStr for MA {
 			"trima" => Ok(Self::TRIMA(length)),
 			"linreg" => Ok(Self::LinReg(length)),
 			"vidya" => Ok(Self::Vidya(length)),
+			"zlema" => Ok(Self::ZLEMA(length)),
 			_ => Err(Error::MovingAverageParse),
 		}
 	}
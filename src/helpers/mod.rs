@@ -2,12 +2,34 @@
 //! Additional helping primitives
 //!
 
+mod cooldown;
+mod ensemble;
+mod forward_fill;
 mod history;
+mod lookahead;
+mod method_indicator;
 mod methods;
+mod normalize;
+mod replay;
+mod sample_every;
+mod sweep;
+mod value_tap;
+mod volume_profile;
 
 use crate::core::{Candle, ValueType};
+pub use cooldown::Cooldown;
+pub use ensemble::Ensemble;
+pub use forward_fill::ForwardFill;
 pub use history::{Buffered, Peekable, WithHistory, WithLastValue};
+pub use lookahead::assert_no_lookahead;
+pub use method_indicator::{MethodIndicator, MethodIndicatorInstance};
 pub use methods::{MAInstance, MA};
+pub use normalize::{Normalization, Normalize};
+pub use replay::{replay, replay_values, run_trimmed};
+pub use sample_every::SampleEvery;
+pub use sweep::{sweep, Sweep};
+pub use value_tap::ValueTap;
+pub use volume_profile::{VolumeDistribution, VolumeProfile};
 
 /// sign is like [`f64::signum`]
 /// except when value == 0.0, then sign returns 0.0
@@ -125,6 +147,21 @@ pub fn assert_neq_float(value1: ValueType, value2: ValueType) {
 }
 
 /// Random Candles iterator for testing purposes
+///
+/// It is an infinite deterministic sequence of candles, so every new instance produces the same candles.
+///
+/// It is `Clone`, so the stream may be forked at any point and both copies produce the same subsequent candles.
+///
+/// ```
+/// use yata::helpers::RandomCandles;
+///
+/// let mut candles = RandomCandles::new();
+/// let _ = candles.nth(10);
+///
+/// let mut fork = candles.clone();
+/// assert_eq!(candles.next(), fork.next());
+/// assert_eq!(candles.first(), RandomCandles::new().next().unwrap());
+/// ```
 #[derive(Debug, Clone, Default)]
 #[allow(missing_copy_implementations)]
 pub struct RandomCandles(u16);
@@ -139,15 +176,11 @@ impl RandomCandles {
 		Self::default()
 	}
 
-	/// Returns very first candle in the sequence
+	/// Returns very first candle in the sequence without advancing the iterator
 	#[allow(clippy::missing_panics_doc)]
-	pub fn first(&mut self) -> Candle {
-		let position = self.0;
-		self.0 = 0;
-		let candle = self.next().unwrap();
-		self.0 = position;
-
-		candle
+	#[must_use]
+	pub fn first(&self) -> Candle {
+		Self::default().next().unwrap()
 	}
 }
 
@@ -182,11 +215,51 @@ impl Iterator for RandomCandles {
 		Some(candle)
 	}
 
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+
 	#[allow(clippy::cast_possible_truncation)]
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		self.0 = n as u16;
-		self.0 = self.0.wrapping_sub(1);
+		// the sequence is periodic, so skipping `n` candles is the same as skipping `n` modulo period
+		self.0 = self.0.wrapping_sub(n as u16);
 
 		self.next()
 	}
 }
+
+impl std::iter::FusedIterator for RandomCandles {}
+
+#[cfg(test)]
+mod tests {
+	use super::RandomCandles;
+
+	#[test]
+	fn test_random_candles_fork() {
+		let mut candles = RandomCandles::new();
+		let _ = candles.by_ref().take(123).count();
+
+		let mut fork = candles.clone();
+		let original: Vec<_> = candles.by_ref().take(500).collect();
+		let forked: Vec<_> = fork.by_ref().take(500).collect();
+
+		assert_eq!(original, forked);
+		assert_eq!(candles.next(), fork.next());
+	}
+
+	#[test]
+	fn test_random_candles_iterator() {
+		let mut candles = RandomCandles::new();
+
+		assert_eq!(candles.size_hint(), (usize::MAX, None));
+		assert_eq!(candles.first(), candles.clone().next().unwrap());
+
+		let first = candles.next().unwrap();
+		assert_eq!(candles.first(), first);
+
+		let mut skipped = candles.clone();
+		let expected = candles.by_ref().take(100).last();
+		assert_eq!(skipped.nth(99), expected);
+		assert_eq!(skipped.nth(1), candles.nth(1));
+	}
+}
@@ -0,0 +1,257 @@
+use crate::core::{Candle, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::RMA;
+
+/// Share of the candle's price, below which the scaling base is treated as zero
+const BASE_EPSILON: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-5
+} else {
+	1e-9
+};
+
+/// Normalization mode for the [`Normalize`] adapter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+	/// Divides `open`, `high`, `low` and `close` prices by the `close` price of the very first candle
+	FirstClose,
+
+	/// Divides `open`, `high`, `low` and `close` prices by the current Average True Range over the given period
+	///
+	/// Average True Range is calculated as [`RMA`](crate::methods::RMA) of the [True Range](crate::core::OHLCV::tr).
+	///
+	/// Period must be > `0`
+	Atr(PeriodType),
+}
+
+#[derive(Debug, Clone)]
+enum NormalizeState {
+	FirstClose(ValueType),
+	Atr { prev_close: ValueType, atr: RMA },
+}
+
+/// Iterator adapter which scales candles' prices to make indicators comparable across instruments with very different price levels
+///
+/// Produces normalized [`Candle`]s. Only `open`, `high`, `low` and `close` prices are scaled. `volume` always stays untouched.
+///
+/// When the scaling base (first `close` price or the current Average True Range) is negligible relative to the candle's prices
+/// (f.e. it is `0.0` or consists of float rounding errors only) or is not a finite number, the candle can't be normalized and [`Error::InvalidCandles`] is produced in place of it.
+/// Iteration goes on with the next candles, so such candles may be skipped with [`Iterator::flatten`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{Normalization, Normalize};
+///
+/// let candles = [
+///     Candle { open: 95.0, high: 110.0, low: 90.0, close: 100.0, volume: 10.0 },
+///     Candle { open: 100.0, high: 125.0, low: 100.0, close: 120.0, volume: 20.0 },
+/// ];
+///
+/// let normalized: Vec<_> = Normalize::new(candles.into_iter(), Normalization::FirstClose)
+///     .unwrap()
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(normalized[0].close(), 1.0);
+/// assert_eq!(normalized[1].high(), 1.25);
+/// assert_eq!(normalized[1].volume(), 20.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Normalize<I, T>
+where
+	I: Iterator<Item = T>,
+	T: OHLCV,
+{
+	iter: I,
+	mode: Normalization,
+	state: Option<NormalizeState>,
+}
+
+impl<I, T> Normalize<I, T>
+where
+	I: Iterator<Item = T>,
+	T: OHLCV,
+{
+	/// Creates new `Normalize` adapter over the `iter` with the given normalization `mode`
+	pub fn new(iter: I, mode: Normalization) -> Result<Self, Error> {
+		if mode == Normalization::Atr(0) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			iter,
+			mode,
+			state: None,
+		})
+	}
+
+	fn base(&mut self, candle: &T) -> Result<ValueType, Error> {
+		let state = match &mut self.state {
+			Some(state) => state,
+			None => self.state.insert(match self.mode {
+				Normalization::FirstClose => NormalizeState::FirstClose(candle.close()),
+				Normalization::Atr(period) => NormalizeState::Atr {
+					prev_close: candle.close(),
+					atr: RMA::new(period, &(candle.high() - candle.low()))?,
+				},
+			}),
+		};
+
+		let base = match state {
+			NormalizeState::FirstClose(close) => *close,
+			NormalizeState::Atr { prev_close, atr } => {
+				let tr = candle.tr_close(*prev_close);
+				*prev_close = candle.close();
+
+				atr.next(&tr)
+			}
+		};
+
+		Ok(base)
+	}
+}
+
+impl<I, T> Iterator for Normalize<I, T>
+where
+	I: Iterator<Item = T>,
+	T: OHLCV,
+{
+	type Item = Result<Candle, Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let candle = self.iter.next()?;
+
+		Some(self.base(&candle).and_then(|base| {
+			let price = candle.high().abs().max(candle.low().abs());
+
+			if !base.is_finite() || base.abs() <= price * BASE_EPSILON {
+				return Err(Error::InvalidCandles);
+			}
+
+			let k = base.recip();
+			let mut normalized = Candle::from(&candle);
+
+			normalized.open *= k;
+			normalized.high *= k;
+			normalized.low *= k;
+			normalized.close *= k;
+
+			Ok(normalized)
+		}))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Normalization, Normalize};
+	use crate::core::{Candle, Error, Method, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::RMA;
+
+	#[test]
+	fn test_normalize_first_close() {
+		let candles: Vec<Candle> = RandomCandles::new()
+			.take(100)
+			.map(|c| Candle {
+				open: c.open * 1000.0,
+				high: c.high * 1000.0,
+				low: c.low * 1000.0,
+				close: c.close * 1000.0,
+				volume: c.volume,
+			})
+			.collect();
+
+		let normalized: Vec<_> = Normalize::new(candles.iter().copied(), Normalization::FirstClose)
+			.unwrap()
+			.collect::<Result<_, _>>()
+			.unwrap();
+
+		assert_eq!(normalized.len(), candles.len());
+		assert_eq_float(1.0, normalized[0].close());
+
+		let base = candles[0].close();
+		for (original, normalized) in candles.iter().zip(&normalized) {
+			assert_eq_float(original.open() / base, normalized.open());
+			assert_eq_float(original.high() / base, normalized.high());
+			assert_eq_float(original.low() / base, normalized.low());
+			assert_eq_float(original.close() / base, normalized.close());
+			assert_eq!(original.volume().to_bits(), normalized.volume().to_bits());
+		}
+	}
+
+	#[test]
+	fn test_normalize_atr() {
+		let candles: Vec<Candle> = RandomCandles::new().take(100).collect();
+
+		let normalized: Vec<_> = Normalize::new(candles.iter().copied(), Normalization::Atr(14))
+			.unwrap()
+			.collect::<Result<_, _>>()
+			.unwrap();
+
+		let mut atr = RMA::new(14, &(candles[0].high() - candles[0].low())).unwrap();
+		let mut prev_close = candles[0].close();
+
+		for (original, normalized) in candles.iter().zip(&normalized) {
+			let base = atr.next(&original.tr_close(prev_close));
+			prev_close = original.close();
+
+			assert_eq_float(original.close() / base, normalized.close());
+			assert_eq_float(original.high() / base, normalized.high());
+			assert_eq!(original.volume().to_bits(), normalized.volume().to_bits());
+		}
+	}
+
+	#[test]
+	fn test_normalize_zero_base() {
+		let flat = Candle {
+			close: 2.0,
+			high: 2.0,
+			low: 2.0,
+			open: 2.0,
+			volume: 1.0,
+		};
+		let moving = Candle {
+			high: 3.0,
+			close: 3.0,
+			..flat
+		};
+		let candles = [flat, flat, flat, moving, flat];
+
+		let normalized: Vec<_> = Normalize::new(candles.iter().copied(), Normalization::Atr(3))
+			.unwrap()
+			.collect();
+
+		assert_eq!(normalized.len(), candles.len());
+		assert!(normalized[..3]
+			.iter()
+			.all(|result| matches!(result, Err(Error::InvalidCandles))));
+		assert!(normalized[3..].iter().all(Result::is_ok));
+
+		// rounding errors only
+		let tiny_close = Candle {
+			low: 1e-12,
+			close: 1e-12,
+			..flat
+		};
+		let mut iter =
+			Normalize::new([tiny_close, flat].into_iter(), Normalization::FirstClose).unwrap();
+
+		assert!(matches!(iter.next(), Some(Err(Error::InvalidCandles))));
+		assert!(matches!(iter.next(), Some(Err(Error::InvalidCandles))));
+
+		let zero_close = Candle { close: 0.0, ..flat };
+		let mut iter =
+			Normalize::new([zero_close, flat].into_iter(), Normalization::FirstClose).unwrap();
+
+		assert!(matches!(iter.next(), Some(Err(Error::InvalidCandles))));
+		assert!(matches!(iter.next(), Some(Err(Error::InvalidCandles))));
+		assert!(iter.next().is_none());
+	}
+
+	#[test]
+	fn test_normalize_wrong_period() {
+		let candles: Vec<Candle> = RandomCandles::new().take(10).collect();
+
+		assert!(Normalize::new(candles.iter().copied(), Normalization::Atr(0)).is_err());
+	}
+}
@@ -0,0 +1,177 @@
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult, ValueType, OHLCV};
+
+/// Initializes indicator `cfg` on the first candle and evaluates it over all the `candles`
+///
+/// It is a shortcut for driving indicators over hand-built candle sequences in tests.
+/// Returns an empty vector when there are no candles.
+///
+/// # Panics
+///
+/// Panics if indicator cannot be initialized with the provided `cfg`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{replay, RandomCandles};
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let results = replay(&candles, MACD::default());
+///
+/// assert_eq!(results.len(), 100);
+/// ```
+///
+/// # See also
+///
+/// [`replay_values`], [`IndicatorConfig::over`](crate::core::IndicatorConfig::over)
+pub fn replay<T: OHLCV, C: IndicatorConfig>(candles: &[T], cfg: C) -> Vec<IndicatorResult> {
+	let Some(first) = candles.first() else {
+		return Vec::new();
+	};
+
+	let mut state = cfg.init(first).expect("Unable to initialize indicator");
+
+	state.over(candles)
+}
+
+/// Same as [`replay`], but returns only the raw value at `index` of every result
+///
+/// # Panics
+///
+/// Panics if indicator cannot be initialized with the provided `cfg` or if `index` is out of the indicator's raw values bounds
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{replay_values, RandomCandles};
+/// use yata::indicators::BollingerBands;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let upper = replay_values(&candles, BollingerBands::default(), 0);
+/// let lower = replay_values(&candles, BollingerBands::default(), 2);
+///
+/// assert!(upper.iter().zip(&lower).all(|(u, l)| u >= l));
+/// ```
+pub fn replay_values<T: OHLCV, C: IndicatorConfig>(
+	candles: &[T],
+	cfg: C,
+	index: usize,
+) -> Vec<ValueType> {
+	replay(candles, cfg)
+		.iter()
+		.map(|result| result.value(index))
+		.collect()
+}
+
+/// Same as [`replay`], but drops the first [`warmup_period`](IndicatorConfig::warmup_period) results
+///
+/// Useful for comparing indicator's output with another implementation, because results during the warmup depend on the initial value.
+/// Returns an empty vector when there are not more candles than the warmup period.
+///
+/// # Panics
+///
+/// Panics if indicator cannot be initialized with the provided `cfg` or if it does not describe its
+/// [`warmup_period`](IndicatorConfig::warmup_period)
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{run_trimmed, RandomCandles};
+/// use yata::indicators::BollingerBands;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let cfg = BollingerBands::default();
+/// let results = run_trimmed(&candles, cfg);
+///
+/// assert_eq!(results.len(), 100 - cfg.warmup_period().unwrap());
+/// ```
+pub fn run_trimmed<T: OHLCV, C: IndicatorConfig>(candles: &[T], cfg: C) -> Vec<IndicatorResult> {
+	let warmup = cfg
+		.warmup_period()
+		.unwrap_or_else(|| panic!("{} does not describe its warmup period", C::NAME));
+	let mut results = replay(candles, cfg);
+
+	results.drain(..warmup.min(results.len()));
+
+	results
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{replay, replay_values, run_trimmed};
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::{BollingerBands, PivotReversalStrategy, Trix, MACD};
+
+	#[test]
+	fn test_replay_pivot_reversal_strategy() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let cfg = PivotReversalStrategy::default();
+
+		let results = replay(&candles, cfg);
+
+		let mut state = cfg.init(&candles[0]).unwrap();
+		assert_eq!(results.len(), candles.len());
+
+		for (candle, result) in candles.iter().zip(&results) {
+			let expected = state.next(candle);
+
+			assert_eq!(expected.size(), result.size());
+			assert_eq!(expected.signals(), result.signals());
+		}
+	}
+
+	#[test]
+	fn test_replay_values() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let cfg = BollingerBands::default();
+
+		let middle = replay_values(&candles, cfg, 1);
+
+		let mut state = cfg.init(&candles[0]).unwrap();
+		assert_eq!(middle.len(), candles.len());
+
+		for (candle, &value) in candles.iter().zip(&middle) {
+			assert_eq_float(state.next(candle).value(1), value);
+		}
+	}
+
+	#[test]
+	fn test_replay_empty() {
+		let candles: [Candle; 0] = [];
+
+		assert!(replay(&candles, BollingerBands::default()).is_empty());
+		assert!(replay_values(&candles, BollingerBands::default(), 0).is_empty());
+	}
+
+	#[test]
+	fn test_run_trimmed() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let cfg = MACD::default();
+		let warmup = cfg.warmup_period().unwrap();
+
+		let full = replay(&candles, cfg);
+		let trimmed = run_trimmed(&candles, cfg);
+
+		assert!(warmup > 0);
+		assert_eq!(trimmed.len(), candles.len() - warmup);
+
+		for (a, b) in full[warmup..].iter().zip(&trimmed) {
+			assert_eq!(format!("{a:?}"), format!("{b:?}"));
+		}
+
+		assert!(run_trimmed(&candles[..warmup], cfg).is_empty());
+		assert!(run_trimmed(&candles[..0], cfg).is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "Trix does not describe its warmup period")]
+	fn test_run_trimmed_unknown_warmup() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+
+		let _ = run_trimmed(&candles, Trix::default());
+	}
+}
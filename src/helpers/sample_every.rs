@@ -0,0 +1,125 @@
+use crate::core::{Error, IndicatorInstance, IndicatorResult, OHLCV};
+
+/// Downsampling wrapper for indicator instances
+///
+/// Calls inner [`IndicatorInstance::next`] on every candle, so the indicator state stays correct,
+/// but yields only every `n`-th result (on the `n`-th, `2n`-th and so on candles).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{RandomCandles, SampleEvery};
+/// use yata::indicators::MACD;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let instance = MACD::default().init(&candles[0]).unwrap();
+///
+/// let mut sampled = SampleEvery::new(instance, 10).unwrap();
+/// let results: Vec<_> = candles.iter().filter_map(|candle| sampled.next(candle)).collect();
+///
+/// assert_eq!(results.len(), 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SampleEvery<I: IndicatorInstance> {
+	instance: I,
+	n: usize,
+	index: usize,
+}
+
+impl<I: IndicatorInstance> SampleEvery<I> {
+	/// Creates new `SampleEvery` wrapper over the indicator `instance`
+	///
+	/// `n` must be > `0`
+	pub fn new(instance: I, n: usize) -> Result<Self, Error> {
+		if n == 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			instance,
+			n,
+			index: 0,
+		})
+	}
+
+	/// Advances inner indicator instance and returns its result on every `n`-th call
+	pub fn next<T: OHLCV>(&mut self, candle: &T) -> Option<IndicatorResult> {
+		let result = self.instance.next(candle);
+
+		self.index += 1;
+
+		if self.index == self.n {
+			self.index = 0;
+			Some(result)
+		} else {
+			None
+		}
+	}
+
+	/// Returns a reference to the inner indicator instance
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Returns the inner indicator instance in its current state
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SampleEvery;
+	use crate::core::{IndicatorConfig, IndicatorInstance};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::{RelativeStrengthIndex, MACD};
+
+	#[test]
+	fn test_sample_every() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for n in [1, 2, 7, 50, 299, 300, 301] {
+			let mut full = MACD::default().init(&candles[0]).unwrap();
+			let mut sampled =
+				SampleEvery::new(MACD::default().init(&candles[0]).unwrap(), n).unwrap();
+
+			for (i, candle) in candles.iter().enumerate() {
+				let expected = full.next(candle);
+				let result = sampled.next(candle);
+
+				if (i + 1) % n == 0 {
+					assert_eq!(format!("{result:?}"), format!("{:?}", Some(expected)));
+				} else {
+					assert!(result.is_none());
+				}
+			}
+
+			// internal state is the same as after running indicator on every candle
+			let mut inner = sampled.into_inner();
+			for candle in candles.iter().take(10) {
+				assert_eq!(
+					format!("{:?}", inner.next(candle)),
+					format!("{:?}", full.next(candle))
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_sample_every_count() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let instance = RelativeStrengthIndex::default().init(&candles[0]).unwrap();
+
+		assert!(SampleEvery::new(instance.clone(), 0).is_err());
+
+		let mut sampled = SampleEvery::new(instance, 3).unwrap();
+		let count = candles.iter().filter_map(|x| sampled.next(x)).count();
+
+		assert_eq!(count, 33);
+		assert_eq!(
+			sampled.instance().config().ma,
+			RelativeStrengthIndex::default().ma
+		);
+	}
+}
@@ -0,0 +1,179 @@
+use crate::core::{Error, IndicatorConfigDyn, OHLCV};
+
+/// Iterator over all the combinations of indicator's parameters values
+///
+/// Each item is a copy of the base configuration with parameters set by [`IndicatorConfigDyn::set`].
+/// When some parameter cannot be set, the item is an `Err`.
+/// Produced configurations are not validated, so use [`IndicatorConfigDyn::validate`] to filter out invalid ones.
+///
+/// Combinations are produced in the order of the provided parameters, where the last parameter changes most frequently.
+/// If any parameter has no values, no combinations are produced. If there are no parameters at all, the only item is a copy of the base configuration.
+///
+/// Created by [`sweep`].
+pub struct Sweep<T: OHLCV> {
+	base: Box<dyn IndicatorConfigDyn<T>>,
+	params: Vec<(String, Vec<String>)>,
+	indexes: Vec<usize>,
+	done: bool,
+}
+
+impl<T: OHLCV> Sweep<T> {
+	fn advance(&mut self) {
+		for (index, (_, values)) in self.indexes.iter_mut().zip(&self.params).rev() {
+			*index += 1;
+
+			if *index < values.len() {
+				return;
+			}
+
+			*index = 0;
+		}
+
+		self.done = true;
+	}
+}
+
+impl<T: OHLCV> Iterator for Sweep<T> {
+	type Item = Result<Box<dyn IndicatorConfigDyn<T>>, Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+
+		let mut config = self.base.clone_box();
+		let result = self
+			.indexes
+			.iter()
+			.zip(&self.params)
+			.try_for_each(|(&index, (name, values))| config.set(name, values[index].clone()));
+
+		self.advance();
+
+		Some(result.map(|()| config))
+	}
+}
+
+impl<T: OHLCV> std::fmt::Debug for Sweep<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Sweep")
+			.field("indicator", &self.base.name())
+			.field("params", &self.params)
+			.field("indexes", &self.indexes)
+			.field("done", &self.done)
+			.finish()
+	}
+}
+
+/// Generates all the combinations of the `base` indicator configuration parameters over the given `params` values
+///
+/// `params` is a collection of `(parameter name, parameter values)` pairs, f.e. a `HashMap`, a `BTreeMap` or just an array.
+///
+/// See more at [`Sweep`].
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::prelude::dd::*;
+/// use yata::helpers::sweep;
+/// use yata::indicators::PivotReversalStrategy;
+///
+/// let base: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(PivotReversalStrategy::default());
+/// let params = [
+///     ("left", vec![String::from("3"), String::from("4")]),
+///     ("right", vec![String::from("1"), String::from("2")]),
+/// ];
+///
+/// let configs: Vec<_> = sweep(base.as_ref(), params).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(configs.len(), 4);
+/// ```
+pub fn sweep<T, I, S>(base: &dyn IndicatorConfigDyn<T>, params: I) -> Sweep<T>
+where
+	T: OHLCV,
+	I: IntoIterator<Item = (S, Vec<String>)>,
+	S: Into<String>,
+{
+	let params: Vec<(String, Vec<String>)> = params
+		.into_iter()
+		.map(|(name, values)| (name.into(), values))
+		.collect();
+
+	Sweep {
+		base: base.clone_box(),
+		indexes: vec![0; params.len()],
+		done: params.iter().any(|(_, values)| values.is_empty()),
+		params,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::sweep;
+	use crate::core::{Candle, IndicatorConfigDyn};
+	use crate::helpers::RandomCandles;
+	use crate::indicators::PivotReversalStrategy;
+	use std::collections::BTreeMap;
+
+	fn values(values: &[&str]) -> Vec<String> {
+		values.iter().map(ToString::to_string).collect()
+	}
+
+	#[test]
+	fn test_sweep() {
+		let base: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(PivotReversalStrategy::default());
+
+		let mut params = BTreeMap::new();
+		params.insert("left", values(&["3", "4", "5"]));
+		params.insert("right", values(&["1", "2"]));
+
+		let configs: Vec<_> = sweep(base.as_ref(), params)
+			.collect::<Result<_, _>>()
+			.unwrap();
+
+		assert_eq!(configs.len(), 6);
+		assert!(configs.iter().all(|config| config.validate()));
+
+		let candles: Vec<Candle> = RandomCandles::new().take(50).collect();
+		let mut expected = Vec::new();
+		for left in 3..=5 {
+			for right in 1..=2 {
+				let config = PivotReversalStrategy {
+					left,
+					right,
+					..PivotReversalStrategy::default()
+				};
+				expected.push(format!(
+					"{:?}",
+					IndicatorConfigDyn::<Candle>::over(&config, &candles).unwrap()
+				));
+			}
+		}
+
+		let actual: Vec<_> = configs
+			.iter()
+			.map(|config| format!("{:?}", config.over(&candles).unwrap()))
+			.collect();
+
+		assert_eq!(actual, expected);
+	}
+
+	#[test]
+	fn test_sweep_edge_cases() {
+		let base: Box<dyn IndicatorConfigDyn<Candle>> = Box::new(PivotReversalStrategy::default());
+
+		assert_eq!(sweep(base.as_ref(), Vec::<(&str, _)>::new()).count(), 1);
+		assert_eq!(
+			sweep(base.as_ref(), [("left", values(&["3"])), ("right", vec![])]).count(),
+			0
+		);
+
+		let results: Vec<_> = sweep(base.as_ref(), [("unknown", values(&["1", "2"]))]).collect();
+		assert_eq!(results.len(), 2);
+		assert!(results.iter().all(Result::is_err));
+
+		let results: Vec<_> = sweep(base.as_ref(), [("left", values(&["3", "x"]))]).collect();
+		assert!(results[0].is_ok());
+		assert!(results[1].is_err());
+	}
+}
@@ -0,0 +1,120 @@
+use crate::core::{Error, IndicatorInstance, ValueType, OHLCV};
+use crate::helpers::Peekable;
+
+/// Extracts a single raw value of the wrapped indicator instance as a scalar timeseries
+///
+/// Calls inner [`IndicatorInstance::next`] on every candle and returns only the raw value at the `index`,
+/// so one of the indicator's outputs can feed a separate chain of [methods](crate::methods).
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{RandomCandles, ValueTap};
+/// use yata::indicators::ElderImpulse;
+/// use yata::methods::EMA;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(100).collect();
+/// let instance = ElderImpulse::default().init(&candles[0]).unwrap();
+///
+/// // `macd_histogram` is the raw value at index 1
+/// let mut histogram = ValueTap::new(instance, 1).unwrap();
+/// assert_eq!(histogram.peek(), None);
+///
+/// let mut ema = EMA::new(5, &histogram.next(&candles[0])).unwrap();
+///
+/// for candle in &candles[1..] {
+///     let smoothed = ema.next(&histogram.next(candle));
+///     println!("{}", smoothed);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValueTap<I: IndicatorInstance> {
+	instance: I,
+	index: usize,
+	last_value: Option<ValueType>,
+}
+
+impl<I: IndicatorInstance> ValueTap<I> {
+	/// Creates new `ValueTap` wrapper over the indicator `instance`, which extracts the raw value at the `index`
+	///
+	/// `instance` should have the raw value at the `index`.
+	///
+	/// Until the first call of [`ValueTap::next`], [`Peekable::peek`] returns `None`.
+	pub fn new(instance: I, index: usize) -> Result<Self, Error> {
+		if index >= instance.size().0 as usize {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			instance,
+			index,
+			last_value: None,
+		})
+	}
+
+	/// Advances inner indicator instance and returns its raw value at the `index`
+	pub fn next<T: OHLCV>(&mut self, candle: &T) -> ValueType {
+		let value = self.instance.next(candle).value(self.index);
+		self.last_value = Some(value);
+
+		value
+	}
+
+	/// Returns a reference to the inner indicator instance
+	pub const fn instance(&self) -> &I {
+		&self.instance
+	}
+
+	/// Returns the inner indicator instance in its current state
+	pub fn into_inner(self) -> I {
+		self.instance
+	}
+}
+
+impl<I: IndicatorInstance> Peekable<Option<ValueType>> for ValueTap<I> {
+	fn peek(&self) -> Option<ValueType> {
+		self.last_value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ValueTap;
+	use crate::core::{IndicatorConfig, IndicatorInstance, Method, ValueType};
+	use crate::helpers::{assert_eq_float, Peekable, RandomCandles};
+	use crate::indicators::{ElderImpulse, MACD};
+	use crate::methods::EMA;
+
+	#[test]
+	fn test_value_tap() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		let mut full = ElderImpulse::default().init(&candles[0]).unwrap();
+		let mut tap = ValueTap::new(full.clone(), 1).unwrap();
+
+		let mut ema = EMA::new(10, &0.0).unwrap();
+		let mut tapped_ema = EMA::new(10, &0.0).unwrap();
+
+		for candle in &candles {
+			let histogram = full.next(candle).value(1);
+			let tapped = tap.next(candle);
+
+			assert_eq!(histogram.to_bits(), tapped.to_bits());
+			assert_eq!(Some(tapped.to_bits()), tap.peek().map(ValueType::to_bits));
+			assert_eq_float(ema.next(&histogram), tapped_ema.next(&tapped));
+		}
+	}
+
+	#[test]
+	fn test_value_tap_wrong_index() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+		let instance = MACD::default().init(&candles[0]).unwrap();
+
+		assert!(ValueTap::new(instance.clone(), 2).is_err());
+
+		let tap = ValueTap::new(instance, 1).unwrap();
+		assert!(tap.peek().is_none());
+		assert_eq!(tap.instance().value_names()[1], "signal_line");
+	}
+}
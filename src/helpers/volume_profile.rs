@@ -0,0 +1,354 @@
+use crate::core::{Error, ValueType, OHLCV};
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Maximum count of buckets a single candle's volume may be spread across
+const MAX_CANDLE_BUCKETS: i64 = 1 << 16;
+
+/// Defines how candle's volume is distributed across price buckets in the [`VolumeProfile`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VolumeDistribution {
+	/// Whole candle's volume is put into the bucket of candle's `close` price
+	Close,
+
+	/// Candle's volume is spread uniformly across the candle's \[`low`; `high`\] range
+	///
+	/// Each bucket gets the part of the volume proportional to its overlap with the candle's range.
+	/// When `high` is equal to `low`, the whole volume is put into the bucket of candle's `close` price.
+	///
+	/// Candle's range may span at most `65536` buckets.
+	Range,
+}
+
+/// Volume-by-price histogram (market profile) builder
+///
+/// Buckets candles' volume by price levels. Each bucket covers prices in \[`price`; `price` + `bucket_size`\),
+/// where `price` is a multiple of `bucket_size`. Buckets are identified by their lower `price`.
+///
+/// The profile accumulates every pushed candle, so to build the profile over a session or a window
+/// just [`clear`](VolumeProfile::clear) it at the session start or push only the candles of the window.
+///
+/// Candles with non-finite prices or volume are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::{VolumeDistribution, VolumeProfile};
+///
+/// let mut profile = VolumeProfile::new(1.0, VolumeDistribution::Close).unwrap();
+///
+/// profile.push(&Candle { close: 10.5, volume: 100.0, ..Candle::default() }).unwrap();
+/// profile.push(&Candle { close: 11.2, volume: 300.0, ..Candle::default() }).unwrap();
+/// profile.push(&Candle { close: 10.1, volume: 150.0, ..Candle::default() }).unwrap();
+///
+/// assert_eq!(profile.point_of_control(), Some(11.0));
+/// assert_eq!(profile.volume_at(10.9), 250.0);
+/// assert_eq!(profile.total_volume(), 550.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VolumeProfile {
+	bucket_size: ValueType,
+	distribution: VolumeDistribution,
+	buckets: BTreeMap<i64, ValueType>,
+	total: ValueType,
+}
+
+impl VolumeProfile {
+	/// Creates new empty `VolumeProfile` with the given `bucket_size` and volume `distribution` mode
+	///
+	/// `bucket_size` must be a positive finite number.
+	pub fn new(bucket_size: ValueType, distribution: VolumeDistribution) -> Result<Self, Error> {
+		if !(bucket_size > 0.0 && bucket_size.is_finite()) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			bucket_size,
+			distribution,
+			buckets: BTreeMap::new(),
+			total: 0.0,
+		})
+	}
+
+	/// Returns the size of the price bucket
+	#[must_use]
+	pub const fn bucket_size(&self) -> ValueType {
+		self.bucket_size
+	}
+
+	/// Returns the volume distribution mode
+	#[must_use]
+	pub const fn distribution(&self) -> VolumeDistribution {
+		self.distribution
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn index(&self, price: ValueType) -> i64 {
+		(price / self.bucket_size).floor() as i64
+	}
+
+	#[allow(clippy::cast_precision_loss)]
+	fn price(&self, index: i64) -> ValueType {
+		index as ValueType * self.bucket_size
+	}
+
+	fn add(&mut self, index: i64, volume: ValueType) {
+		*self.buckets.entry(index).or_insert(0.0) += volume;
+	}
+
+	/// Adds the `candle`'s volume to the profile
+	///
+	/// # Errors
+	///
+	/// Returns [`Error::InvalidCandles`] and leaves the profile untouched if any of the `candle`'s values is not finite
+	/// or if its range spans too many buckets for the [`Range`](VolumeDistribution::Range) distribution.
+	#[allow(clippy::unnecessary_map_or)]
+	pub fn push<T: OHLCV + ?Sized>(&mut self, candle: &T) -> Result<(), Error> {
+		if !candle.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		let volume = candle.volume();
+		let (low, high) = (candle.low(), candle.high());
+
+		match self.distribution {
+			VolumeDistribution::Range if high > low => {
+				let (from, to) = (self.index(low), self.index(high));
+				if to
+					.checked_sub(from)
+					.map_or(true, |span| span >= MAX_CANDLE_BUCKETS)
+				{
+					return Err(Error::InvalidCandles);
+				}
+
+				let range = high - low;
+
+				for index in from..=to {
+					let from = self.price(index).max(low);
+					let to = self.price(index + 1).min(high);
+
+					if to > from {
+						self.add(index, volume * (to - from) / range);
+					}
+				}
+			}
+			_ => self.add(self.index(candle.close()), volume),
+		}
+
+		self.total += volume;
+
+		Ok(())
+	}
+
+	/// Removes all the accumulated volume from the profile
+	pub fn clear(&mut self) {
+		self.buckets.clear();
+		self.total = 0.0;
+	}
+
+	/// Returns total accumulated volume
+	#[must_use]
+	pub const fn total_volume(&self) -> ValueType {
+		self.total
+	}
+
+	/// Returns accumulated volume of the bucket which contains the `price`
+	#[must_use]
+	pub fn volume_at(&self, price: ValueType) -> ValueType {
+		self.buckets.get(&self.index(price)).copied().unwrap_or(0.0)
+	}
+
+	/// Returns an iterator over non-empty buckets as `(price, volume)` pairs in ascending `price` order
+	pub fn iter(&self) -> impl Iterator<Item = (ValueType, ValueType)> + '_ {
+		self.buckets
+			.iter()
+			.map(|(&index, &volume)| (self.price(index), volume))
+	}
+
+	/// Returns the lower price of the bucket with the highest accumulated volume (Point of Control)
+	///
+	/// When several buckets have the same volume, the lowest one is returned.
+	///
+	/// Returns `None` if the profile is empty.
+	#[must_use]
+	pub fn point_of_control(&self) -> Option<ValueType> {
+		self.poc_index().map(|index| self.price(index))
+	}
+
+	#[allow(clippy::unnecessary_map_or)]
+	fn poc_index(&self) -> Option<i64> {
+		let mut poc: Option<(i64, ValueType)> = None;
+
+		for (&index, &volume) in &self.buckets {
+			if poc.map_or(true, |(_, max)| volume > max) {
+				poc = Some((index, volume));
+			}
+		}
+
+		poc.map(|(index, _)| index)
+	}
+
+	/// Returns the value area as a `(low, high)` price range, which contains at least `part` of the total volume
+	///
+	/// Value area is built around the Point of Control by adding the neighbour bucket with the higher volume at each step.
+	/// Classic `part` value is `0.7`.
+	///
+	/// `low` is the lower price of the lowest bucket and `high` is the upper price of the highest bucket in the value area.
+	///
+	/// Returns `None` if the profile is empty.
+	#[must_use]
+	pub fn value_area(&self, part: ValueType) -> Option<(ValueType, ValueType)> {
+		let poc = self.poc_index()?;
+		let (&min, _) = self.buckets.first_key_value()?;
+		let (&max, _) = self.buckets.last_key_value()?;
+
+		let volume = |index: i64| self.buckets.get(&index).copied().unwrap_or(0.0);
+		let target = self.total * part.clamp(0.0, 1.0);
+
+		let (mut low, mut high) = (poc, poc);
+		let mut accumulated = volume(poc);
+
+		while accumulated < target && (low > min || high < max) {
+			let below = if low > min { volume(low - 1) } else { -1.0 };
+			let above = if high < max { volume(high + 1) } else { -1.0 };
+
+			if above >= below {
+				high += 1;
+				accumulated += above;
+			} else {
+				low -= 1;
+				accumulated += below;
+			}
+		}
+
+		Some((self.price(low), self.price(high + 1)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{VolumeDistribution, VolumeProfile};
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_volume_profile_poc() {
+		let candles: Vec<Candle> = RandomCandles::new().take(500).collect();
+
+		for distribution in [VolumeDistribution::Close, VolumeDistribution::Range] {
+			let mut profile = VolumeProfile::new(0.5, distribution).unwrap();
+			for candle in &candles {
+				profile.push(candle).unwrap();
+			}
+
+			let total: ValueType = candles.iter().map(|c| c.volume).sum();
+			assert_eq_float(total, profile.total_volume());
+			assert_eq_float(total, profile.iter().map(|(_, volume)| volume).sum());
+
+			let poc = profile.point_of_control().unwrap();
+			let max = profile
+				.iter()
+				.map(|(_, volume)| volume)
+				.fold(0.0, ValueType::max);
+
+			assert_eq_float(max, profile.volume_at(poc));
+			assert!(profile.iter().all(|(_, volume)| volume <= max));
+		}
+	}
+
+	#[test]
+	fn test_volume_profile_range() {
+		let mut profile = VolumeProfile::new(1.0, VolumeDistribution::Range).unwrap();
+
+		profile
+			.push(&Candle {
+				open: 10.0,
+				high: 12.0,
+				low: 10.5,
+				close: 11.0,
+				volume: 30.0,
+			})
+			.unwrap();
+
+		assert_eq_float(10.0, profile.volume_at(10.0));
+		assert_eq_float(20.0, profile.volume_at(11.0));
+		assert_eq_float(0.0, profile.volume_at(12.0));
+		assert_eq!(profile.point_of_control(), Some(11.0));
+	}
+
+	#[test]
+	fn test_volume_profile_value_area() {
+		let mut profile = VolumeProfile::new(1.0, VolumeDistribution::Close).unwrap();
+
+		for (close, volume) in [
+			(1.5, 5.0),
+			(2.5, 10.0),
+			(3.5, 50.0),
+			(4.5, 20.0),
+			(5.5, 15.0),
+		] {
+			profile
+				.push(&Candle {
+					close,
+					volume,
+					..Candle::default()
+				})
+				.unwrap();
+		}
+
+		assert_eq!(profile.point_of_control(), Some(3.0));
+		assert_eq!(profile.value_area(0.7), Some((3.0, 5.0)));
+		assert_eq!(profile.value_area(1.0), Some((1.0, 6.0)));
+
+		profile.clear();
+		assert_eq!(profile.point_of_control(), None);
+		assert_eq!(profile.value_area(0.7), None);
+	}
+
+	#[test]
+	fn test_volume_profile_rejected_candles() {
+		let mut profile = VolumeProfile::new(0.01, VolumeDistribution::Range).unwrap();
+		let candle = Candle {
+			open: 1.0,
+			high: 2.0,
+			low: 1.0,
+			close: 1.5,
+			volume: 10.0,
+		};
+
+		profile.push(&candle).unwrap();
+
+		for candle in [
+			Candle {
+				high: 1e6,
+				..candle
+			},
+			Candle {
+				low: -ValueType::MAX,
+				high: ValueType::MAX,
+				..candle
+			},
+			Candle {
+				volume: ValueType::NAN,
+				..candle
+			},
+		] {
+			assert!(profile.push(&candle).is_err());
+		}
+
+		assert_eq_float(10.0, profile.total_volume());
+		assert_eq!(profile.iter().count(), 100);
+	}
+
+	#[test]
+	fn test_volume_profile_wrong_bucket_size() {
+		assert!(VolumeProfile::new(0.0, VolumeDistribution::Close).is_err());
+		assert!(VolumeProfile::new(-1.0, VolumeDistribution::Close).is_err());
+		assert!(VolumeProfile::new(ValueType::NAN, VolumeDistribution::Close).is_err());
+	}
+}
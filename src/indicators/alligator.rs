@@ -0,0 +1,284 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+	Action, Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, Window, OHLCV,
+};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::MA;
+
+/// Williams Alligator
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/articles/trading/072115/exploring-williams-alligator-indicator.asp>
+/// * <https://www.tradingview.com/support/solutions/43000592305-williams-alligator/>
+///
+/// # 3 values
+///
+/// * `Jaw` line value
+///
+/// Range is the same as the range of the `source` values.
+///
+/// * `Teeth` line value
+///
+/// Range is the same as the range of the `source` values.
+///
+/// * `Lips` line value
+///
+/// Range is the same as the range of the `source` values.
+///
+/// Every line is shifted forward by its offset. There is no look-ahead, so the value of each line
+/// at the current bar is the moving average value calculated `offset` bars ago.
+///
+/// # 1 signal
+///
+/// * When the alligator's mouth is open upwards (`lips` > `teeth` > `jaw`), returns full buy signal.
+///   When the mouth is open downwards (`lips` < `teeth` < `jaw`), returns full sell signal.
+///   When the lines are intertwined (mouth is closed), returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Alligator<M: MovingAverageConstructor = MA> {
+	/// Jaw line moving average type.
+	///
+	/// Default is [`RMA(13)`](crate::methods::RMA).
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub jaw: M,
+
+	/// Teeth line moving average type.
+	///
+	/// Default is [`RMA(8)`](crate::methods::RMA).
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub teeth: M,
+
+	/// Lips line moving average type.
+	///
+	/// Default is [`RMA(5)`](crate::methods::RMA).
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub lips: M,
+
+	/// Jaw line forward offset. Default is `8`.
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub jaw_offset: PeriodType,
+
+	/// Teeth line forward offset. Default is `5`.
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub teeth_offset: PeriodType,
+
+	/// Lips line forward offset. Default is `3`.
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub lips_offset: PeriodType,
+
+	/// Source type. Default is [`HL2`](crate::core::Source::HL2)
+	pub source: Source,
+}
+
+impl_candle_source!(Alligator<M>);
+
+impl<M: MovingAverageConstructor> IndicatorConfig for Alligator<M> {
+	type Instance = AlligatorInstance<M>;
+
+	const NAME: &'static str = "Alligator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			jaw: cfg.jaw.init(src)?,
+			teeth: cfg.teeth.init(src)?,
+			lips: cfg.lips.init(src)?,
+			jaw_window: Window::new(cfg.jaw_offset, src),
+			teeth_window: Window::new(cfg.teeth_offset, src),
+			lips_window: Window::new(cfg.lips_offset, src),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.jaw.ma_period() > 1
+			&& self.teeth.ma_period() > 1
+			&& self.lips.ma_period() > 1
+			&& self.jaw_offset < PeriodType::MAX
+			&& self.teeth_offset < PeriodType::MAX
+			&& self.lips_offset < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"jaw" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.jaw = value,
+			},
+			"teeth" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.teeth = value,
+			},
+			"lips" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.lips = value,
+			},
+			"jaw_offset" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.jaw_offset = value,
+			},
+			"teeth_offset" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.teeth_offset = value,
+			},
+			"lips_offset" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.lips_offset = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for Alligator<MA> {
+	fn default() -> Self {
+		Self {
+			jaw: MA::RMA(13),
+			teeth: MA::RMA(8),
+			lips: MA::RMA(5),
+			jaw_offset: 8,
+			teeth_offset: 5,
+			lips_offset: 3,
+			source: Source::HL2,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AlligatorInstance<M: MovingAverageConstructor = MA> {
+	cfg: Alligator<M>,
+
+	jaw: M::Instance,
+	teeth: M::Instance,
+	lips: M::Instance,
+	jaw_window: Window<ValueType>,
+	teeth_window: Window<ValueType>,
+	lips_window: Window<ValueType>,
+}
+
+/// Returns the value pushed into the `window` `window.len()` bars ago
+#[inline]
+fn shift(window: &mut Window<ValueType>, value: ValueType) -> ValueType {
+	if window.is_empty() {
+		value
+	} else {
+		window.push(value)
+	}
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for AlligatorInstance<M> {
+	type Config = Alligator<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["jaw", "teeth", "lips"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["mouth"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let jaw = shift(&mut self.jaw_window, self.jaw.next(&src));
+		let teeth = shift(&mut self.teeth_window, self.teeth.next(&src));
+		let lips = shift(&mut self.lips_window, self.lips.next(&src));
+
+		let signal = (lips > teeth && teeth > jaw) as i8 - (lips < teeth && teeth < jaw) as i8;
+
+		IndicatorResult::new(&[jaw, teeth, lips], &[Action::from(signal)])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Alligator;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_alligator_flat() {
+		let candle = Candle {
+			open: 10.0,
+			high: 12.0,
+			low: 8.0,
+			close: 11.0,
+			volume: 1.0,
+		};
+
+		let mut instance = Alligator::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq_float(10.0, result.value(0));
+			assert_eq_float(10.0, result.value(1));
+			assert_eq_float(10.0, result.value(2));
+			assert!(result.signal(0).is_none());
+		}
+	}
+
+	#[test]
+	fn test_alligator_offsets() {
+		let prices: Vec<Candle> = (0..100)
+			.map(|x| {
+				let x = x as ValueType;
+				Candle {
+					high: x,
+					low: x,
+					close: x,
+					..Candle::default()
+				}
+			})
+			.collect();
+
+		let shifted = Alligator {
+			jaw_offset: 7,
+			..Alligator::default()
+		};
+		let unshifted = Alligator {
+			jaw_offset: 0,
+			..Alligator::default()
+		};
+
+		let shifted = shifted.over(&prices).unwrap();
+		let unshifted = unshifted.over(&prices).unwrap();
+
+		for i in 7..prices.len() {
+			assert_eq_float(unshifted[i - 7].value(0), shifted[i].value(0));
+		}
+	}
+}
@@ -143,6 +143,14 @@ impl IndicatorInstance for AroonInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["aroon_up", "aroon_down"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["trend", "edge", "trend_value"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let highest_index = self.highest_index.next(&candle.high());
 		let lowest_index = self.lowest_index.next(&candle.low());
@@ -5,6 +5,7 @@ use super::HLC;
 use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, ValueType, Window, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::MA;
+use crate::methods::TR;
 
 /// Average Directional Index
 ///
@@ -69,7 +70,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for AverageDirectionalIndex<M>
 
 		Ok(Self::Instance {
 			window: Window::new(cfg.period1, HLC::from(candle)),
-			prev_close: candle.close(),
+			tr: TR::new(candle)?,
 			tr_ma: cfg.method1.init(tr)?,
 			plus_di: cfg.method1.init(0.0)?,
 			minus_di: cfg.method1.init(0.0)?,
@@ -139,7 +140,7 @@ pub struct AverageDirectionalIndexInstance<M: MovingAverageConstructor = MA> {
 	cfg: AverageDirectionalIndex<M>,
 
 	window: Window<HLC>,
-	prev_close: ValueType,
+	tr: TR,
 	tr_ma: M::Instance,
 	plus_di: M::Instance,
 	minus_di: M::Instance,
@@ -149,14 +150,12 @@ pub struct AverageDirectionalIndexInstance<M: MovingAverageConstructor = MA> {
 impl<M: MovingAverageConstructor> AverageDirectionalIndexInstance<M> {
 	fn dir_mov(&mut self, candle: HLC) -> (ValueType, ValueType) {
 		let prev_candle = self.window.push(candle);
-		let true_range = self.tr_ma.next(&candle.tr_close(self.prev_close));
+		let true_range = self.tr_ma.next(&self.tr.next(&candle));
 
 		if true_range == 0.0 {
 			return (0.0, 0.0);
 		}
 
-		self.prev_close = candle.close();
-
 		let (du, dd) = (
 			candle.high() - prev_candle.high(),
 			prev_candle.low() - candle.low(),
@@ -190,6 +189,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for AverageDirectionalIndexI
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["adx", "plus_di", "minus_di"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zone", "di_difference"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (plus, minus) = self.dir_mov(HLC::from(candle));
 		let adx = self.adx(plus, minus);
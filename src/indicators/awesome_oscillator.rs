@@ -51,6 +51,8 @@ pub struct AwesomeOscillator<M: MovingAverageConstructor = MA> {
 	pub conseq_peaks: u8,
 }
 
+impl_candle_source!(AwesomeOscillator<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for AwesomeOscillator<M> {
 	type Instance = AwesomeOscillatorInstance<M>;
 
@@ -156,6 +158,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for AwesomeOscillatorInstanc
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["ao"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["twin_peaks", "zero_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
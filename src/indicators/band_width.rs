@@ -0,0 +1,283 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::Peekable;
+use crate::methods::{Lowest, StDev, SMA};
+
+/// Share of the `source` value (or of the standard deviation), below which `middle` is treated as zero
+const MIDDLE_EPSILON: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-5
+} else {
+	1e-9
+};
+
+/// Bollinger Band Width
+///
+/// `BandWidth` = \(`upper bound` - `lower bound`\) / `middle` of the [`BollingerBands`](crate::indicators::BollingerBands)
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:bollinger_band_width>
+///
+/// # 2 values
+///
+/// * `band width`
+///
+/// Range of values is \[`0.0`; `+inf`\)
+///
+/// When `middle` value is negligible relative to the `source` value and to the standard deviation, `band width` is undefined,
+/// so the previous `band width` is returned and no squeeze signal is produced.
+///
+/// * lowest `band width` over the last `squeeze_period` values
+///
+/// Range of values is \[`0.0`; `+inf`\)
+///
+/// # 1 digital signal
+///
+/// When `band width` drops below its lowest value over the previous `squeeze_period` values (so called "squeeze"),
+/// then returns full buy signal. Otherwise returns no signal.
+///
+/// Squeeze itself is not directional, so this signal should be treated as a volatility regime flag.
+///
+/// Lowest value is initialized with zero `band width`, so there are no squeeze signals during the first `squeeze_period` values.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BandWidth {
+	/// Main period length. Default is `20`
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub avg_size: PeriodType,
+	/// Standard deviation multiplier for bounds. Default is `2.0`
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub sigma: ValueType,
+	/// Period for searching the lowest `band width`. Default is `125`
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub squeeze_period: PeriodType,
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(BandWidth);
+
+impl IndicatorConfig for BandWidth {
+	type Instance = BandWidthInstance;
+
+	const NAME: &'static str = "BandWidth";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = T::source(candle, cfg.source);
+		Ok(Self::Instance {
+			ma: SMA::new(cfg.avg_size, &src)?,
+			st_dev: StDev::new(cfg.avg_size, &src)?,
+			lowest: Lowest::new(cfg.squeeze_period, &0.0)?,
+			last_width: 0.0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.sigma > 0.0
+			&& self.avg_size > 2
+			&& self.avg_size < PeriodType::MAX
+			&& self.squeeze_period > 0
+			&& self.squeeze_period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"avg_size" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.avg_size = value,
+			},
+			"sigma" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.sigma = value,
+			},
+			"squeeze_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.squeeze_period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for BandWidth {
+	fn default() -> Self {
+		Self {
+			avg_size: 20,
+			sigma: 2.0,
+			squeeze_period: 125,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BandWidthInstance {
+	cfg: BandWidth,
+
+	ma: SMA,
+	st_dev: StDev,
+	lowest: Lowest,
+	last_width: ValueType,
+}
+
+impl IndicatorInstance for BandWidthInstance {
+	type Config = BandWidth;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["band_width", "lowest"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["squeeze"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+		let middle = self.ma.next(&source);
+		let sq_error = self.st_dev.next(&source);
+
+		let is_defined = middle.abs() > source.abs().max(sq_error) * MIDDLE_EPSILON;
+
+		// same as (upper - lower) / middle
+		let width = if is_defined {
+			(2.0 * self.cfg.sigma * sq_error / middle).abs()
+		} else {
+			self.last_width
+		};
+		self.last_width = width;
+
+		let prev_lowest = self.lowest.peek();
+		let lowest = self.lowest.next(&width);
+
+		let squeeze = is_defined && width < prev_lowest;
+
+		IndicatorResult::new(&[width, lowest], &[Action::from(squeeze as i8)])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::BandWidth;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(close: ValueType) -> Candle {
+		Candle {
+			open: close,
+			high: close,
+			low: close,
+			close,
+			volume: 1.0,
+		}
+	}
+
+	#[test]
+	fn test_band_width_const() {
+		let mut instance = BandWidth::default().init(&candle(10.0)).unwrap();
+
+		for _ in 0..300 {
+			let result = instance.next(&candle(10.0));
+
+			assert_eq_float(0.0, result.value(0));
+			assert!(result.signal(0).is_none());
+		}
+	}
+
+	#[test]
+	fn test_band_width_zero_middle() {
+		let cfg = BandWidth {
+			avg_size: 3,
+			squeeze_period: 10,
+			..BandWidth::default()
+		};
+		let mut instance = cfg.init(&candle(10.0)).unwrap();
+
+		// `middle` gets close to zero, so the width becomes undefined
+		let widths: Vec<_> = [0.1, 0.2, -0.3 + 1e-12]
+			.iter()
+			.cycle()
+			.take(100)
+			.enumerate()
+			.map(|(i, &close)| {
+				let result = instance.next(&candle(close));
+				assert!(result.signal(0).is_none() || i < 3);
+
+				result.value(0)
+			})
+			.collect();
+
+		assert!(widths[1].is_finite());
+		assert!(widths[2..]
+			.iter()
+			.all(|w| w.to_bits() == widths[1].to_bits()));
+	}
+
+	#[test]
+	fn test_band_width_squeeze() {
+		let cfg = BandWidth {
+			squeeze_period: 50,
+			..BandWidth::default()
+		};
+		let mut instance = cfg.init(&candle(100.0)).unwrap();
+
+		// wide swings first, then volatility contracts
+		let mut amplitude = 10.0;
+		let mut widths = Vec::new();
+		let mut squeezes = Vec::new();
+
+		for i in 0..400 {
+			if i >= 200 {
+				amplitude *= 0.98;
+			}
+
+			let close = if i % 2 == 0 {
+				100.0 + amplitude
+			} else {
+				100.0 - amplitude
+			};
+			let result = instance.next(&candle(close));
+
+			assert!(result.value(0) >= result.value(1));
+			widths.push(result.value(0));
+			squeezes.push(result.signal(0));
+		}
+
+		// no squeeze while volatility is stable
+		assert!(squeezes[100..200].iter().all(|s| s.is_none()));
+		// band width makes new lows while volatility contracts
+		assert!(widths[399] < widths[199]);
+		assert!(squeezes[200..].iter().any(|s| s.is_buy()));
+		assert!(squeezes[399].is_buy());
+	}
+}
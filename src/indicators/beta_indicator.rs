@@ -0,0 +1,352 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{CandleSource, IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{CovarianceMatrix, Cross};
+
+/// Rolling Beta of the instrument to the benchmark
+///
+/// Beta is calculated over the last `period` simple returns of both timeseries:
+///
+/// `Beta` = cov\(`instrument`, `benchmark`\) / var\(`benchmark`\)
+///
+/// It is a two-input indicator: instrument price is taken from the candle by `source`,
+/// while benchmark value must be provided by [`BetaIndicatorInstance::push_benchmark`]
+/// (or both at once by [`BetaIndicatorInstance::next_with`]).
+///
+/// Streams may update independently. A new sample is taken only when both of them have been updated,
+/// so when the instrument updates alone, its return is accumulated up to the moment the benchmark updates.
+/// Until then indicator repeats the last `beta` value and returns no signal.
+///
+/// When a return can't be calculated (the previous instrument price or benchmark value is zero), the sample is skipped,
+/// so indicator repeats the last `beta` value and returns no signal.
+///
+/// When benchmark variance is equal to zero (up to float rounding errors), `beta` is `0.0`.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Beta_(finance)>
+///
+/// # 1 value
+///
+/// * `beta` value
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// # 1 signal
+///
+/// * When `beta` crosses `1.0` upwards (leveraged regime), returns full buy signal.
+///   When `beta` crosses `1.0` downwards (defensive regime), returns full sell signal.
+///   Otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::indicators::BetaIndicator;
+///
+/// let candle = Candle { close: 10.0, ..Candle::default() };
+/// let mut beta = BetaIndicator::default().init(&candle).unwrap();
+///
+/// let _ = beta.next_with(&candle, 100.0);
+/// let _ = beta.next_with(&Candle { close: 12.0, ..candle }, 110.0);
+/// let result = beta.next_with(&Candle { close: 9.6, ..candle }, 99.0);
+///
+/// assert!((result.value(0) - 2.0).abs() < 1e-5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BetaIndicator {
+	/// Count of returns to calculate beta over. Default is `20`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// Instrument price source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(BetaIndicator);
+
+impl IndicatorConfig for BetaIndicator {
+	type Instance = BetaIndicatorInstance;
+
+	const NAME: &'static str = "BetaIndicator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			covariance: CovarianceMatrix::new(cfg.period, &[0.0, 0.0][..])?,
+			cross: Cross::new((), &(0.0, 1.0))?,
+			instrument: cfg.price(candle),
+			benchmark: None,
+			pending: None,
+			beta: 0.0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 1 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for BetaIndicator {
+	fn default() -> Self {
+		Self {
+			period: 20,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BetaIndicatorInstance {
+	cfg: BetaIndicator,
+
+	covariance: CovarianceMatrix,
+	cross: Cross,
+	/// last instrument price used for the sample
+	instrument: ValueType,
+	/// last benchmark value used for the sample
+	benchmark: Option<ValueType>,
+	/// benchmark value which is not used for the sample yet
+	pending: Option<ValueType>,
+	beta: ValueType,
+}
+
+impl BetaIndicatorInstance {
+	/// Updates benchmark value
+	///
+	/// It is used for the sample on the next call of [`next`](IndicatorInstance::next).
+	/// If benchmark is updated several times before that, only the last value is used.
+	#[allow(clippy::missing_const_for_fn)]
+	pub fn push_benchmark(&mut self, value: ValueType) {
+		self.pending = Some(value);
+	}
+
+	/// Updates both instrument and benchmark at once
+	pub fn next_with<T: OHLCV>(&mut self, candle: &T, benchmark: ValueType) -> IndicatorResult {
+		self.push_benchmark(benchmark);
+		self.next(candle)
+	}
+
+	/// Returns current `beta` value
+	#[must_use]
+	pub const fn beta(&self) -> ValueType {
+		self.beta
+	}
+}
+
+/// Benchmark deviation below this share of the benchmark return is treated as zero
+const DEVIATION_EPSILON: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-3
+} else {
+	1e-6
+};
+
+/// Returns `None` when the return is not a finite number
+#[inline]
+fn simple_return(value: ValueType, prev: ValueType) -> Option<ValueType> {
+	Some(value / prev - 1.0).filter(|x| x.is_finite())
+}
+
+impl IndicatorInstance for BetaIndicatorInstance {
+	type Config = BetaIndicator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["beta"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["regime"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let Some(benchmark) = self.pending.take() else {
+			return IndicatorResult::new(&[self.beta], &[Action::None]);
+		};
+
+		let instrument = self.cfg.price(candle);
+
+		let Some(prev_benchmark) = self.benchmark.replace(benchmark) else {
+			// the very first benchmark value: nothing to compare with yet
+			self.instrument = instrument;
+			return IndicatorResult::new(&[self.beta], &[Action::None]);
+		};
+
+		let returns = (
+			simple_return(instrument, self.instrument),
+			simple_return(benchmark, prev_benchmark),
+		);
+		self.instrument = instrument;
+
+		let (Some(instrument_return), Some(benchmark_return)) = returns else {
+			return IndicatorResult::new(&[self.beta], &[Action::None]);
+		};
+		let returns = [instrument_return, benchmark_return];
+
+		let matrix = self.covariance.next_matrix(&returns[..]);
+		let variance = matrix[3];
+
+		// constant benchmark returns may leave tiny non-zero variance because of float rounding errors
+		self.beta = if variance.sqrt() > returns[1].abs() * DEVIATION_EPSILON {
+			matrix[1] / variance
+		} else {
+			0.0
+		};
+
+		let signal = self.cross.next(&(self.beta, 1.0));
+
+		IndicatorResult::new(&[self.beta], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::BetaIndicator;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	fn candle(close: ValueType) -> Candle {
+		Candle {
+			close,
+			..Candle::default()
+		}
+	}
+
+	#[test]
+	fn test_beta_indicator_leveraged() {
+		let benchmark: Vec<ValueType> = RandomCandles::new().take(300).map(|x| x.close).collect();
+
+		// instrument moves exactly 1.5 times the benchmark
+		let mut instrument = vec![10.0];
+		for pair in benchmark.windows(2) {
+			let ret = pair[1] / pair[0] - 1.0;
+			let last = instrument[instrument.len() - 1];
+			instrument.push(last * ret.mul_add(1.5, 1.0));
+		}
+
+		let mut beta = BetaIndicator::default()
+			.init(&candle(instrument[0]))
+			.unwrap();
+
+		let results: Vec<_> = instrument
+			.iter()
+			.zip(&benchmark)
+			.map(|(&price, &value)| beta.next_with(&candle(price), value))
+			.collect();
+
+		assert_eq_float(0.0, results[0].value(0));
+		assert_eq!(results[1].signal(0), Action::BUY_ALL);
+
+		for result in &results[1..] {
+			assert_eq_float(1.5, result.value(0));
+		}
+
+		assert_eq_float(1.5, beta.beta());
+	}
+
+	#[test]
+	fn test_beta_indicator_alignment() {
+		let mut beta = BetaIndicator::default().init(&candle(10.0)).unwrap();
+		let _ = beta.next_with(&candle(10.0), 100.0);
+
+		// instrument updates alone: no sample is taken, so the return is accumulated
+		let result = beta.next(&candle(11.0));
+		assert_eq_float(0.0, result.value(0));
+		assert_eq!(result.signal(0), Action::None);
+
+		// benchmark updates several times before the instrument: only the last value is used
+		beta.push_benchmark(150.0);
+		beta.push_benchmark(105.0);
+		let result = beta.next(&candle(12.0));
+		assert_eq_float(4.0, result.value(0));
+		assert_eq!(result.signal(0), Action::BUY_ALL);
+
+		let result = beta.next_with(&candle(9.6), 99.75);
+
+		// returns are `+20%, -20%` for the instrument and `+5%, -5%` for the benchmark
+		assert_eq_float(4.0, result.value(0));
+		assert_eq!(result.signal(0), Action::None);
+	}
+
+	#[test]
+	fn test_beta_indicator_zero_prices() {
+		let mut beta = BetaIndicator::default().init(&candle(10.0)).unwrap();
+		let _ = beta.next_with(&candle(10.0), 100.0);
+		let _ = beta.next_with(&candle(12.0), 110.0);
+
+		// return from the zero price is not defined, so the next sample is skipped
+		let expected = beta.next_with(&candle(0.0), 99.0).value(0);
+		let result = beta.next_with(&candle(9.6), 100.0);
+		assert_eq!(result.value(0).to_bits(), expected.to_bits());
+		assert_eq!(result.signal(0), Action::None);
+
+		// same for the zero benchmark value
+		let expected = beta.next_with(&candle(9.0), 0.0).value(0);
+		let result = beta.next_with(&candle(9.6), 100.0);
+		assert_eq!(result.value(0).to_bits(), expected.to_bits());
+		assert_eq!(result.signal(0), Action::None);
+
+		assert!(beta.next_with(&candle(10.0), 110.0).value(0).is_finite());
+	}
+
+	#[test]
+	fn test_beta_indicator_constant_benchmark_returns() {
+		let instrument: Vec<ValueType> = RandomCandles::new().take(100).map(|x| x.close).collect();
+		// every benchmark return is the same, up to float rounding errors
+		let benchmark: Vec<ValueType> = (0..instrument.len())
+			.map(|i| 100.0 * (0.01 * i as ValueType).exp())
+			.collect();
+
+		let mut beta = BetaIndicator::default()
+			.init(&candle(instrument[0]))
+			.unwrap();
+
+		let results: Vec<_> = instrument
+			.iter()
+			.zip(&benchmark)
+			.map(|(&price, &value)| beta.next_with(&candle(price), value))
+			.collect();
+
+		// the window is filled with the initial zero returns at first
+		for result in &results[25..] {
+			assert_eq_float(0.0, result.value(0));
+			assert_eq!(result.signal(0), Action::None);
+		}
+	}
+}
@@ -42,6 +42,8 @@ pub struct BollingerBands {
 	pub source: Source,
 }
 
+impl_candle_source!(BollingerBands);
+
 impl IndicatorConfig for BollingerBands {
 	type Instance = BollingerBandsInstance;
 
@@ -91,6 +93,10 @@ impl IndicatorConfig for BollingerBands {
 	fn size(&self) -> (u8, u8) {
 		(3, 1)
 	}
+
+	fn warmup_period(&self) -> Option<usize> {
+		Some((self.avg_size as usize).saturating_sub(1))
+	}
 }
 
 impl Default for BollingerBands {
@@ -120,6 +126,14 @@ impl IndicatorInstance for BollingerBandsInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["upper", "middle", "lower"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["relative_position"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let source = candle.source(self.cfg.source);
 		let middle = self.ma.next(&source);
@@ -98,6 +98,14 @@ impl IndicatorInstance for ChaikinMoneyFlowInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["cmf"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_line"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let adi = self.adi.next(candle);
 		self.vol_sum += candle.volume() - self.window.push(candle.volume());
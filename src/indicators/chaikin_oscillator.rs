@@ -128,6 +128,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for ChaikinOscillatorInstanc
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["oscillator"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_line"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let adi = self.adi.next(candle);
 
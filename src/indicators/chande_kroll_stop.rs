@@ -7,7 +7,7 @@ use crate::core::{
 };
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::{signi, MA};
-use crate::methods::{CrossAbove, Highest, Lowest};
+use crate::methods::{CrossAbove, Highest, Lowest, TR};
 
 /// Chande Kroll Stop
 ///
@@ -59,6 +59,8 @@ pub struct ChandeKrollStop<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(ChandeKrollStop<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for ChandeKrollStop<M> {
 	type Instance = ChandeKrollStopInstance<M>;
 
@@ -81,7 +83,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for ChandeKrollStop<M> {
 			highest2: Highest::new(cfg.q, &cfg.x.mul_add(-tr, candle.high()))?,
 			lowest2: Lowest::new(cfg.q, &cfg.x.mul_add(tr, candle.low()))?,
 
-			prev_close: candle.close(),
+			tr: TR::new(candle)?,
 			prev_stop_short: cfg.x.mul_add(-tr, candle.high()),
 			prev_stop_long: cfg.x.mul_add(tr, candle.low()),
 			cross_above: CrossAbove::new(
@@ -153,7 +155,7 @@ pub struct ChandeKrollStopInstance<M: MovingAverageConstructor = MA> {
 	lowest1: Lowest,
 	highest2: Highest,
 	lowest2: Lowest,
-	prev_close: ValueType,
+	tr: TR,
 	prev_stop_short: ValueType,
 	prev_stop_long: ValueType,
 	cross_above: CrossAbove,
@@ -166,10 +168,17 @@ impl<M: MovingAverageConstructor> IndicatorInstance for ChandeKrollStopInstance<
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["stop_long", "source", "stop_short"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["position", "stops_cross"]
+	}
+
 	#[allow(clippy::similar_names)]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
-		let tr = candle.tr_close(self.prev_close);
-		self.prev_close = candle.close();
+		let tr = self.tr.next(candle);
 
 		let atr = self.ma.next(&tr);
 
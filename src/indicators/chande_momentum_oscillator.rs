@@ -37,6 +37,8 @@ pub struct ChandeMomentumOscillator {
 	pub source: Source,
 }
 
+impl_candle_source!(ChandeMomentumOscillator);
+
 impl IndicatorConfig for ChandeMomentumOscillator {
 	type Instance = ChandeMomentumOscillatorInstance;
 
@@ -132,6 +134,14 @@ impl IndicatorInstance for ChandeMomentumOscillatorInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["oscillator"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zone"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let ch = self.change.next(&candle.source(self.cfg.source));
 
@@ -0,0 +1,301 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Highest, Lowest, RMA, TR};
+
+/// Chandelier Exit
+///
+/// `ATR`-based trailing stops hung from the highest high (for long positions) or from the lowest low (for short positions)
+/// over the last `period` candles:
+///
+/// `long stop` = [`Highest`](crate::methods::Highest)\(`high`\) - `atr_mult` \* `ATR`
+///
+/// `short stop` = [`Lowest`](crate::methods::Lowest)\(`low`\) + `atr_mult` \* `ATR`
+///
+/// `ATR` is calculated as [`RMA`](crate::methods::RMA) of [`TR`](crate::methods::TR) over the same `period`.
+///
+/// Stops are trailing: while previous `close` is above previous `long stop`, `long stop` never moves down.
+/// While previous `close` is below previous `short stop`, `short stop` never moves up.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/c/chandelierexit.asp>
+///
+/// # 2 values
+///
+/// * `long stop`
+///
+/// Range of values is the same as the range of the prices.
+///
+/// * `short stop`
+///
+/// Range of values is the same as the range of the prices.
+///
+/// # 1 signal
+///
+/// * Tracks the current position. While long, when `close` falls below previous `long stop` (exit long),
+///   returns full sell signal and the position becomes short.
+///   While short, when `close` rises above previous `short stop` (exit short),
+///   returns full buy signal and the position becomes long.
+///   Before the first signal there is no position, so both exits are checked.
+///   Otherwise returns no signal.
+///
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChandelierExit {
+	/// Highest/lowest and `ATR` period. Default is `22`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+
+	/// `ATR` multiplier. Default is `3.0`.
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub atr_mult: ValueType,
+}
+
+impl IndicatorConfig for ChandelierExit {
+	type Instance = ChandelierExitInstance;
+
+	const NAME: &'static str = "ChandelierExit";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let tr = candle.high() - candle.low();
+
+		Ok(Self::Instance {
+			highest: Highest::new(cfg.period, &candle.high())?,
+			lowest: Lowest::new(cfg.period, &candle.low())?,
+			tr: TR::new(candle)?,
+			atr: RMA::new(cfg.period, &tr)?,
+			long_stop: cfg.atr_mult.mul_add(-tr, candle.high()),
+			short_stop: cfg.atr_mult.mul_add(tr, candle.low()),
+			prev_close: candle.close(),
+			direction: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX && self.atr_mult > 0.0
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"atr_mult" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.atr_mult = value,
+			},
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for ChandelierExit {
+	fn default() -> Self {
+		Self {
+			period: 22,
+			atr_mult: 3.0,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChandelierExitInstance {
+	cfg: ChandelierExit,
+
+	highest: Highest,
+	lowest: Lowest,
+	tr: TR,
+	atr: RMA,
+	long_stop: ValueType,
+	short_stop: ValueType,
+	prev_close: ValueType,
+	direction: i8,
+}
+
+impl IndicatorInstance for ChandelierExitInstance {
+	type Config = ChandelierExit;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["long_stop", "short_stop"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["exit"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let atr = self.atr.next(&self.tr.next(candle)) * self.cfg.atr_mult;
+
+		let mut long_stop = self.highest.next(&candle.high()) - atr;
+		let mut short_stop = self.lowest.next(&candle.low()) + atr;
+
+		if self.prev_close > self.long_stop {
+			long_stop = long_stop.max(self.long_stop);
+		}
+
+		if self.prev_close < self.short_stop {
+			short_stop = short_stop.min(self.short_stop);
+		}
+
+		let close = candle.close();
+		let exit_long = close < self.long_stop;
+		let exit_short = close > self.short_stop;
+
+		// every exit is checked against the current position only
+		let direction = match self.direction {
+			-1 | 0 if exit_short => 1,
+			1 | 0 if exit_long => -1,
+			direction => direction,
+		};
+
+		let signal = if direction == self.direction {
+			Action::None
+		} else {
+			Action::from(direction)
+		};
+
+		self.long_stop = long_stop;
+		self.short_stop = short_stop;
+		self.prev_close = close;
+		self.direction = direction;
+
+		IndicatorResult::new(&[long_stop, short_stop], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ChandelierExit;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_chandelier_exit_const() {
+		let candle = Candle {
+			open: 10.0,
+			high: 12.0,
+			low: 9.0,
+			close: 11.0,
+			volume: 1.0,
+		};
+		let mut instance = ChandelierExit::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq_float(3.0, result.value(0));
+			assert_eq_float(18.0, result.value(1));
+		}
+	}
+
+	#[test]
+	fn test_chandelier_exit_ratchet() {
+		// uptrend with occasional volatility spikes, which push raw `long stop` down
+		let candles: Vec<Candle> = (0..200)
+			.map(|i| {
+				let close = 100.0 + i as ValueType;
+				let range = if i % 7 == 0 { 6.0 } else { 1.0 };
+
+				Candle {
+					open: close - 0.5,
+					high: close + 0.5,
+					low: close - range,
+					close,
+					volume: 1.0,
+				}
+			})
+			.collect();
+
+		let mut instance = ChandelierExit::default().init(&candles[0]).unwrap();
+		let results: Vec<_> = candles.iter().map(|x| instance.next(x)).collect();
+
+		for (i, pair) in results.windows(2).enumerate() {
+			let (prev, result) = (pair[0].value(0), pair[1].value(0));
+
+			assert!(
+				result >= prev,
+				"long stop moved down at {i}: {prev} => {result}"
+			);
+			assert!(result < candles[i + 1].close);
+			assert_ne!(pair[1].signal(0), Action::SELL_ALL);
+		}
+
+		assert!(results[199].value(0) > results[0].value(0));
+	}
+
+	#[test]
+	fn test_chandelier_exit_long_exit() {
+		let candle = |close: ValueType| Candle {
+			open: close,
+			high: close + 1.0,
+			low: close - 1.0,
+			close,
+			volume: 1.0,
+		};
+
+		let cfg = ChandelierExit {
+			period: 3,
+			atr_mult: 1.0,
+		};
+		let mut instance = cfg.init(&candle(100.0)).unwrap();
+
+		for close in [101.0, 102.0, 103.0, 104.0] {
+			let _ = instance.next(&candle(close));
+		}
+
+		// `long stop` is about `104 + 1 - 2 = 103`
+		let result = instance.next(&candle(95.0));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+	}
+
+	#[test]
+	fn test_chandelier_exit_trend_then_drop() {
+		let candle = |close: ValueType| Candle {
+			open: close,
+			high: close + 1.0,
+			low: close - 1.0,
+			close,
+			volume: 1.0,
+		};
+
+		let candles: Vec<_> = (0..60).map(|i| candle(100.0 + i as ValueType)).collect();
+		let mut instance = ChandelierExit::default().init(&candles[0]).unwrap();
+
+		let signals: Vec<_> = candles.iter().map(|x| instance.next(x).signal(0)).collect();
+		assert_eq!(signals.iter().filter(|s| s.is_some()).count(), 1);
+		assert_eq!(signals.iter().find(|s| s.is_some()), Some(&Action::BUY_ALL));
+
+		// `close` falls below `long stop` (about `160 - 3 * 2 = 154`), but stays above `short stop`
+		let result = instance.next(&candle(150.0));
+		assert!(150.0 < result.value(0) && 150.0 > result.value(1));
+		assert_eq!(result.signal(0), Action::SELL_ALL);
+
+		// already short, so the next candle below both stops does not repeat the signal
+		assert_eq!(instance.next(&candle(140.0)).signal(0), Action::None);
+	}
+}
@@ -40,6 +40,8 @@ pub struct CommodityChannelIndex {
 	pub source: Source,
 }
 
+impl_candle_source!(CommodityChannelIndex);
+
 impl IndicatorConfig for CommodityChannelIndex {
 	type Instance = CommodityChannelIndexInstance;
 
@@ -121,6 +123,14 @@ impl IndicatorInstance for CommodityChannelIndexInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["cci"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zone"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let value = candle.source(self.cfg.source);
 
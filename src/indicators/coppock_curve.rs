@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::MA;
-use crate::methods::{Cross, RateOfChange, ReversalSignal};
+use crate::methods::{Cross, ReversalSignal, SmoothedRocSum, SmoothedRocSumParams};
 
 /// Coppock curve
 ///
@@ -68,6 +68,8 @@ pub struct CoppockCurve<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(CoppockCurve<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for CoppockCurve<M> {
 	type Instance = CoppockCurveInstance<M>;
 
@@ -81,9 +83,12 @@ impl<M: MovingAverageConstructor> IndicatorConfig for CoppockCurve<M> {
 		let cfg = self;
 		let src = &candle.source(cfg.source);
 		Ok(Self::Instance {
-			roc1: RateOfChange::new(cfg.period2, src)?,
-			roc2: RateOfChange::new(cfg.period3, src)?,
-			ma1: cfg.ma1.init(0.)?,   // method(cfg.method1, cfg.period1, 0.)?,
+			roc_sum: SmoothedRocSum::new(
+				SmoothedRocSumParams::new(cfg.ma1.clone())
+					.roc(cfg.period2, 1.0)
+					.roc(cfg.period3, 1.0),
+				src,
+			)?,
 			ma2: cfg.s3_ma.init(0.)?, //method(cfg.method2, cfg.s3_period, 0.)?,
 			cross_over1: Cross::default(),
 			pivot: ReversalSignal::new(cfg.s2_left, cfg.s2_right, &0.)?,
@@ -168,9 +173,7 @@ impl Default for CoppockCurve<MA> {
 pub struct CoppockCurveInstance<M: MovingAverageConstructor = MA> {
 	cfg: CoppockCurve<M>,
 
-	roc1: RateOfChange,
-	roc2: RateOfChange,
-	ma1: M::Instance,
+	roc_sum: SmoothedRocSum<M>,
 	ma2: M::Instance,
 	cross_over1: Cross,
 	pivot: ReversalSignal,
@@ -184,11 +187,17 @@ impl<M: MovingAverageConstructor> IndicatorInstance for CoppockCurveInstance<M>
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["main", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_cross", "reversal", "signal_line_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = &candle.source(self.cfg.source);
-		let roc1 = self.roc1.next(src);
-		let roc2 = self.roc2.next(src);
-		let value1 = self.ma1.next(&(roc1 + roc2));
+		let value1 = self.roc_sum.next(src);
 		let value2 = self.ma2.next(&value1);
 
 		let signal1 = self.cross_over1.next(&(value1, 0.));
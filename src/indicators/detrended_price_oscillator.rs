@@ -6,6 +6,7 @@ use crate::core::{
 };
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::MA;
+use crate::methods::Cross;
 
 // The Formula for the Detrended Price Oscillator (DPO) is
 // DPO=Price from X2+1 periods ago−X period SMA
@@ -20,13 +21,25 @@ use crate::helpers::MA;
 ///
 /// * <https://en.wikipedia.org/wiki/Detrended_price_oscillator>
 ///
+/// `DPO` = `source`\[`period` / `2` + `1` bars ago\] - `MA`\(`period`\)
+///
+/// where `period` is the period of the main moving average and `period` / `2` is rounded down.
+/// F.e. for `SMA(21)` the source value is taken `11` bars ago.
+///
+/// Note that `DPO` is not anchored to the latest bar: it compares the moving average with the displaced (past) `source` value,
+/// so it shows cycles in the past rather than the current state of the price.
+///
 /// # 1 value
 ///
 /// * `DPO` value
 ///
 /// Range in \(`-inf`; `+inf`\).
 ///
-/// # Has no signals
+/// # 1 signal
+///
+/// * When `DPO` value crosses zero line upwards, returns full buy signal.
+///   When `DPO` value crosses zero line downwards, returns full sell signal.
+///   Otherwise returns no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DetrendedPriceOscillator<M: MovingAverageConstructor = MA> {
@@ -40,6 +53,8 @@ pub struct DetrendedPriceOscillator<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(DetrendedPriceOscillator<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for DetrendedPriceOscillator<M> {
 	type Instance = DetrendedPriceOscillatorInstance<M>;
 
@@ -56,6 +71,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for DetrendedPriceOscillator<M
 		Ok(Self::Instance {
 			sma: cfg.ma.init(src)?, // method(cfg.method, cfg.period, src)?,
 			window: Window::new(cfg.ma.ma_period() / 2 + 1, src),
+			cross: Cross::default(),
 			cfg,
 		})
 	}
@@ -84,7 +100,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for DetrendedPriceOscillator<M
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 0)
+		(1, 1)
 	}
 }
 
@@ -104,6 +120,7 @@ pub struct DetrendedPriceOscillatorInstance<M: MovingAverageConstructor = MA> {
 
 	sma: M::Instance,
 	window: Window<ValueType>,
+	cross: Cross,
 }
 
 impl<M: MovingAverageConstructor> IndicatorInstance for DetrendedPriceOscillatorInstance<M> {
@@ -113,6 +130,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for DetrendedPriceOscillator
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["dpo"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -121,6 +146,41 @@ impl<M: MovingAverageConstructor> IndicatorInstance for DetrendedPriceOscillator
 
 		let dpo = left_src - sma;
 
-		IndicatorResult::new(&[dpo], &[])
+		let signal = self.cross.next(&(dpo, 0.0));
+
+		IndicatorResult::new(&[dpo], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::DetrendedPriceOscillator;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::{assert_eq_float, MA};
+
+	#[test]
+	fn test_dpo_const() {
+		let candle = Candle {
+			open: 5.0,
+			high: 7.0,
+			low: 3.0,
+			close: 6.0,
+			volume: 1.0,
+		};
+
+		for period in 2..50 {
+			let cfg = DetrendedPriceOscillator {
+				ma: MA::SMA(period),
+				..DetrendedPriceOscillator::default()
+			};
+			let mut instance = cfg.init(&candle).unwrap();
+
+			for _ in 0..100 {
+				let result = instance.next(&candle);
+
+				assert_eq_float(0.0, result.value(0));
+				assert!(result.signal(0).is_none());
+			}
+		}
 	}
 }
@@ -109,6 +109,14 @@ impl IndicatorInstance for DonchianChannelInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["lower", "middle", "upper"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["breakout"]
+	}
+
 	#[inline]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (high, low) = (candle.high(), candle.low());
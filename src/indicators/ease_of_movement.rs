@@ -2,7 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::HLC;
-use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Window, OHLCV};
+use crate::core::{
+	Action, Error, Method, MovingAverageConstructor, PeriodType, ValueType, Window, OHLCV,
+};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::MA;
 use crate::methods::Cross;
@@ -20,6 +22,11 @@ use crate::methods::Cross;
 ///
 /// Range in \(`-inf`; `+inf`\)
 ///
+/// When candle's `high` is equal to it's `low`, raw value for this candle is `0.0`.
+///
+/// When candle's `volume` is `0.0`, raw value for this candle is undefined, so the candle is skipped:
+/// the previous main value is returned and there is no signal.
+///
 /// # 1 signal
 ///
 /// * Signal 1 appears when `main value` crosses zero line.
@@ -55,6 +62,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for EaseOfMovement<M> {
 			m1: cfg.ma.init(0.)?, //method(cfg.method, cfg.period1, 0.)?,
 			w: Window::new(cfg.period2, HLC::from(candle)),
 			cross: Cross::new((), &(0.0, 0.0))?,
+			last_value: 0.0,
 
 			cfg,
 		})
@@ -105,6 +113,7 @@ pub struct EaseOfMovementInstance<M: MovingAverageConstructor = MA> {
 	m1: M::Instance,
 	w: Window<HLC>,
 	cross: Cross,
+	last_value: ValueType,
 }
 
 impl<M: MovingAverageConstructor> IndicatorInstance for EaseOfMovementInstance<M> {
@@ -114,6 +123,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EaseOfMovementInstance<M
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["eom"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let prev_candle = self.w.push(HLC::from(candle));
 
@@ -122,15 +139,17 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EaseOfMovementInstance<M
 
 		let d = (d_high + d_low) * 0.5;
 
-		let v = if candle.volume() == 0.0 {
-			0.0
-		} else {
-			d * (candle.high() - candle.low()) / candle.volume()
-		};
+		let range = candle.high() - candle.low();
 
-		debug_assert!(v.is_finite() && !v.is_nan());
+		// same as d / (volume / range), but without dividing by zero when range is zero
+		let v = d * range / candle.volume();
+
+		if !v.is_finite() {
+			return IndicatorResult::new(&[self.last_value], &[Action::None]);
+		}
 
 		let value = self.m1.next(&v);
+		self.last_value = value;
 
 		// let signal = if value > 0. {
 		// 	1
@@ -144,3 +163,80 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EaseOfMovementInstance<M
 		IndicatorResult::new(&[value], &[signal])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::EaseOfMovement;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(price: ValueType, volume: ValueType) -> Candle {
+		Candle {
+			open: price,
+			high: price + 1.0,
+			low: price - 1.0,
+			close: price,
+			volume,
+		}
+	}
+
+	fn last_value(volume: ValueType) -> ValueType {
+		let mut instance = EaseOfMovement::default()
+			.init(&candle(10.0, volume))
+			.unwrap();
+
+		(1..50)
+			.map(|i| {
+				instance
+					.next(&candle(10.0 + i as ValueType, volume))
+					.value(0)
+			})
+			.last()
+			.unwrap()
+	}
+
+	#[test]
+	fn test_ease_of_movement_rising_low_volume() {
+		let low_volume = last_value(10.0);
+		let high_volume = last_value(1000.0);
+
+		// d = 1.0, range = 2.0
+		assert_eq_float(0.2, low_volume);
+		assert_eq_float(0.002, high_volume);
+		assert!(low_volume > high_volume);
+	}
+
+	#[test]
+	fn test_ease_of_movement_zero_volume_and_range() {
+		let flat = |price: ValueType, volume: ValueType| Candle {
+			high: price,
+			low: price,
+			..candle(price, volume)
+		};
+
+		let mut instance = EaseOfMovement::default().init(&flat(10.0, 0.0)).unwrap();
+
+		for i in 1..50 {
+			let price = 10.0 + i as ValueType;
+
+			for c in [flat(price, 10.0), candle(price, 0.0)] {
+				let result = instance.next(&c);
+				assert_eq_float(0.0, result.value(0));
+				assert!(result.signal(0).is_none());
+			}
+		}
+
+		let mut instance = EaseOfMovement::default().init(&candle(10.0, 10.0)).unwrap();
+		for i in 1..50 {
+			let _ = instance.next(&candle(10.0 + i as ValueType, 10.0));
+		}
+		let value = instance.next(&candle(60.0, 10.0)).value(0);
+
+		// candles without volume are skipped
+		for i in 1..50 {
+			let result = instance.next(&candle(60.0 - i as ValueType, 0.0));
+			assert_eq!(result.value(0).to_bits(), value.to_bits());
+			assert!(result.signal(0).is_none());
+		}
+	}
+}
@@ -0,0 +1,192 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, MovingAverageConstructor, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::MA;
+use crate::indicators::{MACDInstance, MACD};
+
+/// Elder Impulse System
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=chart_analysis:elder_impulse_system>
+///
+/// # 2 values
+///
+/// * `MA` value
+///
+/// Range is the same as the range of the `MACD`'s `source` values.
+///
+/// * `MACD histogram` value (`MACD` - `Signal line`)
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// # 1 signal
+///
+/// * When both `MA` and `MACD histogram` rise (green bar), returns full buy signal.
+///   When both `MA` and `MACD histogram` fall (red bar), returns full sell signal.
+///   Otherwise (blue bar) returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderImpulse<M: MovingAverageConstructor = MA> {
+	/// Trend moving average type.
+	///
+	/// Default is [`EMA(13)`](crate::methods::EMA).
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub ma: M,
+
+	/// MACD configuration for momentum. Default is [`MACD::default`](crate::indicators::MACD).
+	///
+	/// `source` of the `MACD` is also used as a source for the trend moving average.
+	pub macd: MACD<M>,
+}
+
+impl<M: MovingAverageConstructor> IndicatorConfig for ElderImpulse<M> {
+	type Instance = ElderImpulseInstance<M>;
+
+	const NAME: &'static str = "ElderImpulse";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.macd.source);
+
+		Ok(Self::Instance {
+			ma: cfg.ma.init(src)?,
+			macd: cfg.macd.clone().init(candle)?,
+			prev_ma: src,
+			prev_histogram: 0.0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.ma.ma_period() > 1 && self.macd.validate()
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"ma" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.ma = value,
+			},
+			"ma1" | "ma2" | "signal" | "source" => self.macd.set(name, value)?,
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 1)
+	}
+}
+
+impl Default for ElderImpulse<MA> {
+	fn default() -> Self {
+		Self {
+			ma: MA::EMA(13),
+			macd: MACD::default(),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElderImpulseInstance<M: MovingAverageConstructor = MA> {
+	cfg: ElderImpulse<M>,
+
+	ma: M::Instance,
+	macd: MACDInstance<M>,
+	prev_ma: ValueType,
+	prev_histogram: ValueType,
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for ElderImpulseInstance<M> {
+	type Config = ElderImpulse<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["ma", "macd_histogram"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["impulse"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.macd.source);
+
+		let ma = self.ma.next(&src);
+		let macd = self.macd.next(candle);
+		let histogram = macd.value(0) - macd.value(1);
+
+		let ma_slope = (ma > self.prev_ma) as i8 - (ma < self.prev_ma) as i8;
+		let histogram_slope =
+			(histogram > self.prev_histogram) as i8 - (histogram < self.prev_histogram) as i8;
+
+		self.prev_ma = ma;
+		self.prev_histogram = histogram;
+
+		let signal = if ma_slope == histogram_slope {
+			Action::from(ma_slope)
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[ma, histogram], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ElderImpulse;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+
+	const GROWTH: ValueType = 1.05;
+
+	fn impulses(prices: impl Iterator<Item = ValueType>) -> Vec<Action> {
+		let candles: Vec<Candle> = prices
+			.map(|close| Candle {
+				open: close,
+				high: close,
+				low: close,
+				close,
+				volume: 1.0,
+			})
+			.collect();
+
+		let mut instance = ElderImpulse::default().init(&candles[0]).unwrap();
+
+		candles
+			.iter()
+			.map(|candle| instance.next(candle).signal(0))
+			.skip(50)
+			.collect()
+	}
+
+	#[test]
+	fn test_elder_impulse_uptrend() {
+		let signals = impulses((0..150).map(|x| GROWTH.powi(x)));
+
+		assert!(signals.iter().all(|&s| s == Action::BUY_ALL));
+	}
+
+	#[test]
+	fn test_elder_impulse_downtrend() {
+		let signals = impulses((0..150).map(|x| 10000. - GROWTH.powi(x)));
+
+		assert!(signals.iter().all(|&s| s == Action::SELL_ALL));
+	}
+}
@@ -42,6 +42,8 @@ pub struct EldersForceIndex<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(EldersForceIndex<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for EldersForceIndex<M> {
 	type Instance = EldersForceIndexInstance<M>;
 
@@ -122,6 +124,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EldersForceIndexInstance
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["efi"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let left_candle = self.window.push(Candle::from(candle));
 
@@ -11,7 +11,7 @@ use crate::helpers::MA;
 ///
 /// * <https://www.investopedia.com/terms/e/envelope.asp>
 ///
-/// # 3 values
+/// # 4 values
 ///
 /// * `Upper bound`
 ///
@@ -19,10 +19,14 @@ use crate::helpers::MA;
 ///
 /// * `Lower bound`
 ///
-/// Range of values is the same as the range of the `source` values.Action
+/// Range of values is the same as the range of the `source` values.
 ///
 /// *  Raw `Source2` value
 ///
+/// * `Middle line` (moving average of the `source` values)
+///
+/// Range of values is the same as the range of the `source` values.
+///
 /// # 1 signal
 ///
 /// * Signal 1 appears when `Source2` value crosses bounds.
@@ -47,6 +51,8 @@ pub struct Envelopes<M: MovingAverageConstructor = MA> {
 	pub source2: Source,
 }
 
+impl_candle_source!(Envelopes<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
 	type Instance = EnvelopesInstance<M>;
 
@@ -100,7 +106,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for Envelopes<M> {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(3, 1)
+		(4, 1)
 	}
 }
 
@@ -132,6 +138,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EnvelopesInstance<M> {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["upper", "lower", "source2", "middle"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["bounds_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 		let v = self.ma.next(&src);
@@ -149,6 +163,35 @@ impl<M: MovingAverageConstructor> IndicatorInstance for EnvelopesInstance<M> {
 
 		let signal = (src2 < value2) as i8 - (src2 > value1) as i8;
 
-		IndicatorResult::new(&[value1, value2, src2], &[Action::from(signal)])
+		IndicatorResult::new(&[value1, value2, src2, v], &[Action::from(signal)])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Envelopes;
+	use crate::core::{IndicatorConfig, IndicatorInstance};
+	use crate::helpers::{assert_eq_float, RandomCandles, MA};
+
+	#[test]
+	fn test_envelopes_bands() {
+		for k in [0.01, 0.1, 0.5] {
+			let cfg = Envelopes {
+				ma: MA::SMA(10),
+				k,
+				..Envelopes::default()
+			};
+			let candles = RandomCandles::new();
+			let mut instance = cfg.init(&candles.first()).unwrap();
+
+			candles.take(300).for_each(|candle| {
+				let result = instance.next(&candle);
+				let (upper, lower, middle) = (result.value(0), result.value(1), result.value(3));
+
+				assert!(upper > middle && middle > lower);
+				assert_eq_float(upper - middle, middle - lower);
+				assert_eq_float(middle * k, upper - middle);
+			});
+		}
 	}
 }
@@ -3,9 +3,13 @@
 //! It has a **Configuration** with parameters `price`, `period` and `source`.
 //!
 //! The idea is to find signals where price of timeseries crosses this config's `price` for the last `period` frames.
+//!
+//! Price of timeseries is selected by `source` parameter through [`CandleSource`](crate::core::CandleSource) trait.
 
 // Some core structures and traits
-use crate::core::{Action, Error, IndicatorResult, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{
+	Action, CandleSource, Error, IndicatorResult, PeriodType, Source, ValueType, OHLCV,
+};
 use crate::prelude::*;
 
 // Cross method for searching crossover between price and our value
@@ -26,11 +30,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Example {
+	/// Price level to search crosses with. Default is `2.0`.
 	price: ValueType,
+	/// Count of frames to hold the signal after the cross. Default is `3`.
 	period: PeriodType,
+	/// Source of the timeseries price. Default is [`Close`](crate::core::Source::Close).
 	source: Source,
 }
 
+/// Implementing [`CandleSource`](crate::core::CandleSource) trait, so the price is extracted from the candle by `source`
+impl CandleSource for Example {
+	fn source(&self) -> Source {
+		self.source
+	}
+}
+
 /// Implementing [`IndicatorConfig`](crate::core::IndicatorConfig) trait
 impl IndicatorConfig for Example {
 	type Instance = ExampleInstance;
@@ -63,6 +77,14 @@ impl IndicatorConfig for Example {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.price = value,
 			},
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -111,15 +133,20 @@ impl IndicatorInstance for ExampleInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["price"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["signal", "some_other_signal"]
+	}
+
 	/// Calculates next value by giving [`OHLCV`](crate::core::OHLCV)-object
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
-		let new_signal = self.cross.next(&(candle.close(), self.cfg.price));
+		let price = self.cfg.price(candle);
+		let new_signal = self.cross.next(&(price, self.cfg.price));
 
 		let signal = if new_signal == Action::None {
-			self.last_signal = new_signal;
-			self.last_signal_position = 0;
-			new_signal
-		} else {
 			if Action::None != self.last_signal {
 				self.last_signal_position += 1;
 				if self.last_signal_position > self.cfg.period {
@@ -128,10 +155,79 @@ impl IndicatorInstance for ExampleInstance {
 			}
 
 			self.last_signal
+		} else {
+			self.last_signal = new_signal;
+			self.last_signal_position = 0;
+			new_signal
 		};
 
 		let some_other_signal = Action::from(0.5);
 
-		IndicatorResult::new(&[candle.close()], &[signal, some_other_signal])
+		IndicatorResult::new(&[price], &[signal, some_other_signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Example;
+	use crate::core::{Action, Candle, IndicatorConfig, Source};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_example_source() {
+		let candle = |open, close| Candle {
+			open,
+			high: open.max(close),
+			low: open.min(close),
+			close,
+			volume: 1.0,
+		};
+
+		let candles = [candle(1.0, 1.0), candle(1.5, 3.0), candle(2.5, 3.0)];
+
+		let mut config = Example::default();
+		config.set("source", "open".to_string()).unwrap();
+		assert_eq!(config.source, Source::Open);
+
+		// close crosses the `price` on the second candle, but open crosses it only on the third one
+		let results = config.over(candles).unwrap();
+		assert_eq_float(2.5, results[2].value(0));
+		assert_eq!(results[1].signal(0), Action::None);
+		assert_eq!(results[2].signal(0), Action::BUY_ALL);
+
+		let results = Example::default().over(candles).unwrap();
+		assert_eq_float(3.0, results[2].value(0));
+		assert_eq!(results[1].signal(0), Action::BUY_ALL);
+	}
+
+	#[test]
+	fn test_example_period() {
+		let candles: Vec<Candle> = [1.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0]
+			.iter()
+			.map(|&close| Candle {
+				close,
+				..Candle::default()
+			})
+			.collect();
+
+		for period in [1, 3, 5] {
+			let mut config = Example::default();
+			config.set("period", period.to_string()).unwrap();
+			assert_eq!(config.period, period);
+
+			// signal is held for `period` frames after the cross
+			let results = config.over(&candles).unwrap();
+			for (i, result) in results.iter().enumerate() {
+				let expected = if (1..=1 + period as usize).contains(&i) {
+					Action::BUY_ALL
+				} else {
+					Action::None
+				};
+
+				assert_eq!(result.signal(0), expected, "at {i} with period {period}");
+			}
+		}
+
+		assert!(Example::default().set("period", "x".to_string()).is_err());
 	}
 }
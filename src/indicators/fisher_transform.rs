@@ -52,6 +52,8 @@ pub struct FisherTransform<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(FisherTransform<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for FisherTransform<M> {
 	type Instance = FisherTransformInstance<M>;
 
@@ -152,6 +154,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for FisherTransformInstance<
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["main", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_cross", "signal_line_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = &candle.source(self.cfg.source);
 
@@ -0,0 +1,287 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::Peekable;
+use crate::methods::EMA;
+
+/// Count of moving averages in each ribbon
+const RIBBON_SIZE: usize = 6;
+
+/// Parses comma separated list of exactly [`RIBBON_SIZE`] periods
+fn parse_ribbon(value: &str) -> Option<[PeriodType; RIBBON_SIZE]> {
+	let mut ribbon = [0; RIBBON_SIZE];
+	let mut periods = value.split(',').map(|x| x.trim().parse().ok());
+
+	for period in &mut ribbon {
+		*period = periods.next()??;
+	}
+
+	if periods.next().is_some() {
+		return None;
+	}
+
+	Some(ribbon)
+}
+
+/// Returns minimum and maximum of the `values`
+fn bounds(values: impl Iterator<Item = ValueType>) -> (ValueType, ValueType) {
+	values.fold(
+		(ValueType::INFINITY, ValueType::NEG_INFINITY),
+		|(min, max), x| (min.min(x), max.max(x)),
+	)
+}
+
+/// Guppy Multiple Moving Average (GMMA)
+///
+/// Consists of two ribbons of [`EMA`](crate::methods::EMA)s: short-term ribbon and long-term ribbon.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/g/guppy-multiple-moving-average.asp>
+///
+/// # 4 values
+///
+/// [`IndicatorResult`] may hold only up to 4 raw values, so instead of all twelve moving averages it returns the bounds of each ribbon.
+/// Every single moving average value is available through [`GuppyMultipleMovingAverageInstance::ribbon`].
+///
+/// * `short ribbon` lowest value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `short ribbon` highest value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `long ribbon` lowest value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `long ribbon` highest value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// * When the whole `short ribbon` is above the whole `long ribbon`, returns full buy signal.
+///   When the whole `short ribbon` is below the whole `long ribbon`, returns full sell signal.
+///   When the ribbons are intertwined, returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GuppyMultipleMovingAverage {
+	/// Short-term ribbon [`EMA`](crate::methods::EMA) periods. Default is `[3, 5, 8, 10, 12, 15]`.
+	///
+	/// Can be set as comma separated list of 6 periods.
+	///
+	/// Every period range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub short: [PeriodType; RIBBON_SIZE],
+
+	/// Long-term ribbon [`EMA`](crate::methods::EMA) periods. Default is `[30, 35, 40, 45, 50, 60]`.
+	///
+	/// Can be set as comma separated list of 6 periods.
+	///
+	/// Every period range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub long: [PeriodType; RIBBON_SIZE],
+
+	/// Source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(GuppyMultipleMovingAverage);
+
+impl IndicatorConfig for GuppyMultipleMovingAverage {
+	type Instance = GuppyMultipleMovingAverageInstance;
+
+	const NAME: &'static str = "GuppyMultipleMovingAverage";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			emas: cfg
+				.short
+				.iter()
+				.chain(&cfg.long)
+				.map(|&period| EMA::new(period, &src))
+				.collect::<Result<_, _>>()?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.short
+			.iter()
+			.chain(&self.long)
+			.all(|&period| period > 0 && period < PeriodType::MAX)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"short" => match parse_ribbon(&value) {
+				None => return Err(Error::ParameterParse(name.to_string(), value)),
+				Some(value) => self.short = value,
+			},
+			"long" => match parse_ribbon(&value) {
+				None => return Err(Error::ParameterParse(name.to_string(), value)),
+				Some(value) => self.long = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl Default for GuppyMultipleMovingAverage {
+	fn default() -> Self {
+		Self {
+			short: [3, 5, 8, 10, 12, 15],
+			long: [30, 35, 40, 45, 50, 60],
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GuppyMultipleMovingAverageInstance {
+	cfg: GuppyMultipleMovingAverage,
+
+	/// short ribbon EMAs followed by long ribbon EMAs
+	emas: Vec<EMA>,
+}
+
+impl GuppyMultipleMovingAverageInstance {
+	/// Returns current values of all the moving averages: short ribbon first, then long ribbon,
+	/// each in the order of periods in the configuration
+	pub fn ribbon(&self) -> impl Iterator<Item = ValueType> + '_ {
+		self.emas.iter().map(Peekable::peek)
+	}
+}
+
+impl IndicatorInstance for GuppyMultipleMovingAverageInstance {
+	type Config = GuppyMultipleMovingAverage;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["short_lower", "short_upper", "long_lower", "long_upper"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["ribbons_aligned"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		self.emas.iter_mut().for_each(|ema| {
+			let _ = ema.next(&src);
+		});
+
+		let (short, long) = self.emas.split_at(RIBBON_SIZE);
+		let (short_lower, short_upper) = bounds(short.iter().map(Peekable::peek));
+		let (long_lower, long_upper) = bounds(long.iter().map(Peekable::peek));
+
+		let signal = (short_lower > long_upper) as i8 - (short_upper < long_lower) as i8;
+
+		IndicatorResult::new(
+			&[short_lower, short_upper, long_lower, long_upper],
+			&[Action::from(signal)],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::GuppyMultipleMovingAverage;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(price: ValueType) -> Candle {
+		Candle {
+			open: price,
+			high: price,
+			low: price,
+			close: price,
+			volume: 1.0,
+		}
+	}
+
+	#[test]
+	fn test_guppy_multiple_moving_average_flat() {
+		let mut instance = GuppyMultipleMovingAverage::default()
+			.init(&candle(10.0))
+			.unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle(10.0));
+
+			for &value in result.values() {
+				assert_eq_float(10.0, value);
+			}
+			assert!(instance.ribbon().all(|x| (x - 10.0).abs() < 1e-6));
+			assert!(result.signal(0).is_none());
+		}
+	}
+
+	#[test]
+	fn test_guppy_multiple_moving_average_uptrend() {
+		let mut instance = GuppyMultipleMovingAverage::default()
+			.init(&candle(10.0))
+			.unwrap();
+
+		let signals: Vec<_> = (1..200)
+			.map(|i| instance.next(&candle(10.0 + i as ValueType)).signal(0))
+			.collect();
+
+		assert!(signals[50..].iter().all(|s| s.is_buy()));
+
+		let ribbon: Vec<_> = instance.ribbon().collect();
+		assert_eq!(ribbon.len(), 12);
+		// in a strong uptrend faster EMAs are always above slower ones
+		assert!(ribbon.windows(2).all(|x| x[0] > x[1]));
+
+		let mut instance = GuppyMultipleMovingAverage::default()
+			.init(&candle(1000.0))
+			.unwrap();
+
+		let signals: Vec<_> = (1..200)
+			.map(|i| instance.next(&candle(1000.0 - i as ValueType)).signal(0))
+			.collect();
+
+		assert!(signals[50..].iter().all(|s| s.is_sell()));
+	}
+
+	#[test]
+	fn test_guppy_multiple_moving_average_set() {
+		let mut cfg = GuppyMultipleMovingAverage::default();
+
+		cfg.set("short", "1, 2, 3, 4, 5, 6".to_string()).unwrap();
+		assert_eq!(cfg.short, [1, 2, 3, 4, 5, 6]);
+
+		assert!(cfg.set("long", "1,2,3".to_string()).is_err());
+		assert!(cfg.set("long", "1,2,3,4,5,6,7".to_string()).is_err());
+		assert!(cfg.set("long", "1,2,3,4,5,x".to_string()).is_err());
+		assert_eq!(cfg.long, [30, 35, 40, 45, 50, 60]);
+	}
+}
@@ -44,6 +44,8 @@ pub struct HullMovingAverage {
 	pub source: Source,
 }
 
+impl_candle_source!(HullMovingAverage);
+
 impl IndicatorConfig for HullMovingAverage {
 	type Instance = HullMovingAverageInstance;
 
@@ -130,6 +132,14 @@ impl IndicatorInstance for HullMovingAverageInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["hma"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["reversal"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let value = self.hma.next(&candle.source(self.cfg.source));
 		let signal = self.pivot.next(&value);
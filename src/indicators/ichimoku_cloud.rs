@@ -58,6 +58,8 @@ pub struct IchimokuCloud {
 	pub source: Source,
 }
 
+impl_candle_source!(IchimokuCloud);
+
 impl IndicatorConfig for IchimokuCloud {
 	type Instance = IchimokuCloudInstance;
 
@@ -160,6 +162,14 @@ impl IndicatorInstance for IchimokuCloudInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["tenkan_sen", "kijun_sen", "senkou_span_a", "senkou_span_b"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["tenkan_kijun_cross", "kijun_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 		let (high, low) = (candle.high(), candle.low());
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{Change, Cross, LinearVolatility, StDev};
+use crate::methods::{Cross, EfficiencyRatio, StDev};
 
 /// Kaufman Adaptive Moving Average (KAMA)
 /// # Links
@@ -63,6 +63,8 @@ pub struct Kaufman {
 
 pub type KAMA = Kaufman;
 
+impl_candle_source!(Kaufman);
+
 impl IndicatorConfig for Kaufman {
 	type Instance = KaufmanInstance;
 
@@ -77,8 +79,7 @@ impl IndicatorConfig for Kaufman {
 		let src = &candle.source(cfg.source);
 
 		Ok(Self::Instance {
-			volatility: LinearVolatility::new(cfg.period1, src)?,
-			change: Change::new(cfg.period1, src)?,
+			er: EfficiencyRatio::new(cfg.period1, src)?,
 			fastest: 2. / (cfg.period2 + 1) as ValueType,
 			slowest: 2. / (cfg.period3 + 1) as ValueType,
 			st_dev: StDev::new(cfg.filter_period, src)?,
@@ -159,8 +160,7 @@ impl Default for Kaufman {
 pub struct KaufmanInstance {
 	cfg: Kaufman,
 
-	volatility: LinearVolatility,
-	change: Change,
+	er: EfficiencyRatio,
 	fastest: ValueType,
 	slowest: ValueType,
 	st_dev: StDev,
@@ -177,17 +177,18 @@ impl IndicatorInstance for KaufmanInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["kama"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["source_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = &candle.source(self.cfg.source);
 
-		let direction = self.change.next(src).abs();
-		let volatility = self.volatility.next(src);
-
-		let er = if volatility == 0. {
-			0.
-		} else {
-			direction / volatility
-		};
+		let er = self.er.next(src);
 		let mut smooth = er.mul_add(self.fastest - self.slowest, self.slowest);
 
 		if self.cfg.square_smooth {
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use crate::core::{Error, Method, MovingAverageConstructor, Source, ValueType, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::MA;
-use crate::methods::{CrossAbove, CrossUnder, SMA};
+use crate::methods::{CrossAbove, CrossUnder, SMA, TR};
 
 /// Keltner Channel
 ///
@@ -47,6 +47,8 @@ pub struct KeltnerChannel<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(KeltnerChannel<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for KeltnerChannel<M> {
 	type Instance = KeltnerChannelInstance<M>;
 
@@ -60,7 +62,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KeltnerChannel<M> {
 		let cfg = self;
 		let src = candle.source(cfg.source);
 		Ok(Self::Instance {
-			prev_close: candle.close(),
+			tr: TR::new(candle)?,
 			ma: cfg.ma.init(src)?, // method(cfg.method, cfg.period, src)?,
 			sma: SMA::new(cfg.ma.ma_period(), &(candle.high() - candle.low()))?,
 			cross_above: CrossAbove::default(),
@@ -116,7 +118,7 @@ impl Default for KeltnerChannel<MA> {
 pub struct KeltnerChannelInstance<M: MovingAverageConstructor = MA> {
 	cfg: KeltnerChannel<M>,
 
-	prev_close: ValueType,
+	tr: TR,
 	ma: M::Instance,
 	sma: SMA,
 	cross_above: CrossAbove,
@@ -130,10 +132,17 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KeltnerChannelInstance<M
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["source", "upper", "lower"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["breakout"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let source = candle.source(self.cfg.source);
-		let tr = candle.tr_close(self.prev_close);
-		self.prev_close = candle.close();
+		let tr = self.tr.next(candle);
 
 		let ma: ValueType = self.ma.next(&source);
 		let atr = self.sma.next(&tr);
@@ -8,6 +8,12 @@ use crate::methods::Cross;
 
 /// Klinger Volume Oscillator
 ///
+/// Volume force is a volume signed by the trend direction, which is the direction of the last change of
+/// [typical price](crate::core::OHLCV::tp) (`hlc3`). When typical price is unchanged, the previous trend direction is kept.
+/// Until the first change of typical price, volume force is `0.0`.
+///
+/// `main` value is the difference between fast (`ma1`) and slow (`ma2`) moving averages of volume force.
+///
 /// ## Links
 ///
 /// * <https://en.wikipedia.org/wiki/Volume_analysis#Klinger_Volume_Oscillator>
@@ -55,6 +61,9 @@ pub struct KlingerVolumeOscillator<M: MovingAverageConstructor = MA> {
 	pub signal: M,
 }
 
+/// Just an alias for `KlingerVolumeOscillator`
+pub type Klinger<M = MA> = KlingerVolumeOscillator<M>;
+
 impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M> {
 	type Instance = KlingerVolumeOscillatorInstance<M>;
 
@@ -73,6 +82,7 @@ impl<M: MovingAverageConstructor> IndicatorConfig for KlingerVolumeOscillator<M>
 			cross1: Cross::default(),
 			cross2: Cross::default(),
 			last_tp: candle.tp(),
+			trend: 0.,
 			cfg,
 		})
 	}
@@ -133,6 +143,7 @@ pub struct KlingerVolumeOscillatorInstance<M: MovingAverageConstructor = MA> {
 	cross1: Cross,
 	cross2: Cross,
 	last_tp: ValueType,
+	trend: ValueType,
 }
 
 impl<M: MovingAverageConstructor> IndicatorInstance for KlingerVolumeOscillatorInstance<M> {
@@ -142,21 +153,26 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KlingerVolumeOscillatorI
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["main", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_cross", "signal_line_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let tp = candle.tp();
 
 		let d = tp - self.last_tp;
 		self.last_tp = tp;
 
-		// let vol = if d > 0. {
-		// 	candle.volume()
-		// } else if d < 0. {
-		// 	-candle.volume()
-		// } else {
-		// 	0.
-		// };
+		// unchanged typical price keeps the previous trend
+		if d != 0. {
+			self.trend = sign(d);
+		}
 
-		let vol = sign(d) * candle.volume();
+		let vol = self.trend * candle.volume();
 
 		let ma1: ValueType = self.ma1.next(&vol);
 		let ma2: ValueType = self.ma2.next(&vol);
@@ -170,3 +186,55 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KlingerVolumeOscillatorI
 		IndicatorResult::new(&[ko, ma3], &[s1, s2])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::KlingerVolumeOscillator;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance};
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_klinger_flat() {
+		let candle = Candle {
+			open: 10.0,
+			high: 12.0,
+			low: 9.0,
+			close: 11.0,
+			volume: 100.0,
+		};
+		let mut instance = KlingerVolumeOscillator::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq_float(0.0, result.value(0));
+			assert_eq_float(0.0, result.value(1));
+		}
+	}
+
+	#[test]
+	fn test_klinger_unchanged_trend() {
+		let candle = |close| Candle {
+			open: close,
+			high: close,
+			low: close,
+			close,
+			volume: 100.0,
+		};
+
+		let mut instance = KlingerVolumeOscillator::default()
+			.init(&candle(10.0))
+			.unwrap();
+		let mut unchanged = instance.clone();
+
+		let _ = instance.next(&candle(11.0));
+		let _ = unchanged.next(&candle(11.0));
+
+		// typical price is unchanged, so volume force is still positive
+		let result = unchanged.next(&candle(11.0));
+		let rising = instance.next(&candle(12.0));
+
+		assert!(result.value(0) > 0.0);
+		assert_eq_float(rising.value(0), result.value(0));
+	}
+}
@@ -184,6 +184,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for KnowSureThingInstance<M>
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["kst", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["signal_line_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let close = &candle.close();
 
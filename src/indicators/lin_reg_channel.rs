@@ -0,0 +1,254 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{LinReg, StDev};
+
+/// Share of the total sum of squares, below which the sum of squared residuals is treated as zero
+const RESIDUAL_EPSILON: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-5
+} else {
+	1e-10
+};
+
+/// Linear Regression Channel
+///
+/// Regression line is a [`LinReg`](crate::methods::LinReg) value. Bands are placed at `k` standard deviations of the residuals
+/// (differences between `source` values and the fitted line) over the same `period`.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/support/solutions/43000502266-linear-regression-channel/>
+///
+/// # 3 values
+///
+/// * `regression line` value
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `upper band`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `lower band`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # 1 signal
+///
+/// * When `source` value touches or goes under the `lower band`, returns full buy signal.
+///   When `source` value touches or goes above the `upper band`, returns full sell signal.
+///   Otherwise or when the bands have zero width returns no signal.
+///
+/// When `source` values lie on a straight line (up to float rounding errors), the bands have zero width.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinRegChannel {
+	/// Regression period length. Default is `100`
+	///
+	/// Range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Residuals standard deviation multiplier for bands. Default is `2.0`
+	///
+	/// Range in \(`0.0`; `+inf`\)
+	pub k: ValueType,
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(LinRegChannel);
+
+impl IndicatorConfig for LinRegChannel {
+	type Instance = LinRegChannelInstance;
+
+	const NAME: &'static str = "LinRegChannel";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let n = cfg.period as ValueType;
+
+		Ok(Self::Instance {
+			lin_reg: LinReg::new(cfg.period, &src)?,
+			st_dev: StDev::new(cfg.period, &src)?,
+			sq_dev_k: n - 1.0,
+			sq_tan_k: n * n.mul_add(n, -1.0) / 12.0,
+			divider: n.recip(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.k > 0.0 && self.period > 2 && self.period < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"k" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.k = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for LinRegChannel {
+	fn default() -> Self {
+		Self {
+			period: 100,
+			k: 2.0,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinRegChannelInstance {
+	cfg: LinRegChannel,
+
+	lin_reg: LinReg,
+	st_dev: StDev,
+	sq_dev_k: ValueType,
+	sq_tan_k: ValueType,
+	divider: ValueType,
+}
+
+impl IndicatorInstance for LinRegChannelInstance {
+	type Config = LinRegChannel;
+
+	#[inline]
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["line", "upper", "lower"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["bands_touch"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let source = candle.source(self.cfg.source);
+		let line = self.lin_reg.next(&source);
+		let st_dev = self.st_dev.next(&source);
+		let tan = self.lin_reg.tan();
+
+		// sum of squared residuals = Σ(y - mean(y))² - tan² * Σ(x - mean(x))²
+		let sq_total = st_dev * st_dev * self.sq_dev_k;
+		let sq_residuals = (tan * tan).mul_add(-self.sq_tan_k, sq_total);
+		// the difference cancels out when values lie on the line, so float rounding errors may produce
+		// small positive or negative values instead of zero
+		let residual_st_dev = if sq_residuals > sq_total * RESIDUAL_EPSILON {
+			(sq_residuals * self.divider).sqrt()
+		} else {
+			0.0
+		};
+
+		let upper = residual_st_dev.mul_add(self.cfg.k, line);
+		let lower = residual_st_dev.mul_add(-self.cfg.k, line);
+
+		let signal = if upper > lower {
+			(source <= lower) as i8 - (source >= upper) as i8
+		} else {
+			0
+		};
+
+		IndicatorResult::new(&[line, upper, lower], &[Action::from(signal)])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::LinRegChannel;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::assert_eq_float;
+
+	fn candle(price: ValueType) -> Candle {
+		Candle {
+			open: price,
+			high: price,
+			low: price,
+			close: price,
+			volume: 1.0,
+		}
+	}
+
+	#[test]
+	fn test_lin_reg_channel_linear() {
+		let cfg = LinRegChannel {
+			period: 20,
+			..LinRegChannel::default()
+		};
+		let mut instance = cfg.init(&candle(1.0)).unwrap();
+
+		for i in 1..200 {
+			let price = (i as ValueType).mul_add(2.0, 1.0);
+			let result = instance.next(&candle(price));
+
+			if i >= 20 {
+				assert_eq_float(price, result.value(0));
+				assert_eq!(result.value(1).to_bits(), result.value(0).to_bits());
+				assert_eq!(result.value(2).to_bits(), result.value(0).to_bits());
+				assert!(result.signal(0).is_none());
+			}
+		}
+	}
+
+	#[test]
+	fn test_lin_reg_channel_noisy() {
+		let cfg = LinRegChannel {
+			period: 50,
+			..LinRegChannel::default()
+		};
+		let mut instance = cfg.init(&candle(100.0)).unwrap();
+
+		let mut inside = 0;
+		let mut total = 0;
+
+		for i in 1..1000 {
+			let x = i as ValueType;
+			let noise = (x * 0.31).cos().mul_add(0.5, (x * 1.7).sin());
+			let price = x.mul_add(0.3, 100.0) + noise;
+			let result = instance.next(&candle(price));
+
+			if i >= 50 {
+				let (line, upper, lower) = (result.value(0), result.value(1), result.value(2));
+				assert!(upper > line && line > lower);
+				assert_eq_float(upper - line, line - lower);
+
+				total += 1;
+				if price > lower && price < upper {
+					inside += 1;
+				}
+			}
+		}
+
+		assert!(inside * 10 >= total * 9, "{inside} of {total}");
+	}
+}
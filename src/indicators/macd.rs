@@ -60,6 +60,8 @@ pub struct MACD<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(MACD<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for MACD<M> {
 	type Instance = MACDInstance<M>;
 
@@ -117,6 +119,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for MACD<M> {
 	fn size(&self) -> (u8, u8) {
 		(2, 2)
 	}
+
+	fn warmup_period(&self) -> Option<usize> {
+		Some((self.ma2.ma_period() as usize + self.signal.ma_period() as usize).saturating_sub(2))
+	}
 }
 
 impl Default for MACD {
@@ -152,6 +158,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for MACDInstance<M> {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["macd", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["signal_line_cross", "zero_cross"]
+	}
+
 	#[inline]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = &candle.source(self.cfg.source);
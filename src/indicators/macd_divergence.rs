@@ -0,0 +1,325 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+	Action, Error, Method, MovingAverageConstructor, PeriodType, ValueType, Window, OHLCV,
+};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::MA;
+use crate::indicators::{MACDInstance, MACD};
+use crate::methods::{LowerReversalSignal, UpperReversalSignal};
+
+/// MACD histogram divergence
+///
+/// Searches for pivots of the price (`high` for pivot highs and `low` for pivot lows) and compares each confirmed pivot
+/// with the previous one of the same kind together with the `MACD histogram` values at the same candles.
+///
+/// * Bearish divergence: price makes a higher high, while `MACD histogram` makes a lower high.
+/// * Bullish divergence: price makes a lower low, while `MACD histogram` makes a higher low.
+///
+/// Pivots are confirmed `right` candles after they happen, so the signal is also delayed by `right` candles.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=technical_indicators:macd-histogram>
+///
+/// # 1 value
+///
+/// * `MACD histogram` value (`MACD` - `Signal line`)
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// # 1 signal
+///
+/// * When bullish divergence is confirmed, returns full buy signal.
+///   When bearish divergence is confirmed, returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MacdDivergence<M: MovingAverageConstructor = MA> {
+	/// MACD configuration for the histogram, which is calculated exactly as in [`MACD`](crate::indicators::MACD) indicator.
+	///
+	/// Default is [`MACD::default`](crate::indicators::MACD).
+	pub macd: MACD<M>,
+
+	/// How many periods should left before pivot point. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)-`right`\).
+	pub left: PeriodType,
+
+	/// How many periods should appear after pivot point. Default is `5`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)-`left`\).
+	pub right: PeriodType,
+}
+
+impl<M: MovingAverageConstructor> IndicatorConfig for MacdDivergence<M> {
+	type Instance = MacdDivergenceInstance<M>;
+
+	const NAME: &'static str = "MacdDivergence";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			macd: cfg.macd.clone().init(candle)?,
+			ph: UpperReversalSignal::new(cfg.left, cfg.right, &candle.high())?,
+			pl: LowerReversalSignal::new(cfg.left, cfg.right, &candle.low())?,
+			window: Window::new(cfg.right, (candle.high(), candle.low(), 0.0)),
+			last_high: None,
+			last_low: None,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.macd.validate()
+			&& self.left >= 1
+			&& self.right >= 1
+			&& self.left.saturating_add(self.right) < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"ma1" | "ma2" | "signal" | "source" => self.macd.set(name, value)?,
+			"left" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.left = value,
+			},
+			"right" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.right = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+
+	fn warmup_period(&self) -> Option<usize> {
+		Some(self.macd.warmup_period()? + self.left as usize + self.right as usize)
+	}
+}
+
+impl Default for MacdDivergence<MA> {
+	fn default() -> Self {
+		Self {
+			macd: MACD::default(),
+			left: 5,
+			right: 5,
+		}
+	}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "M: Serialize, M::Instance: Serialize",
+		deserialize = "M: Deserialize<'de>, M::Instance: Deserialize<'de>"
+	))
+)]
+pub struct MacdDivergenceInstance<M: MovingAverageConstructor = MA> {
+	cfg: MacdDivergence<M>,
+
+	macd: MACDInstance<M>,
+	ph: UpperReversalSignal,
+	pl: LowerReversalSignal,
+	/// (`high`, `low`, `histogram`) of the last `right` candles
+	window: Window<(ValueType, ValueType, ValueType)>,
+	/// (`high`, `histogram`) of the last pivot high
+	last_high: Option<(ValueType, ValueType)>,
+	/// (`low`, `histogram`) of the last pivot low
+	last_low: Option<(ValueType, ValueType)>,
+}
+
+// `derive` can't see through `MACDInstance<M>` that it needs `M::Instance` bounds
+impl<M: MovingAverageConstructor + std::fmt::Debug> std::fmt::Debug for MacdDivergenceInstance<M>
+where
+	M::Instance: std::fmt::Debug,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("MacdDivergenceInstance")
+			.field("cfg", &self.cfg)
+			.field("macd", &self.macd)
+			.field("ph", &self.ph)
+			.field("pl", &self.pl)
+			.field("window", &self.window)
+			.field("last_high", &self.last_high)
+			.field("last_low", &self.last_low)
+			.finish()
+	}
+}
+
+impl<M: MovingAverageConstructor + Clone> Clone for MacdDivergenceInstance<M>
+where
+	M::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			macd: self.macd.clone(),
+			ph: self.ph.clone(),
+			pl: self.pl.clone(),
+			window: self.window.clone(),
+			last_high: self.last_high,
+			last_low: self.last_low,
+		}
+	}
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for MacdDivergenceInstance<M> {
+	type Config = MacdDivergence<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["macd_histogram"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["divergence"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let macd = self.macd.next(candle);
+		let histogram = macd.value(0) - macd.value(1);
+
+		let (high, low) = (candle.high(), candle.low());
+		let (pivot_high, pivot_low, pivot_histogram) = self.window.push((high, low, histogram));
+
+		let mut signal = Action::None;
+
+		if self.ph.next(&high).is_buy() {
+			if let Some((prev_high, prev_histogram)) = self.last_high {
+				if pivot_high > prev_high && pivot_histogram < prev_histogram {
+					signal = Action::SELL_ALL;
+				}
+			}
+
+			self.last_high = Some((pivot_high, pivot_histogram));
+		}
+
+		if self.pl.next(&low).is_buy() {
+			if let Some((prev_low, prev_histogram)) = self.last_low {
+				if pivot_low < prev_low && pivot_histogram > prev_histogram {
+					signal = Action::BUY_ALL;
+				}
+			}
+
+			self.last_low = Some((pivot_low, pivot_histogram));
+		}
+
+		IndicatorResult::new(&[histogram], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MacdDivergence;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::RandomCandles;
+
+	fn candle(price: ValueType) -> Candle {
+		Candle {
+			open: price,
+			high: price,
+			low: price,
+			close: price,
+			volume: 1.0,
+		}
+	}
+
+	fn signals(prices: &[ValueType]) -> Vec<Action> {
+		let cfg = MacdDivergence {
+			left: 2,
+			right: 2,
+			..MacdDivergence::default()
+		};
+		let mut instance = cfg.init(&candle(prices[0])).unwrap();
+
+		prices
+			.iter()
+			.map(|&price| instance.next(&candle(price)).signal(0))
+			.collect()
+	}
+
+	#[test]
+	fn test_macd_divergence_bearish() {
+		let mut prices = vec![100.0; 30];
+		// strong rally up to the first peak at 120.0
+		prices.extend((1..=10).map(|x| (x as ValueType).mul_add(2.0, 100.0)));
+		// pullback
+		prices.extend((1..=5).map(|x| 120.0 - x as ValueType));
+		// slow rally up to the higher peak at 121.0
+		prices.extend((1..=12).map(|x| (x as ValueType).mul_add(0.5, 115.0)));
+		// drop, which confirms the second peak
+		prices.extend((1..=5).map(|x| (x as ValueType).mul_add(-2.0, 121.0)));
+
+		let signals = signals(&prices);
+		let second_peak = 30 + 10 + 5 + 12 - 1;
+
+		// the only signal is the bearish divergence confirmed `right` candles after the second peak
+		assert_eq!(signals[second_peak + 2], Action::SELL_ALL);
+		assert_eq!(signals.iter().filter(|s| s.is_some()).count(), 1);
+	}
+
+	#[test]
+	fn test_macd_divergence_bullish() {
+		let mut prices = vec![200.0; 30];
+		prices.extend((1..=10).map(|x| (x as ValueType).mul_add(-2.0, 200.0)));
+		prices.extend((1..=5).map(|x| 180.0 + x as ValueType));
+		prices.extend((1..=12).map(|x| (x as ValueType).mul_add(-0.5, 185.0)));
+		prices.extend((1..=5).map(|x| (x as ValueType).mul_add(2.0, 179.0)));
+
+		let signals = signals(&prices);
+		let second_trough = 30 + 10 + 5 + 12 - 1;
+
+		assert_eq!(signals[second_trough + 2], Action::BUY_ALL);
+		assert_eq!(signals.iter().filter(|s| s.is_some()).count(), 1);
+	}
+
+	#[test]
+	fn test_macd_divergence_no_divergence() {
+		// momentum keeps growing, so higher highs are confirmed by higher histogram
+		let prices: Vec<_> = (0..100)
+			.map(|x| {
+				let x = x as ValueType;
+				(x * x).mul_add(0.01, 100.0) + (x * 0.8).sin()
+			})
+			.collect();
+
+		assert!(signals(&prices).iter().all(|s| !s.is_sell()));
+	}
+
+	#[test]
+	fn test_macd_divergence_config() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		let mut cfg = MacdDivergence::default();
+		cfg.set("left", "3".to_string()).unwrap();
+		cfg.set("ma1", "ema-8".to_string()).unwrap();
+		assert_eq!(cfg.left, 3);
+		assert!(cfg.set("unknown", "3".to_string()).is_err());
+
+		let results = cfg.over(&candles).unwrap();
+		assert_eq!(results.len(), candles.len());
+
+		cfg.right = 0;
+		assert!(!cfg.validate());
+		assert!(cfg.init(&candles[0]).is_err());
+	}
+}
@@ -0,0 +1,267 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Integral, EMA};
+
+/// Share of the single EMA of the range, below which double EMA of the range is treated as zero
+const RANGE_EPSILON: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-5
+} else {
+	1e-9
+};
+
+// https://www.investopedia.com/terms/m/mass-index.asp
+// Single EMA = 9-period EMA of (High - Low)
+// Double EMA = 9-period EMA of the Single EMA
+// EMA Ratio = Single EMA / Double EMA
+// Mass Index = 25-period sum of EMA Ratio
+// A "reversal bulge" occurs when Mass Index rises above 27 and then falls below 26.5
+/// Mass Index
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Mass_index>
+/// * <https://www.investopedia.com/terms/m/mass-index.asp>
+///
+/// # 1 value
+///
+/// * `Mass Index` value
+///
+/// Range in \(`0.0`; `+inf`\)
+///
+/// When double EMA of the range is negligible relative to the single EMA (f.e. when there is no range at all),
+/// EMA ratio is undefined, so the previous EMA ratio is used instead.
+/// Before the first defined ratio it is `1.0`, which is the ratio for a constant range.
+///
+/// # 1 signal
+///
+/// * When `Mass Index` rises above `bulge_high` and then falls below `bulge_low`, a "reversal bulge" appears.
+///   If at this moment `source` value is above it's EMA of `ema_period` (uptrend), returns full sell signal.
+///   If `source` value is below it's EMA (downtrend), returns full buy signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MassIndex {
+	/// EMA period for high-low range smoothing. Default is `9`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub ema_period: PeriodType,
+
+	/// Summation period. Default is `25`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub sum_period: PeriodType,
+
+	/// Upper bulge zone. Default is `27.0`.
+	///
+	/// Range in \(`bulge_low`; `+inf`\)
+	pub bulge_high: ValueType,
+
+	/// Lower bulge zone. Default is `26.5`.
+	///
+	/// Range in \(`0.0`; `bulge_high`\)
+	pub bulge_low: ValueType,
+
+	/// Source type for trend detection. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(MassIndex);
+
+impl IndicatorConfig for MassIndex {
+	type Instance = MassIndexInstance;
+
+	const NAME: &'static str = "MassIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let range = candle.high() - candle.low();
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			ema1: EMA::new(cfg.ema_period, &range)?,
+			ema2: EMA::new(cfg.ema_period, &range)?,
+			sum: Integral::new(cfg.sum_period, &1.0)?,
+			trend: EMA::new(cfg.ema_period, &src)?,
+			last_ratio: 1.0,
+			bulge: false,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.ema_period > 1
+			&& self.sum_period > 0
+			&& self.ema_period < PeriodType::MAX
+			&& self.sum_period < PeriodType::MAX
+			&& self.bulge_low > 0.0
+			&& self.bulge_high > self.bulge_low
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"ema_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.ema_period = value,
+			},
+			"sum_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.sum_period = value,
+			},
+			"bulge_high" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.bulge_high = value,
+			},
+			"bulge_low" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.bulge_low = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for MassIndex {
+	fn default() -> Self {
+		Self {
+			ema_period: 9,
+			sum_period: 25,
+			bulge_high: 27.0,
+			bulge_low: 26.5,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MassIndexInstance {
+	cfg: MassIndex,
+
+	ema1: EMA,
+	ema2: EMA,
+	sum: Integral,
+	trend: EMA,
+	last_ratio: ValueType,
+	bulge: bool,
+}
+
+impl IndicatorInstance for MassIndexInstance {
+	type Config = MassIndex;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["mass_index"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["reversal_bulge"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let range = candle.high() - candle.low();
+
+		let ema1 = self.ema1.next(&range);
+		let ema2 = self.ema2.next(&ema1);
+
+		if ema2 > ema1.abs() * RANGE_EPSILON {
+			self.last_ratio = ema1 / ema2;
+		}
+		let value = self.sum.next(&self.last_ratio);
+
+		let src = candle.source(self.cfg.source);
+		let trend = self.trend.next(&src);
+
+		let signal = if self.bulge && value < self.cfg.bulge_low {
+			self.bulge = false;
+			Action::from((src < trend) as i8 - (src > trend) as i8)
+		} else {
+			self.bulge = self.bulge || value > self.cfg.bulge_high;
+			Action::None
+		};
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MassIndex;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_mass_index_no_range() {
+		let flat = |c: Candle| Candle {
+			high: c.close,
+			low: c.close,
+			..c
+		};
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+
+		// no range at all from the start, so the ratio stays `1.0`
+		let mut instance = MassIndex::default().init(&flat(candles[0])).unwrap();
+		for candle in &candles {
+			let result = instance.next(&flat(*candle));
+			assert_eq!(result.value(0).to_bits(), ValueType::to_bits(25.0));
+			assert!(result.signal(0).is_none());
+		}
+
+		// range vanishes after some movement: the ratio decays and then keeps its last defined value
+		let mut instance = MassIndex::default().init(&candles[0]).unwrap();
+		for candle in &candles {
+			let _ = instance.next(candle);
+		}
+		for candle in candles.iter().cycle().take(20000) {
+			let value = instance.next(&flat(*candle)).value(0);
+			assert!(value.is_finite() && value >= 0.0);
+		}
+	}
+
+	#[test]
+	fn test_mass_index_const_range() {
+		let candles: Vec<Candle> = RandomCandles::new()
+			.take(300)
+			.map(|c| Candle {
+				high: c.low + 0.75,
+				..c
+			})
+			.collect();
+
+		for sum_period in 1..30 {
+			let cfg = MassIndex {
+				sum_period,
+				..MassIndex::default()
+			};
+			let mut instance = cfg.init(&candles[0]).unwrap();
+
+			for candle in &candles {
+				let result = instance.next(candle);
+				assert_eq_float(sum_period as ValueType, result.value(0));
+				assert!(result.signal(0).is_none());
+			}
+		}
+	}
+}
@@ -0,0 +1,315 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+	Action, Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV,
+};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::MA;
+use crate::indicators::{RelativeStrengthIndex, RelativeStrengthIndexInstance};
+use crate::methods::Streak;
+
+/// Short-term mean reversion
+///
+/// Combines a short-period [RSI](crate::indicators::RelativeStrengthIndex), a long-period moving average trend filter
+/// and a count of consecutive down (or up) closes from [`Streak`](crate::methods::Streak).
+///
+/// Looks for short pullbacks inside the long-term trend: buys when RSI is very low
+/// after at least `pullback` consecutive falling values, while the value is still above the trend moving average.
+/// Sells in the opposite situation.
+///
+/// ## Links
+///
+/// * <https://school.stockcharts.com/doku.php?id=trading_strategies:rsi2>
+///
+/// # 3 values
+///
+/// * `RSI` value
+///
+/// Range in \[`0.0`; `1.0`\]
+///
+/// * `trend` moving average value
+///
+/// Range is the same as the range of the `source` values
+///
+/// * `streak` value: count of consecutive rising (positive) or falling (negative) values
+///
+/// Range in \(`-inf`; `+inf`\), always an integer number
+///
+/// # 1 signal
+///
+/// * When `source` value is above the `trend` moving average, `RSI` is below `zone` and `streak` <= -`pullback`,
+///   returns full buy signal.
+///   When `source` value is below the `trend` moving average, `RSI` is above `1.0`-`zone` and `streak` >= `pullback`,
+///   returns full sell signal.
+///   Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MeanReversion<M: MovingAverageConstructor = MA> {
+	/// RSI moving average type.
+	///
+	/// Default is [`RMA(2)`](crate::methods::RMA)
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub rsi: M,
+
+	/// Trend filter moving average type.
+	///
+	/// Default is [`SMA(200)`](crate::methods::SMA)
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub trend: M,
+
+	/// Oversold/overbought relative zone for RSI. Default is `0.1`.
+	///
+	/// Range in \(`0.0`; `0.5`\]
+	pub zone: ValueType,
+
+	/// Minimum count of consecutive falling (for buy) or rising (for sell) values. Default is `2`.
+	///
+	/// Value `0` means no pullback filter at all.
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub pullback: PeriodType,
+
+	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(MeanReversion<M>);
+
+impl<M: MovingAverageConstructor> IndicatorConfig for MeanReversion<M> {
+	type Instance = MeanReversionInstance<M>;
+
+	const NAME: &'static str = "MeanReversion";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+		let rsi = RelativeStrengthIndex {
+			ma: cfg.rsi.clone(),
+			zone: cfg.zone,
+			source: cfg.source,
+			source_smoothing: 0,
+		};
+
+		Ok(Self::Instance {
+			rsi: rsi.init(candle)?,
+			trend: cfg.trend.init(src)?,
+			streak: Streak::new((), &src)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.rsi.ma_period() >= 2
+			&& self.rsi.ma_period() < PeriodType::MAX
+			&& self.trend.ma_period() >= 2
+			&& self.trend.ma_period() < PeriodType::MAX
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+			&& self.pullback < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"rsi" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.rsi = value,
+			},
+			"trend" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.trend = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.zone = value,
+			},
+			"pullback" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.pullback = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+
+	fn warmup_period(&self) -> Option<usize> {
+		Some((self.trend.ma_period().max(self.rsi.ma_period()) as usize).saturating_sub(1))
+	}
+}
+
+impl Default for MeanReversion {
+	fn default() -> Self {
+		Self {
+			rsi: MA::RMA(2),
+			trend: MA::SMA(200),
+			zone: 0.1,
+			pullback: 2,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MeanReversionInstance<M: MovingAverageConstructor = MA> {
+	cfg: MeanReversion<M>,
+
+	rsi: RelativeStrengthIndexInstance<M>,
+	trend: M::Instance,
+	streak: Streak,
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for MeanReversionInstance<M> {
+	type Config = MeanReversion<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["rsi", "trend", "streak"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["mean_reversion"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let rsi = self.rsi.next(candle).value(0);
+		let trend = self.trend.next(&src);
+		let streak = self.streak.next(&src);
+		let pullback = self.cfg.pullback as ValueType;
+
+		let signal = if src > trend && rsi < self.cfg.zone && streak <= -pullback {
+			Action::BUY_ALL
+		} else if src < trend && rsi > 1. - self.cfg.zone && streak >= pullback {
+			Action::SELL_ALL
+		} else {
+			Action::None
+		};
+
+		IndicatorResult::new(&[rsi, trend, streak], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::MeanReversion;
+	use crate::core::{Action, Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{RandomCandles, MA};
+
+	fn candle(close: ValueType) -> Candle {
+		Candle {
+			open: close,
+			high: close,
+			low: close,
+			close,
+			volume: 1.0,
+		}
+	}
+
+	fn signals(prices: &[ValueType]) -> Vec<Action> {
+		let cfg = MeanReversion {
+			trend: MA::SMA(20),
+			..MeanReversion::default()
+		};
+		let mut instance = cfg.init(&candle(prices[0])).unwrap();
+
+		prices
+			.iter()
+			.map(|&price| instance.next(&candle(price)).signal(0))
+			.collect()
+	}
+
+	#[test]
+	fn test_mean_reversion_pullback_in_uptrend() {
+		// steady uptrend up to 140.0
+		let mut prices: Vec<_> = (0..=40).map(|x| 100.0 + x as ValueType).collect();
+		// short pullback, which stays above the trend moving average
+		prices.extend((1..=5).map(|x| 140.0 - x as ValueType));
+
+		let signals = signals(&prices);
+
+		assert!(signals[..=40].iter().all(|signal| signal.is_none()));
+		// RSI(2) halves on every falling value and gets below `zone` on the fourth one
+		assert_eq!(
+			signals[41..],
+			[
+				Action::None,
+				Action::None,
+				Action::None,
+				Action::BUY_ALL,
+				Action::BUY_ALL
+			]
+		);
+	}
+
+	#[test]
+	fn test_mean_reversion_below_trend() {
+		let mut prices: Vec<_> = (0..=40).map(|x| 100.0 + x as ValueType).collect();
+		// deep drop, which falls below the trend moving average
+		prices.extend((1..=4).map(|x| (x as ValueType).mul_add(-10.0, 140.0)));
+
+		let signals = signals(&prices);
+
+		assert!(signals.iter().all(|signal| signal.is_none()));
+	}
+
+	#[test]
+	fn test_mean_reversion_config() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let cfg = MeanReversion::default();
+
+		assert!(cfg.validate());
+		assert_eq!(cfg.warmup_period(), Some(199));
+
+		let mut instance = cfg.init(&candles[0]).unwrap();
+		for candle in &candles {
+			let result = instance.next(candle);
+			assert_eq!(result.size(), (3, 1));
+			assert!((0.0..=1.0).contains(&result.value(0)));
+		}
+
+		for cfg in [
+			MeanReversion {
+				rsi: MA::RMA(1),
+				..MeanReversion::default()
+			},
+			MeanReversion {
+				trend: MA::SMA(1),
+				..MeanReversion::default()
+			},
+			MeanReversion {
+				zone: 0.0,
+				..MeanReversion::default()
+			},
+			MeanReversion {
+				zone: 0.6,
+				..MeanReversion::default()
+			},
+		] {
+			assert!(!cfg.validate());
+			assert!(cfg.init(&candles[0]).is_err());
+		}
+	}
+}
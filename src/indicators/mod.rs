@@ -2,10 +2,31 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorConfigDyn, ValueType, OHLCV};
 
 pub mod example;
 
+/// Implements [`CandleSource`](crate::core::CandleSource) for the indicator **Configuration** with `source` field
+///
+/// Use `impl_candle_source!(Config)` for plain configurations and `impl_candle_source!(Config<M>)`
+/// for the ones generic over [`MovingAverageConstructor`](crate::core::MovingAverageConstructor).
+macro_rules! impl_candle_source {
+	($name:ident) => {
+		impl crate::core::CandleSource for $name {
+			fn source(&self) -> crate::core::Source {
+				self.source
+			}
+		}
+	};
+	($name:ident<M>) => {
+		impl<M: crate::core::MovingAverageConstructor> crate::core::CandleSource for $name<M> {
+			fn source(&self) -> crate::core::Source {
+				self.source
+			}
+		}
+	};
+}
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 struct HLC {
@@ -49,6 +70,9 @@ impl OHLCV for HLC {
 	}
 }
 
+mod alligator;
+pub use alligator::{Alligator, AlligatorInstance};
+
 mod aroon;
 pub use aroon::{Aroon, AroonInstance};
 
@@ -58,6 +82,12 @@ pub use average_directional_index::{AverageDirectionalIndex, AverageDirectionalI
 mod awesome_oscillator;
 pub use awesome_oscillator::{AwesomeOscillator, AwesomeOscillatorInstance};
 
+mod band_width;
+pub use band_width::{BandWidth, BandWidthInstance};
+
+mod beta_indicator;
+pub use beta_indicator::{BetaIndicator, BetaIndicatorInstance};
+
 mod bollinger_bands;
 pub use bollinger_bands::{BollingerBands, BollingerBandsInstance};
 
@@ -73,6 +103,9 @@ pub use chande_kroll_stop::{ChandeKrollStop, ChandeKrollStopInstance};
 mod chande_momentum_oscillator;
 pub use chande_momentum_oscillator::{ChandeMomentumOscillator, ChandeMomentumOscillatorInstance};
 
+mod chandelier_exit;
+pub use chandelier_exit::{ChandelierExit, ChandelierExitInstance};
+
 mod commodity_channel_index;
 pub use commodity_channel_index::{CommodityChannelIndex, CommodityChannelIndexInstance};
 
@@ -88,6 +121,9 @@ pub use donchian_channel::{DonchianChannel, DonchianChannelInstance};
 mod ease_of_movement;
 pub use ease_of_movement::{EaseOfMovement, EaseOfMovementInstance};
 
+mod elder_impulse;
+pub use elder_impulse::{ElderImpulse, ElderImpulseInstance};
+
 mod elders_force_index;
 pub use elders_force_index::{EldersForceIndex, EldersForceIndexInstance};
 
@@ -97,6 +133,11 @@ pub use envelopes::{Envelopes, EnvelopesInstance};
 mod fisher_transform;
 pub use fisher_transform::{FisherTransform, FisherTransformInstance};
 
+mod guppy_multiple_moving_average;
+pub use guppy_multiple_moving_average::{
+	GuppyMultipleMovingAverage, GuppyMultipleMovingAverageInstance,
+};
+
 mod hull_moving_average;
 pub use hull_moving_average::{HullMovingAverage, HullMovingAverageInstance};
 
@@ -110,13 +151,27 @@ mod keltner_channel;
 pub use keltner_channel::{KeltnerChannel, KeltnerChannelInstance};
 
 mod klinger_volume_oscillator;
-pub use klinger_volume_oscillator::{KlingerVolumeOscillator, KlingerVolumeOscillatorInstance};
+pub use klinger_volume_oscillator::{
+	Klinger, KlingerVolumeOscillator, KlingerVolumeOscillatorInstance,
+};
 
 mod know_sure_thing;
 pub use know_sure_thing::{KnowSureThing, KnowSureThingInstance};
 
+mod lin_reg_channel;
+pub use lin_reg_channel::{LinRegChannel, LinRegChannelInstance};
+
 mod macd;
-pub use macd::{MovingAverageConvergenceDivergence, MACD, MACDInstance};
+pub use macd::{MACDInstance, MovingAverageConvergenceDivergence, MACD};
+
+mod macd_divergence;
+pub use macd_divergence::{MacdDivergence, MacdDivergenceInstance};
+
+mod mass_index;
+pub use mass_index::{MassIndex, MassIndexInstance};
+
+mod mean_reversion;
+pub use mean_reversion::{MeanReversion, MeanReversionInstance};
 
 mod momentum_index;
 pub use momentum_index::{MomentumIndex, MomentumIndexInstance};
@@ -124,29 +179,54 @@ pub use momentum_index::{MomentumIndex, MomentumIndexInstance};
 mod money_flow_index;
 pub use money_flow_index::{MoneyFlowIndex, MoneyFlowIndexInstance};
 
+mod normalized_macd;
+pub use normalized_macd::{NormalizedMACD, NormalizedMACDInstance};
+
 mod parabolic_sar;
 pub use parabolic_sar::{ParabolicSAR, ParabolicSARInstance, ParabolicStopAndReverse};
 
+mod percentage_price_oscillator;
+pub use percentage_price_oscillator::{
+	PercentagePriceOscillator, PercentagePriceOscillatorInstance, PPO,
+};
+
+mod pivot_points;
+pub use pivot_points::{PivotLevels, PivotPoints, PivotPointsInstance, PivotPointsMethod};
+
 mod pivot_reversal_strategy;
 pub use pivot_reversal_strategy::{PivotReversalStrategy, PivotReversalStrategyInstance};
 
 mod price_channel_strategy;
 pub use price_channel_strategy::{PriceChannelStrategy, PriceChannelStrategyInstance};
 
+mod rainbow_oscillator;
+pub use rainbow_oscillator::{RainbowOscillator, RainbowOscillatorInstance};
+
 mod relative_strength_index;
 pub use relative_strength_index::{RelativeStrengthIndex, RelativeStrengthIndexInstance, RSI};
 
 mod relative_vigor_index;
 pub use relative_vigor_index::{RelativeVigorIndex, RelativeVigorIndexInstance};
 
+mod schaff_trend_cycle;
+pub use schaff_trend_cycle::{SchaffTrendCycle, SchaffTrendCycleInstance};
+
 mod smi_ergodic_indicator;
 pub use smi_ergodic_indicator::{SMIErgodicIndicator, SMIErgodicIndicatorInstance};
 
+mod smoothed_heikin_ashi;
+pub use smoothed_heikin_ashi::{SmoothedHeikinAshi, SmoothedHeikinAshiInstance};
+
 mod stochastic_oscillator;
 pub use stochastic_oscillator::{StochasticOscillator, StochasticOscillatorInstance};
 
+mod stochastic_relative_strength_index;
+pub use stochastic_relative_strength_index::{
+	StochRSI, StochasticRelativeStrengthIndex, StochasticRelativeStrengthIndexInstance,
+};
+
 mod trix;
-pub use trix::{Trix, TRIXInstance};
+pub use trix::{TRIXInstance, Trix};
 
 mod trend_strength_index;
 pub use trend_strength_index::{TrendStrengthIndex, TrendStrengthIndexInstance};
@@ -156,3 +236,226 @@ pub use true_strength_index::{TrueStrengthIndex, TrueStrengthIndexInstance};
 
 mod woodies_cci;
 pub use woodies_cci::{WoodiesCCI, WoodiesCCIInstance};
+
+macro_rules! indicator_from_name {
+	($name:expr, $($indicator:ty),* $(,)?) => {{
+		$(
+			if $name == <$indicator as IndicatorConfig>::NAME {
+				return Some(Box::new(<$indicator>::default()));
+			}
+		)*
+
+		None
+	}};
+}
+
+/// Creates a dynamically dispatchable indicator **Configuration** with default parameters by indicator's name
+///
+/// `name` must be equal to the [`IndicatorConfig::NAME`] of an indicator. Returns `None` if there is no indicator with such `name`.
+///
+/// Indicators which need more inputs than a candle (f.e. [`BetaIndicator`]) are not available here.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::dd::*;
+/// use yata::helpers::RandomCandles;
+/// use yata::indicators::indicator_from_name;
+///
+/// let candles: Vec<_> = RandomCandles::new().take(10).collect();
+///
+/// let mut config = indicator_from_name("PivotReversalStrategy").unwrap();
+/// config.set("left", "3".to_string()).unwrap();
+///
+/// let results = config.over(&candles).unwrap();
+/// println!("{:?}", results);
+///
+/// assert!(indicator_from_name::<yata::core::Candle>("Unknown").is_none());
+/// ```
+#[must_use]
+pub fn indicator_from_name<T: OHLCV>(name: &str) -> Option<Box<dyn IndicatorConfigDyn<T>>> {
+	indicator_from_name!(
+		name,
+		Alligator,
+		Aroon,
+		AverageDirectionalIndex,
+		AwesomeOscillator,
+		BandWidth,
+		BollingerBands,
+		ChaikinMoneyFlow,
+		ChaikinOscillator,
+		ChandeKrollStop,
+		ChandeMomentumOscillator,
+		ChandelierExit,
+		CommodityChannelIndex,
+		CoppockCurve,
+		DetrendedPriceOscillator,
+		DonchianChannel,
+		EaseOfMovement,
+		ElderImpulse,
+		EldersForceIndex,
+		Envelopes,
+		example::Example,
+		FisherTransform,
+		GuppyMultipleMovingAverage,
+		HullMovingAverage,
+		IchimokuCloud,
+		Kaufman,
+		KeltnerChannel,
+		KlingerVolumeOscillator,
+		KnowSureThing,
+		LinRegChannel,
+		MACD,
+		MacdDivergence,
+		MassIndex,
+		MeanReversion,
+		MomentumIndex,
+		MoneyFlowIndex,
+		NormalizedMACD,
+		ParabolicSAR,
+		PercentagePriceOscillator,
+		PivotPoints,
+		PivotReversalStrategy,
+		PriceChannelStrategy,
+		RainbowOscillator,
+		RelativeStrengthIndex,
+		RelativeVigorIndex,
+		SchaffTrendCycle,
+		SMIErgodicIndicator,
+		SmoothedHeikinAshi,
+		StochasticOscillator,
+		StochasticRelativeStrengthIndex,
+		TrendStrengthIndex,
+		Trix,
+		TrueStrengthIndex,
+		WoodiesCCI,
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::indicator_from_name;
+	use crate::core::Candle;
+	use crate::helpers::RandomCandles;
+
+	const NAMES: [&str; 53] = [
+		"Alligator",
+		"Aroon",
+		"AverageDirectionalIndex",
+		"AwesomeOscillator",
+		"BandWidth",
+		"BollingerBands",
+		"ChaikinMoneyFlow",
+		"ChaikinOscillator",
+		"ChandeKrollStop",
+		"ChandeMomentumOscillator",
+		"ChandelierExit",
+		"CommodityChannelIndex",
+		"CoppockCurve",
+		"DetrendedPriceOscillator",
+		"DonchianChannel",
+		"EaseOfMovement",
+		"ElderImpulse",
+		"EldersForceIndex",
+		"Envelopes",
+		"Example",
+		"FisherTransform",
+		"GuppyMultipleMovingAverage",
+		"HullMovingAverage",
+		"IchimokuCloud",
+		"Kaufman",
+		"KeltnerChannel",
+		"KlingerVolumeOscillator",
+		"KnowSureThing",
+		"LinRegChannel",
+		"MACD",
+		"MacdDivergence",
+		"MassIndex",
+		"MeanReversion",
+		"MomentumIndex",
+		"MoneyFlowIndex",
+		"NormalizedMACD",
+		"ParabolicSAR",
+		"PercentagePriceOscillator",
+		"PivotPoints",
+		"PivotReversalStrategy",
+		"PriceChannelStrategy",
+		"RainbowOscillator",
+		"RelativeStrengthIndex",
+		"RelativeVigorIndex",
+		"SchaffTrendCycle",
+		"SMIErgodicIndicator",
+		"SmoothedHeikinAshi",
+		"StochasticOscillator",
+		"StochasticRelativeStrengthIndex",
+		"TrendStrengthIndex",
+		"Trix",
+		"TrueStrengthIndex",
+		"WoodiesCCI",
+	];
+
+	#[test]
+	fn test_indicator_from_name() {
+		for name in NAMES {
+			let config = indicator_from_name::<Candle>(name).unwrap();
+			assert_eq!(config.name(), name);
+			assert!(config.validate());
+		}
+
+		for name in [
+			"",
+			"Unknown",
+			"pivotreversalstrategy",
+			"MACD ",
+			"BetaIndicator",
+		] {
+			assert!(indicator_from_name::<Candle>(name).is_none());
+		}
+	}
+
+	#[test]
+	fn test_value_and_signal_names() {
+		let candles: Vec<_> = RandomCandles::new().take(10).collect();
+
+		for name in NAMES {
+			let config = indicator_from_name::<Candle>(name).unwrap();
+			let instance = config.init(&candles[0]).unwrap();
+			let (values, signals) = instance.size();
+
+			assert_eq!(instance.value_names().len(), values as usize, "{name}");
+			assert_eq!(instance.signal_names().len(), signals as usize, "{name}");
+		}
+	}
+
+	#[test]
+	fn test_init_from() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+
+		for name in NAMES {
+			let config = indicator_from_name::<Candle>(name).unwrap();
+
+			let mut warmed = config.init_from(&candles[..50]).unwrap();
+			let mut instance = config.init(&candles[0]).unwrap();
+
+			for candle in &candles[..50] {
+				let _ = instance.next(candle);
+			}
+
+			for candle in &candles[50..] {
+				let (a, b) = (warmed.next(candle), instance.next(candle));
+
+				assert_eq!(a.signals(), b.signals(), "{name}");
+				assert!(
+					a.values()
+						.iter()
+						.zip(b.values())
+						.all(|(x, y)| x.to_bits() == y.to_bits()),
+					"{name}"
+				);
+			}
+		}
+
+		let config = indicator_from_name::<Candle>("MACD").unwrap();
+		assert!(config.init_from(&[]).is_err());
+	}
+}
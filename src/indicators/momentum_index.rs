@@ -39,6 +39,8 @@ pub struct MomentumIndex {
 	pub source: Source,
 }
 
+impl_candle_source!(MomentumIndex);
+
 impl IndicatorConfig for MomentumIndex {
 	type Instance = MomentumIndexInstance;
 
@@ -117,6 +119,14 @@ impl IndicatorInstance for MomentumIndexInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["slow_momentum", "fast_momentum"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["momentum"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = &candle.source(self.cfg.source);
 
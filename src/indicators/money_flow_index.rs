@@ -142,6 +142,14 @@ impl IndicatorInstance for MoneyFlowIndexInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["upper", "mfi", "lower"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["enters_zone", "leaves_zone"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let static_candle = Candle::from(candle);
 		let (pos, neg) = tfunc(&static_candle, &self.prev_candle);
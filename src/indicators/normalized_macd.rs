@@ -0,0 +1,304 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::MA;
+use crate::indicators::{MACDInstance, MACD};
+use crate::methods::{AbsMax, Cross};
+
+/// Share of the `source` value, below which the histogram is treated as zero
+const HISTOGRAM_EPSILON: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-5
+} else {
+	1e-9
+};
+
+/// Normalized Moving average convergence/divergence histogram
+///
+/// Raw [`MACD`](crate::indicators::MACD) histogram (`MACD` - `Signal line`) scales with the price, so it can't be compared across different instruments.
+/// This indicator divides the histogram by its highest absolute value over the last `window` values,
+/// so the output is always in range \[`-1.0`; `1.0`\] and does not depend on the price scale.
+///
+/// When highest absolute value is negligible relative to the `source` value (f.e. when the histogram decays after a flat period
+/// or consists of float rounding errors only), output is `0.0`.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/MACD>
+///
+/// # 1 value
+///
+/// * Normalized `histogram` value
+///
+/// Range in \[`-1.0`; `1.0`\].
+///
+/// # 1 signal
+///
+/// * When normalized `histogram` crosses zero line upwards, returns full buy signal.
+///   When normalized `histogram` crosses zero line downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NormalizedMACD<M: MovingAverageConstructor = MA> {
+	/// MACD configuration for the histogram, which is calculated exactly as in [`MACD`](crate::indicators::MACD) indicator.
+	///
+	/// Default is [`MACD::default`](crate::indicators::MACD).
+	pub macd: MACD<M>,
+
+	/// Normalization window size. Default is `100`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub window: PeriodType,
+}
+
+impl<M: MovingAverageConstructor> IndicatorConfig for NormalizedMACD<M> {
+	type Instance = NormalizedMACDInstance<M>;
+
+	const NAME: &'static str = "NormalizedMACD";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+
+		Ok(Self::Instance {
+			macd: cfg.macd.clone().init(candle)?,
+			abs_max: AbsMax::new(cfg.window, &0.0)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.macd.validate()
+			&& self.macd.ma2.ma_period() < PeriodType::MAX
+			&& self.macd.signal.ma_period() < PeriodType::MAX
+			&& self.window > 0
+			&& self.window < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"ma1" | "ma2" | "signal" | "source" => self.macd.set(name, value)?,
+			"window" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.window = value,
+			},
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for NormalizedMACD {
+	fn default() -> Self {
+		Self {
+			macd: MACD::default(),
+			window: 100,
+		}
+	}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "M: Serialize, M::Instance: Serialize",
+		deserialize = "M: Deserialize<'de>, M::Instance: Deserialize<'de>"
+	))
+)]
+pub struct NormalizedMACDInstance<M: MovingAverageConstructor = MA> {
+	cfg: NormalizedMACD<M>,
+
+	macd: MACDInstance<M>,
+	abs_max: AbsMax,
+	cross: Cross,
+}
+
+// `derive` can't see through `MACDInstance<M>` that it needs `M::Instance` bounds
+impl<M: MovingAverageConstructor + std::fmt::Debug> std::fmt::Debug for NormalizedMACDInstance<M>
+where
+	M::Instance: std::fmt::Debug,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("NormalizedMACDInstance")
+			.field("cfg", &self.cfg)
+			.field("macd", &self.macd)
+			.field("abs_max", &self.abs_max)
+			.field("cross", &self.cross)
+			.finish()
+	}
+}
+
+impl<M: MovingAverageConstructor + Clone> Clone for NormalizedMACDInstance<M>
+where
+	M::Instance: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			cfg: self.cfg.clone(),
+			macd: self.macd.clone(),
+			abs_max: self.abs_max.clone(),
+			cross: self.cross,
+		}
+	}
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for NormalizedMACDInstance<M> {
+	type Config = NormalizedMACD<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["histogram"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_cross"]
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.macd.source);
+
+		let macd = self.macd.next(candle);
+		let histogram = macd.value(0) - macd.value(1);
+
+		let abs_max = self.abs_max.next(&histogram);
+		let value = if abs_max > src.abs() * HISTOGRAM_EPSILON {
+			histogram / abs_max
+		} else {
+			0.0
+		};
+
+		let signal = self.cross.next(&(value, 0.0));
+
+		IndicatorResult::new(&[value], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::NormalizedMACD;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::MACD;
+	use crate::methods::AbsMax;
+	use crate::prelude::Method;
+
+	#[test]
+	fn test_normalized_macd_const() {
+		let candle = Candle {
+			open: 10.0,
+			high: 12.0,
+			low: 9.0,
+			close: 11.0,
+			volume: 1.0,
+		};
+		let mut instance = NormalizedMACD::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			assert_eq_float(0.0, instance.next(&candle).value(0));
+		}
+	}
+
+	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+		1e-3
+	} else {
+		1e-6
+	};
+
+	#[test]
+	fn test_normalized_macd_flat_after_move() {
+		let candle = |close: ValueType| Candle {
+			open: close,
+			high: close,
+			low: close,
+			close,
+			volume: 1.0,
+		};
+		let mut instance = NormalizedMACD::default().init(&candle(100.0)).unwrap();
+
+		for i in 0..50 {
+			let _ = instance.next(&candle(100.0 + i as ValueType));
+		}
+
+		// histogram decays to zero after the move, so it must not be normalized back to `1.0`
+		let results: Vec<_> = (0..2000).map(|_| instance.next(&candle(150.0))).collect();
+
+		for result in &results[1000..] {
+			assert_eq_float(0.0, result.value(0));
+			assert!(result.signal(0).is_none());
+		}
+	}
+
+	#[test]
+	fn test_normalized_macd_price_scale() {
+		let candles: Vec<Candle> = RandomCandles::new().take(500).collect();
+		let scale = |candle: &Candle, k: ValueType| Candle {
+			open: candle.open * k,
+			high: candle.high * k,
+			low: candle.low * k,
+			close: candle.close * k,
+			volume: candle.volume,
+		};
+
+		for window in [1, 10, 100] {
+			let config = NormalizedMACD {
+				window,
+				..NormalizedMACD::default()
+			};
+
+			let base = config.over(&candles).unwrap();
+			let mut max = 0.0;
+
+			for k in [0.01, 3.0, 250.0, 1e5] {
+				let scaled: Vec<_> = candles.iter().map(|x| scale(x, k)).collect();
+				let results = config.over(&scaled).unwrap();
+
+				for (a, b) in base.iter().zip(&results) {
+					let value = b.value(0);
+					assert!(
+						(-1.0..=1.0).contains(&value),
+						"{value} with window {window}"
+					);
+					assert!((a.value(0) - value).abs() < SIGMA);
+
+					max = value.abs().max(max);
+				}
+			}
+
+			assert_eq_float(1.0, max);
+		}
+	}
+
+	#[test]
+	fn test_normalized_macd_histogram() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+
+		let macd = MACD::default().over(&candles).unwrap();
+		let normalized = NormalizedMACD::default().over(&candles).unwrap();
+
+		let mut abs_max = AbsMax::new(100, &0.0).unwrap();
+		for (a, b) in macd.iter().zip(&normalized) {
+			let histogram = a.value(0) - a.value(1);
+			let max = abs_max.next(&histogram);
+			let expected = if max > 0.0 { histogram / max } else { 0.0 };
+
+			assert_eq_float(expected, b.value(0));
+		}
+	}
+}
@@ -118,6 +118,14 @@ impl IndicatorInstance for ParabolicSARInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["sar", "trend"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["trend_reversal"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		match self.trend.cmp(&0) {
 			Ordering::Greater => {
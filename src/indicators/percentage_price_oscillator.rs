@@ -0,0 +1,275 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Action, Error, Method, MovingAverageConstructor, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::MA;
+use crate::methods::Cross;
+
+/// Share of the `source` value (or of the fast moving average), below which slow moving average is treated as zero
+const SLOW_EPSILON: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-5
+} else {
+	1e-9
+};
+
+/// Percentage Price Oscillator (PPO)
+///
+/// It is the same as [`MACD`](crate::indicators::MACD), but the difference between moving averages is expressed
+/// as a percentage of the slow moving average, so it is comparable across instruments with different prices.
+///
+/// `PPO` = `100` * (`fast MA` - `slow MA`) / `slow MA`
+///
+/// When slow moving average is negligible relative to the `source` value and to the fast moving average,
+/// `PPO` is undefined, so `PPO` and `histogram` are [`NaN`](ValueType::NAN), `signal line` keeps its previous value
+/// and no signal is produced.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/p/ppo.asp>
+///
+/// # 3 values
+///
+/// * `PPO` value
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// * `Signal line` value
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// * `Histogram` value (`PPO` - `Signal line`)
+///
+/// Range in \(`-inf`; `+inf`\).
+///
+/// # 1 signal
+///
+/// * When `PPO` crosses `Signal line` upwards, returns full buy signal.
+///   When `PPO` crosses `Signal line` downwards, returns full sell signal.
+///   Otherwise returns no signal.
+///
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentagePriceOscillator<M: MovingAverageConstructor = MA> {
+	/// Fast MA type.
+	///
+	/// Default is [`EMA(12)`](crate::methods::EMA).
+	///
+	/// Period range in \[`2`; slow's period\)
+	pub fast: M,
+
+	/// Slow MA type.
+	///
+	/// Default is [`EMA(26)`](crate::methods::EMA).
+	///
+	/// Period range in \(fast's period; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub slow: M,
+
+	/// Signal line MA type.
+	///
+	/// Default is [`EMA(9)`](crate::methods::EMA).
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub signal: M,
+
+	/// Source value type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(PercentagePriceOscillator<M>);
+
+impl<M: MovingAverageConstructor> IndicatorConfig for PercentagePriceOscillator<M> {
+	type Instance = PercentagePriceOscillatorInstance<M>;
+
+	const NAME: &'static str = "PercentagePriceOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			fast: cfg.fast.init(src)?,
+			slow: cfg.slow.init(src)?,
+			signal: cfg.signal.init(0.)?,
+			last_sigline: 0.0,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.fast.ma_period() < self.slow.ma_period()
+			&& self.fast.ma_period() > 1
+			&& self.signal.ma_period() > 1
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"fast" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.fast = value,
+			},
+			"slow" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.slow = value,
+			},
+			"signal" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.signal = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for PercentagePriceOscillator {
+	fn default() -> Self {
+		Self {
+			fast: MA::EMA(12),
+			slow: MA::EMA(26),
+			signal: MA::EMA(9),
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for `PercentagePriceOscillator`
+pub type PPO<M = MA> = PercentagePriceOscillator<M>;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentagePriceOscillatorInstance<M: MovingAverageConstructor = MA> {
+	cfg: PercentagePriceOscillator<M>,
+
+	fast: M::Instance,
+	slow: M::Instance,
+	signal: M::Instance,
+	last_sigline: ValueType,
+	cross: Cross,
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for PercentagePriceOscillatorInstance<M> {
+	type Config = PercentagePriceOscillator<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["ppo", "signal_line", "histogram"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["signal_line_cross"]
+	}
+
+	#[inline]
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = &candle.source(self.cfg.source);
+
+		let fast = self.fast.next(src);
+		let slow = self.slow.next(src);
+
+		if slow.abs() <= src.abs().max(fast.abs()) * SLOW_EPSILON {
+			return IndicatorResult::new(
+				&[ValueType::NAN, self.last_sigline, ValueType::NAN],
+				&[Action::None],
+			);
+		}
+
+		let ppo = (fast - slow) / slow * 100.0;
+
+		let sigline = self.signal.next(&ppo);
+		self.last_sigline = sigline;
+		let histogram = ppo - sigline;
+
+		let signal = self.cross.next(&(ppo, sigline));
+
+		IndicatorResult::new(&[ppo, sigline, histogram], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::PercentagePriceOscillator;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_ppo_const() {
+		let candle = Candle {
+			open: 10.0,
+			high: 12.0,
+			low: 9.0,
+			close: 11.0,
+			volume: 1.0,
+		};
+		let mut instance = PercentagePriceOscillator::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq_float(0.0, result.value(0));
+			assert_eq_float(0.0, result.value(1));
+			assert_eq_float(0.0, result.value(2));
+		}
+	}
+
+	#[test]
+	fn test_ppo_zero_slow() {
+		let candle = |close: ValueType| Candle {
+			open: close,
+			high: close,
+			low: close,
+			close,
+			volume: 1.0,
+		};
+
+		// slow moving average is zero, so `PPO` is undefined
+		let mut instance = PercentagePriceOscillator::default()
+			.init(&candle(0.0))
+			.unwrap();
+		for _ in 0..50 {
+			let result = instance.next(&candle(0.0));
+
+			assert!(result.value(0).is_nan());
+			assert_eq!(result.value(1).to_bits(), ValueType::to_bits(0.0));
+			assert!(result.value(2).is_nan());
+			assert!(result.signal(0).is_none());
+		}
+
+		let result = instance.next(&candle(10.0));
+		assert!(result.value(0).is_finite());
+		assert!(result.value(2).is_finite());
+	}
+
+	#[test]
+	fn test_ppo_histogram() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let mut instance = PercentagePriceOscillator::default()
+			.init(&candles[0])
+			.unwrap();
+
+		for candle in &candles {
+			let result = instance.next(candle);
+
+			assert_eq_float(result.value(0) - result.value(1), result.value(2));
+		}
+	}
+}
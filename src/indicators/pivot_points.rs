@@ -0,0 +1,374 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, PeriodType, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use std::str::FromStr;
+
+/// Method of calculating support and resistance levels for [`PivotPoints`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum PivotPointsMethod {
+	/// Classic floor-trader pivot points
+	///
+	/// * `R1` = 2 \* `P` - `L`, `S1` = 2 \* `P` - `H`
+	/// * `R2` = `P` + \(`H` - `L`\), `S2` = `P` - \(`H` - `L`\)
+	/// * `R3` = `H` + 2 \* \(`P` - `L`\), `S3` = `L` - 2 \* \(`H` - `P`\)
+	Classic,
+
+	/// Fibonacci pivot points
+	///
+	/// * `R1` = `P` + 0.382 \* \(`H` - `L`\), `S1` = `P` - 0.382 \* \(`H` - `L`\)
+	/// * `R2` = `P` + 0.618 \* \(`H` - `L`\), `S2` = `P` - 0.618 \* \(`H` - `L`\)
+	/// * `R3` = `P` + \(`H` - `L`\), `S3` = `P` - \(`H` - `L`\)
+	Fibonacci,
+
+	/// Camarilla pivot points
+	///
+	/// * `R1` = `C` + 1.1 / 12 \* \(`H` - `L`\), `S1` = `C` - 1.1 / 12 \* \(`H` - `L`\)
+	/// * `R2` = `C` + 1.1 / 6 \* \(`H` - `L`\), `S2` = `C` - 1.1 / 6 \* \(`H` - `L`\)
+	/// * `R3` = `C` + 1.1 / 4 \* \(`H` - `L`\), `S3` = `C` - 1.1 / 4 \* \(`H` - `L`\)
+	Camarilla,
+}
+
+impl FromStr for PivotPointsMethod {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().trim() {
+			"classic" => Ok(Self::Classic),
+			"fibonacci" => Ok(Self::Fibonacci),
+			"camarilla" => Ok(Self::Camarilla),
+
+			value => Err(Error::ParameterParse(
+				"method".to_string(),
+				value.to_string(),
+			)),
+		}
+	}
+}
+
+/// Pivot point with support and resistance levels
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotLevels {
+	/// Pivot point `P` = \(`H` + `L` + `C`\) / 3
+	pub pivot: ValueType,
+	/// Resistance levels `R1`, `R2` and `R3`
+	pub resistance: [ValueType; 3],
+	/// Support levels `S1`, `S2` and `S3`
+	pub support: [ValueType; 3],
+}
+
+impl PivotLevels {
+	fn new(method: PivotPointsMethod, high: ValueType, low: ValueType, close: ValueType) -> Self {
+		let pivot = (high + low + close) / 3.0;
+		let range = high - low;
+
+		let (resistance, support) = match method {
+			PivotPointsMethod::Classic => (
+				[
+					pivot.mul_add(2.0, -low),
+					pivot + range,
+					(pivot - low).mul_add(2.0, high),
+				],
+				[
+					pivot.mul_add(2.0, -high),
+					pivot - range,
+					(high - pivot).mul_add(-2.0, low),
+				],
+			),
+			PivotPointsMethod::Fibonacci => (
+				[
+					range.mul_add(0.382, pivot),
+					range.mul_add(0.618, pivot),
+					pivot + range,
+				],
+				[
+					range.mul_add(-0.382, pivot),
+					range.mul_add(-0.618, pivot),
+					pivot - range,
+				],
+			),
+			PivotPointsMethod::Camarilla => (
+				[
+					range.mul_add(1.1 / 12.0, close),
+					range.mul_add(1.1 / 6.0, close),
+					range.mul_add(1.1 / 4.0, close),
+				],
+				[
+					range.mul_add(-1.1 / 12.0, close),
+					range.mul_add(-1.1 / 6.0, close),
+					range.mul_add(-1.1 / 4.0, close),
+				],
+			),
+		};
+
+		Self {
+			pivot,
+			resistance,
+			support,
+		}
+	}
+}
+
+/// Pivot Points
+///
+/// Calculates floor-trader support and resistance levels based on `high`, `low` and `close` prices of the previous completed period.
+///
+/// Period consists of `period` consecutive candles. Levels stay the same during the whole period and are recalculated
+/// when the next period starts. Until the first period is completed, levels are calculated from the initial candle.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Pivot_point_(technical_analysis)>
+/// * <https://www.investopedia.com/terms/p/pivotpoint.asp>
+///
+/// # 3 values
+///
+/// [`IndicatorResult`] may hold only up to 4 raw values, so only the levels of the configured `level` are returned.
+/// All the levels are available through [`PivotPointsInstance::levels`].
+///
+/// * `pivot` point
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `resistance` level `R{level}`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// * `support` level `S{level}`
+///
+/// Range of values is the same as the range of the `source` values.
+///
+/// # Has no signals
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotPoints {
+	/// Levels calculation method. Default is [`Classic`](PivotPointsMethod::Classic)
+	pub method: PivotPointsMethod,
+	/// Count of candles in each period. Default is `24` (f.e. daily pivot points over hourly candles)
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub period: PeriodType,
+	/// Support and resistance level returned as raw values. Default is `1`
+	///
+	/// Range in \[`1`; `3`\]
+	pub level: u8,
+}
+
+impl IndicatorConfig for PivotPoints {
+	type Instance = PivotPointsInstance;
+
+	const NAME: &'static str = "PivotPoints";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		Ok(Self::Instance {
+			levels: PivotLevels::new(cfg.method, candle.high(), candle.low(), candle.close()),
+			high: ValueType::NEG_INFINITY,
+			low: ValueType::INFINITY,
+			count: 0,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0 && self.period < PeriodType::MAX && (1..=3).contains(&self.level)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.method = value,
+			},
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"level" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.level = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 0)
+	}
+}
+
+impl Default for PivotPoints {
+	fn default() -> Self {
+		Self {
+			method: PivotPointsMethod::Classic,
+			period: 24,
+			level: 1,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotPointsInstance {
+	cfg: PivotPoints,
+
+	levels: PivotLevels,
+	high: ValueType,
+	low: ValueType,
+	count: PeriodType,
+}
+
+impl PivotPointsInstance {
+	/// Returns all the levels calculated from the previous completed period
+	#[must_use]
+	pub const fn levels(&self) -> PivotLevels {
+		self.levels
+	}
+}
+
+impl IndicatorInstance for PivotPointsInstance {
+	type Config = PivotPoints;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["pivot", "resistance", "support"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&[]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let levels = self.levels;
+
+		self.high = self.high.max(candle.high());
+		self.low = self.low.min(candle.low());
+		self.count += 1;
+
+		if self.count == self.cfg.period {
+			self.levels = PivotLevels::new(self.cfg.method, self.high, self.low, candle.close());
+
+			self.high = ValueType::NEG_INFINITY;
+			self.low = ValueType::INFINITY;
+			self.count = 0;
+		}
+
+		let index = (self.cfg.level - 1) as usize;
+
+		IndicatorResult::new(
+			&[
+				levels.pivot,
+				levels.resistance[index],
+				levels.support[index],
+			],
+			&[],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{PivotPoints, PivotPointsMethod};
+	use crate::core::{IndicatorConfig, IndicatorInstance, ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_pivot_points_classic() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let cfg = PivotPoints {
+			period: 10,
+			..PivotPoints::default()
+		};
+
+		let mut instance = cfg.init(&candles[0]).unwrap();
+
+		for (i, candle) in candles.iter().enumerate() {
+			let result = instance.next(candle);
+
+			if i < 10 {
+				let c = &candles[0];
+				assert_eq_float((c.high() + c.low() + c.close()) / 3.0, result.value(0));
+				continue;
+			}
+
+			let start = i / 10 * 10 - 10;
+			let prev = &candles[start..start + 10];
+
+			let high = prev
+				.iter()
+				.map(OHLCV::high)
+				.fold(prev[0].high(), ValueType::max);
+			let low = prev
+				.iter()
+				.map(OHLCV::low)
+				.fold(prev[0].low(), ValueType::min);
+			let close = prev[9].close();
+			let pivot = (high + low + close) / 3.0;
+
+			assert_eq_float(pivot, result.value(0));
+			assert_eq_float(pivot.mul_add(2.0, -low), result.value(1));
+			assert_eq_float(pivot.mul_add(2.0, -high), result.value(2));
+
+			let levels = instance.levels();
+			if i % 10 != 9 {
+				assert_eq_float(pivot + high - low, levels.resistance[1]);
+				assert_eq_float(pivot - high + low, levels.support[1]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_pivot_points_levels_order() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for method in [
+			PivotPointsMethod::Classic,
+			PivotPointsMethod::Fibonacci,
+			PivotPointsMethod::Camarilla,
+		] {
+			for level in 1..=3 {
+				let cfg = PivotPoints {
+					method,
+					period: 7,
+					level,
+				};
+				let mut instance = cfg.init(&candles[0]).unwrap();
+
+				for candle in &candles {
+					let result = instance.next(candle);
+					let levels = instance.levels();
+
+					assert!(result.value(1) >= result.value(2));
+					assert!(levels.resistance.windows(2).all(|x| x[0] <= x[1]));
+					assert!(levels.support.windows(2).all(|x| x[0] >= x[1]));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_pivot_points_set_method() {
+		let mut cfg = PivotPoints::default();
+
+		cfg.set("method", "Fibonacci".to_string()).unwrap();
+		assert_eq!(cfg.method, PivotPointsMethod::Fibonacci);
+
+		assert!(cfg.set("method", "unknown".to_string()).is_err());
+	}
+}
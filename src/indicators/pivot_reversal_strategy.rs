@@ -2,7 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
-use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::core::{
+	IndicatorConfig, IndicatorInstance, IndicatorResult, Parameter, ReconfigurableInstance,
+};
 use crate::methods::{LowerReversalSignal, UpperReversalSignal};
 
 use super::HLC;
@@ -15,7 +17,25 @@ use super::HLC;
 ///
 /// * <https://www.incrediblecharts.com/technical/pivot_point_reversal.php>
 ///
-/// # No values
+/// # No values or 4 values
+///
+/// When `memory` is `1`, returns no values.
+///
+/// When `memory` > `1`, returns:
+///
+/// * `pivot_high` price of the last found high pivot
+///
+/// * `pivot_low` price of the last found low pivot
+///
+/// * `prev_pivot_high` price of the previous high pivot
+///
+/// * `prev_pivot_low` price of the previous low pivot
+///
+/// Pivot prices older than the previous ones are not returned as values. All the `memory` last pivot prices
+/// are available only through [`PivotReversalStrategyInstance::pivot_highs`] and [`PivotReversalStrategyInstance::pivot_lows`],
+/// so it is possible to detect higher-high / lower-low structure over more than two pivots.
+///
+/// Until enough pivots are found, missing prices are `0.0`.
 ///
 /// # 1 signal
 ///
@@ -37,6 +57,17 @@ pub struct PivotReversalStrategy {
 	///
 	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)-`left`\).
 	pub right: PeriodType,
+
+	/// How many last pivot highs and lows to keep. Default is `1`.
+	///
+	/// May be omitted when deserializing, which means the default value.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	#[cfg_attr(
+		feature = "serde",
+		serde(default = "PivotReversalStrategy::default_memory")
+	)]
+	pub memory: PeriodType,
 }
 
 impl IndicatorConfig for PivotReversalStrategy {
@@ -54,14 +85,18 @@ impl IndicatorConfig for PivotReversalStrategy {
 			ph: UpperReversalSignal::new(cfg.left, cfg.right, &candle.high())?,
 			pl: LowerReversalSignal::new(cfg.left, cfg.right, &candle.low())?,
 			window: Window::new(cfg.right, HLC::from(candle)),
-			hprice: 0.,
-			lprice: 0.,
+			highs: Window::new(cfg.memory, 0.),
+			lows: Window::new(cfg.memory, 0.),
 			cfg,
 		})
 	}
 
 	fn validate(&self) -> bool {
-		self.left >= 1 && self.right >= 1 && self.left.saturating_add(self.right) < PeriodType::MAX
+		self.left >= 1
+			&& self.right >= 1
+			&& self.left.saturating_add(self.right) < PeriodType::MAX
+			&& self.memory >= 1
+			&& self.memory < PeriodType::MAX
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -74,6 +109,10 @@ impl IndicatorConfig for PivotReversalStrategy {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.right = value,
 			},
+			"memory" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.memory = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -84,13 +123,45 @@ impl IndicatorConfig for PivotReversalStrategy {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(0, 1)
+		if self.memory > 1 {
+			(4, 1)
+		} else {
+			(0, 1)
+		}
+	}
+
+	fn warmup_period(&self) -> Option<usize> {
+		Some(self.left as usize + self.right as usize)
+	}
+
+	fn parameters(&self) -> Option<Vec<Parameter>> {
+		let max = (PeriodType::MAX - 1) as ValueType;
+
+		Some(vec![
+			Parameter::integer("left", self.left)
+				.min(1.0)
+				.max(max - self.right as ValueType),
+			Parameter::integer("right", self.right)
+				.min(1.0)
+				.max(max - self.left as ValueType),
+			Parameter::integer("memory", self.memory).min(1.0).max(max),
+		])
+	}
+}
+
+impl PivotReversalStrategy {
+	const fn default_memory() -> PeriodType {
+		1
 	}
 }
 
 impl Default for PivotReversalStrategy {
 	fn default() -> Self {
-		Self { left: 4, right: 2 }
+		Self {
+			left: 4,
+			right: 2,
+			memory: Self::default_memory(),
+		}
 	}
 }
 
@@ -102,8 +173,31 @@ pub struct PivotReversalStrategyInstance {
 	ph: UpperReversalSignal,
 	pl: LowerReversalSignal,
 	window: Window<HLC>,
-	hprice: ValueType,
-	lprice: ValueType,
+	highs: Window<ValueType>,
+	lows: Window<ValueType>,
+}
+
+impl PivotReversalStrategyInstance {
+	/// Returns an iterator over the last `memory` pivot high prices from the newest to the oldest
+	pub fn pivot_highs(&self) -> impl Iterator<Item = ValueType> + '_ {
+		self.highs.iter().copied()
+	}
+
+	/// Returns an iterator over the last `memory` pivot low prices from the newest to the oldest
+	pub fn pivot_lows(&self) -> impl Iterator<Item = ValueType> + '_ {
+		self.lows.iter().copied()
+	}
+
+	fn resize(window: &Window<ValueType>, memory: PeriodType) -> Window<ValueType> {
+		let kept: Vec<ValueType> = window.iter().take(memory as usize).copied().collect();
+		let mut resized = Window::new(memory, 0.);
+
+		for &price in kept.iter().rev() {
+			resized.push(price);
+		}
+
+		resized
+	}
 }
 
 impl IndicatorInstance for PivotReversalStrategyInstance {
@@ -113,6 +207,23 @@ impl IndicatorInstance for PivotReversalStrategyInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		if self.cfg.memory > 1 {
+			&[
+				"pivot_high",
+				"pivot_low",
+				"prev_pivot_high",
+				"prev_pivot_low",
+			]
+		} else {
+			&[]
+		}
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["pivot"]
+	}
+
 	#[allow(clippy::similar_names)]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (high, low) = (candle.high(), candle.low());
@@ -124,24 +235,291 @@ impl IndicatorInstance for PivotReversalStrategyInstance {
 		let mut le = 0;
 		let mut se = 0;
 
-		if swh.analog() > 0 {
-			self.hprice = past_candle.high();
+		if swh.is_buy() {
+			self.highs.push(past_candle.high());
 		}
 
-		if swh.analog() > 0 || candle.high() <= self.hprice {
+		let hprice = *self.highs.newest();
+		if swh.is_buy() || candle.high() <= hprice {
 			le = 1;
 		}
 
-		if swl.analog() > 0 {
-			self.lprice = past_candle.low();
+		if swl.is_buy() {
+			self.lows.push(past_candle.low());
 		}
 
-		if swl.analog() > 0 || low >= self.lprice {
+		let lprice = *self.lows.newest();
+		if swl.is_buy() || low >= lprice {
 			se = 1;
 		}
 
 		let r = se - le;
 
-		IndicatorResult::new(&[], &[r.into()])
+		if self.cfg.memory > 1 {
+			IndicatorResult::new(&[hprice, lprice, self.highs[1], self.lows[1]], &[r.into()])
+		} else {
+			IndicatorResult::new(&[], &[r.into()])
+		}
+	}
+}
+
+/// When `left` or `right` changes, the last `right` candles are kept (as many as fit into the new `right`)
+/// and pivot searching restarts over them, as if the indicator was initialized on the oldest kept candle.
+/// When `memory` changes, the newest pivot prices are kept (as many as fit into the new `memory`).
+/// Prices of the last found pivots always survive.
+impl ReconfigurableInstance for PivotReversalStrategyInstance {
+	fn reconfigure(&mut self, cfg: Self::Config) -> Result<(), Error> {
+		if !cfg.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		if cfg.left != self.cfg.left || cfg.right != self.cfg.right {
+			let kept: Vec<HLC> = self
+				.window
+				.iter()
+				.take(cfg.right as usize)
+				.copied()
+				.collect();
+			let oldest = kept
+				.last()
+				.copied()
+				.unwrap_or_else(|| *self.window.newest());
+
+			let mut window = Window::new(cfg.right, oldest);
+			let mut ph = UpperReversalSignal::new(cfg.left, cfg.right, &oldest.high())?;
+			let mut pl = LowerReversalSignal::new(cfg.left, cfg.right, &oldest.low())?;
+
+			for candle in kept.iter().rev() {
+				window.push(*candle);
+				let _ = ph.next(&candle.high());
+				let _ = pl.next(&candle.low());
+			}
+
+			self.window = window;
+			self.ph = ph;
+			self.pl = pl;
+		}
+
+		if cfg.memory != self.cfg.memory {
+			self.highs = Self::resize(&self.highs, cfg.memory);
+			self.lows = Self::resize(&self.lows, cfg.memory);
+		}
+
+		self.cfg = cfg;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::PivotReversalStrategy;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ReconfigurableInstance};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_pivot_reversal_strategy_memory() {
+		let candles: Vec<_> = [1.0, 3.0, 1.0, 4.0, 1.0, 5.0, 1.0, 1.0]
+			.iter()
+			.map(|&high| Candle {
+				open: high - 0.25,
+				high,
+				low: high - 0.5,
+				close: high - 0.25,
+				volume: 1.0,
+			})
+			.collect();
+
+		let cfg = PivotReversalStrategy {
+			left: 1,
+			right: 1,
+			memory: 3,
+		};
+		let mut instance = cfg.init(&candles[0]).unwrap();
+		let results: Vec<_> = candles.iter().map(|x| instance.next(x)).collect();
+
+		// ascending pivot highs: higher-high structure
+		let highs: Vec<_> = instance.pivot_highs().collect();
+		assert_eq!(highs, [5.0, 4.0, 3.0]);
+		assert_eq!(instance.pivot_lows().count(), 3);
+
+		let last = results.last().unwrap();
+		assert_eq!(last.size(), (4, 1));
+		assert_eq_float(5.0, last.value(0));
+		assert_eq_float(4.0, last.value(2));
+
+		// shrinking memory keeps the newest pivots
+		instance
+			.reconfigure(PivotReversalStrategy { memory: 2, ..cfg })
+			.unwrap();
+		let highs: Vec<_> = instance.pivot_highs().collect();
+		assert_eq!(highs, [5.0, 4.0]);
+
+		instance
+			.reconfigure(PivotReversalStrategy { memory: 1, ..cfg })
+			.unwrap();
+		let result = instance.next(&candles[0]);
+		assert_eq!(result.size(), (0, 1));
+		assert_eq!(instance.pivot_highs().collect::<Vec<_>>(), [5.0]);
+	}
+
+	#[test]
+	fn test_pivot_reversal_strategy_reconfigure() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let mut instance = PivotReversalStrategy::default().init(&candles[0]).unwrap();
+
+		for candle in &candles[..50] {
+			let _ = instance.next(candle);
+		}
+
+		assert_eq!(instance.window.len(), 2);
+
+		let cfg = PivotReversalStrategy {
+			left: 4,
+			right: 5,
+			memory: 1,
+		};
+		instance.reconfigure(cfg).unwrap();
+
+		assert_eq!(instance.window.len(), 5);
+		assert_eq!(instance.config().right, 5);
+		// the last 2 candles survive
+		assert_eq!(
+			instance.window[0].high.to_bits(),
+			candles[49].high.to_bits()
+		);
+		assert_eq!(
+			instance.window[1].high.to_bits(),
+			candles[48].high.to_bits()
+		);
+
+		instance
+			.reconfigure(PivotReversalStrategy {
+				left: 4,
+				right: 1,
+				memory: 1,
+			})
+			.unwrap();
+		assert_eq!(instance.window.len(), 1);
+		assert_eq!(
+			instance.window[0].high.to_bits(),
+			candles[49].high.to_bits()
+		);
+
+		for candle in &candles[50..] {
+			let _ = instance.next(candle);
+		}
+
+		assert!(instance
+			.reconfigure(PivotReversalStrategy {
+				left: 0,
+				right: 1,
+				memory: 1,
+			})
+			.is_err());
+		assert_eq!(instance.config().left, 4);
+	}
+
+	#[test]
+	fn test_pivot_reversal_strategy_reconfigure_left() {
+		let candles: Vec<_> = RandomCandles::new().take(100).collect();
+		let mut instance = PivotReversalStrategy::default().init(&candles[0]).unwrap();
+
+		for candle in &candles[..50] {
+			let _ = instance.next(candle);
+		}
+
+		let highs: Vec<_> = instance.pivot_highs().collect();
+		let cfg = PivotReversalStrategy {
+			left: 3,
+			..*instance.config()
+		};
+		instance.reconfigure(cfg).unwrap();
+
+		assert_eq!(instance.config().left, 3);
+		// prices of the last found pivots survive
+		assert_eq!(instance.pivot_highs().collect::<Vec<_>>(), highs);
+
+		for candle in &candles[50..] {
+			assert_eq!(instance.next(candle).size(), (0, 1));
+		}
+	}
+
+	#[test]
+	fn test_pivot_reversal_strategy_next_batch() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+
+		for memory in [1, 3] {
+			let cfg = PivotReversalStrategy {
+				memory,
+				..PivotReversalStrategy::default()
+			};
+			let mut batch = cfg.init(&candles[0]).unwrap();
+			let mut single = cfg.init(&candles[0]).unwrap();
+
+			// in two batches, so the state is carried over between them
+			let mut results = batch.next_batch(&candles[..100]);
+			results.extend(batch.next_batch(&candles[100..]));
+			assert_eq!(results.len(), candles.len());
+
+			for (candle, result) in candles.iter().zip(&results) {
+				let expected = single.next(candle);
+
+				assert_eq!(expected.values(), result.values());
+				assert_eq!(expected.signals(), result.signals());
+			}
+
+			assert!(batch.next_batch::<Candle>(&[]).is_empty());
+		}
+	}
+
+	#[test]
+	fn test_pivot_reversal_strategy_parameters() {
+		let cfg = PivotReversalStrategy::default();
+		let parameters = cfg.parameters().unwrap();
+		let names: Vec<_> = parameters.iter().map(|param| param.name).collect();
+
+		assert_eq!(names, ["left", "right", "memory"]);
+
+		// every described parameter is accepted by `set`
+		let mut cfg2 = cfg;
+		for param in parameters {
+			cfg2.set(param.name, "3".to_string()).unwrap();
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_pivot_reversal_strategy_deserialize_without_memory() {
+		let cfg: PivotReversalStrategy = serde_json::from_str(r#"{"left":4,"right":2}"#).unwrap();
+
+		assert_eq!(cfg.memory, 1);
+		assert_eq!(cfg.size(), (0, 1));
+	}
+
+	#[test]
+	#[cfg(feature = "json_schema")]
+	fn test_pivot_reversal_strategy_json_schema() {
+		let schema = PivotReversalStrategy::default().json_schema().unwrap();
+
+		assert_eq!(schema["title"], "PivotReversalStrategy");
+		assert_eq!(schema["type"], "object");
+
+		for (name, default) in [("left", 4), ("right", 2)] {
+			let param = &schema["properties"][name];
+
+			assert_eq!(param["type"], "integer");
+			assert_eq!(param["default"], default);
+			assert_eq!(param["minimum"], 1.0);
+			assert!(param["maximum"].as_f64().unwrap() >= 1.0);
+		}
+
+		let required: Vec<_> = schema["required"]
+			.as_array()
+			.unwrap()
+			.iter()
+			.map(|x| x.as_str().unwrap())
+			.collect();
+		assert_eq!(required, ["left", "right", "memory"]);
 	}
 }
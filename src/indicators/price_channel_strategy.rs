@@ -114,6 +114,14 @@ impl IndicatorInstance for PriceChannelStrategyInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["upper", "lower"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["touch"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (high, low) = (candle.high(), candle.low());
 		let highest = self.highest.next(&high);
@@ -0,0 +1,262 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::methods::{Cross, Highest, Lowest, SMA};
+
+/// Rainbow Oscillator
+///
+/// Source value is recursively smoothed by a chain of `depth` [`SMA`](crate::methods::SMA)s of length `period`,
+/// so the first line is `SMA(source)`, the second line is `SMA(SMA(source))` and so on. These lines form a "rainbow".
+///
+/// All the values are scaled by the `source` values range \(`HHV` - `LLV`\) over the last `lookback` values.
+///
+/// ## Links
+///
+/// * <https://www.tradingview.com/script/gWYg0ti0-Indicators-Rainbow-Charts-Oscillator-Binary-Wave-and-MAs/>
+///
+/// # 3 values
+///
+/// * `oscillator` value
+///
+/// \(`source` - average of the rainbow lines\) / \(`HHV` - `LLV`\)
+///
+/// Range of values is \(`-inf`; `+inf`\), but mostly it lays in \[`-1.0`; `1.0`\]
+///
+/// * `upper` band value
+///
+/// \(highest rainbow line - lowest rainbow line\) / \(`HHV` - `LLV`\)
+///
+/// Range of values is \[`0.0`; `+inf`\), but mostly it lays in \[`0.0`; `1.0`\]
+///
+/// * `lower` band value
+///
+/// Always equal to -`upper`.
+///
+/// When the `source` values range is `0.0`, all the values are `0.0`.
+///
+/// # 1 signal
+///
+/// When `oscillator` crosses zero upwards, returns full buy signal.
+/// When `oscillator` crosses zero downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RainbowOscillator {
+	/// Length of every [`SMA`](crate::methods::SMA) in the chain. Default is `2`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub period: PeriodType,
+
+	/// Count of [`SMA`](crate::methods::SMA)s in the chain. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub depth: PeriodType,
+
+	/// Period for searching highest and lowest `source` values. Default is `10`.
+	///
+	/// Range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub lookback: PeriodType,
+
+	/// Source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(RainbowOscillator);
+
+impl IndicatorConfig for RainbowOscillator {
+	type Instance = RainbowOscillatorInstance;
+
+	const NAME: &'static str = "RainbowOscillator";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			lines: (0..cfg.depth)
+				.map(|_| SMA::new(cfg.period, &src))
+				.collect::<Result<_, _>>()?,
+			highest: Highest::new(cfg.lookback, &src)?,
+			lowest: Lowest::new(cfg.lookback, &src)?,
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.period > 0
+			&& self.period < PeriodType::MAX
+			&& self.depth > 0
+			&& self.depth < PeriodType::MAX
+			&& self.lookback > 0
+			&& self.lookback < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.period = value,
+			},
+			"depth" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.depth = value,
+			},
+			"lookback" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.lookback = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(3, 1)
+	}
+}
+
+impl Default for RainbowOscillator {
+	fn default() -> Self {
+		Self {
+			period: 2,
+			depth: 10,
+			lookback: 10,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RainbowOscillatorInstance {
+	cfg: RainbowOscillator,
+
+	lines: Vec<SMA>,
+	highest: Highest,
+	lowest: Lowest,
+	cross: Cross,
+}
+
+impl IndicatorInstance for RainbowOscillatorInstance {
+	type Config = RainbowOscillator;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["oscillator", "upper", "lower"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zero_cross"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let mut value = src;
+		let mut sum = 0.0;
+		let mut min = ValueType::INFINITY;
+		let mut max = ValueType::NEG_INFINITY;
+
+		for line in &mut self.lines {
+			value = line.next(&value);
+
+			sum += value;
+			min = min.min(value);
+			max = max.max(value);
+		}
+
+		let range = self.highest.next(&src) - self.lowest.next(&src);
+
+		let (oscillator, upper) = if range > 0.0 {
+			let average = sum / self.cfg.depth as ValueType;
+
+			((src - average) / range, (max - min) / range)
+		} else {
+			(0.0, 0.0)
+		};
+
+		let signal = self.cross.next(&(oscillator, 0.0));
+
+		IndicatorResult::new(&[oscillator, upper, -upper], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::RainbowOscillator;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_rainbow_oscillator_const() {
+		let candle = Candle {
+			open: 5.0,
+			high: 6.0,
+			low: 4.0,
+			close: 5.0,
+			volume: 1.0,
+		};
+		let mut instance = RainbowOscillator::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq_float(0.0, result.value(0));
+			assert_eq_float(0.0, result.value(1));
+			assert_eq_float(0.0, result.value(2));
+			assert!(result.signal(0).is_none());
+		}
+	}
+
+	#[test]
+	fn test_rainbow_oscillator_bands() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let mut instance = RainbowOscillator::default().init(&candles[0]).unwrap();
+
+		for candle in &candles {
+			let result = instance.next(candle);
+
+			assert!(result.value(1) >= 0.0);
+			assert_eq_float(-result.value(1), result.value(2));
+		}
+	}
+
+	#[test]
+	fn test_rainbow_oscillator_uptrend() {
+		let mut instance = RainbowOscillator::default()
+			.init(&Candle::default())
+			.unwrap();
+
+		let mut last = 0.0;
+		for i in 1..100 {
+			let price = i as ValueType;
+			last = instance
+				.next(&Candle {
+					close: price,
+					..Candle::default()
+				})
+				.value(0);
+		}
+
+		// price is always above its smoothed lines in a steady uptrend
+		assert!(last > 0.0);
+	}
+}
@@ -1,10 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Error, Method, MovingAverageConstructor, Source, ValueType, OHLCV};
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::helpers::MA;
-use crate::methods::Cross;
+use crate::methods::{Cross, EMA};
 use std::mem::replace;
 
 /// Relative Strength Index
@@ -39,7 +39,7 @@ pub struct RelativeStrengthIndex<M: MovingAverageConstructor = MA> {
 	///
 	/// Default is [`EMA(14)`](crate::methods::EMA)
 	///
-	/// Period range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
 	pub ma: M,
 
 	/// Overbought/oversell relative zone. Default is `0.3`.
@@ -49,8 +49,18 @@ pub struct RelativeStrengthIndex<M: MovingAverageConstructor = MA> {
 
 	/// Source type of values. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
+
+	/// Period of [`EMA`](crate::methods::EMA) for pre-smoothing `source` values before RSI calculation.
+	/// Default is `0`.
+	///
+	/// Values `0` and `1` mean no smoothing at all.
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub source_smoothing: PeriodType,
 }
 
+impl_candle_source!(RelativeStrengthIndex<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
 	type Instance = RelativeStrengthIndexInstance<M>;
 
@@ -64,8 +74,15 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
 		let cfg = self;
 		let src = candle.source(cfg.source);
 
+		let smoothing = if cfg.source_smoothing > 1 {
+			Some(EMA::new(cfg.source_smoothing, &src)?)
+		} else {
+			None
+		};
+
 		Ok(Self::Instance {
 			previous_input: src,
+			smoothing,
 			posma: cfg.ma.init(0.)?,
 			negma: cfg.ma.init(0.)?,
 			cross_upper: Cross::new((), &(0.5, 1.0 - cfg.zone))?,
@@ -75,7 +92,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
 	}
 
 	fn validate(&self) -> bool {
-		self.ma.ma_period() > 2 && self.zone > 0. && self.zone <= 0.5
+		self.ma.ma_period() > 1
+			&& self.zone > 0.
+			&& self.zone <= 0.5
+			&& self.source_smoothing < PeriodType::MAX
 	}
 
 	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
@@ -92,6 +112,10 @@ impl<M: MovingAverageConstructor> IndicatorConfig for RelativeStrengthIndex<M> {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
 			},
+			"source_smoothing" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source_smoothing = value,
+			},
 
 			_ => {
 				return Err(Error::ParameterParse(name.to_string(), value));
@@ -112,6 +136,7 @@ impl Default for RelativeStrengthIndex {
 			ma: MA::EMA(14),
 			zone: 0.3,
 			source: Source::Close,
+			source_smoothing: 0,
 		}
 	}
 }
@@ -122,6 +147,7 @@ pub struct RelativeStrengthIndexInstance<M: MovingAverageConstructor = MA> {
 	cfg: RelativeStrengthIndex<M>,
 
 	previous_input: ValueType,
+	smoothing: Option<EMA>,
 	posma: M::Instance,
 	negma: M::Instance,
 	cross_upper: Cross,
@@ -138,8 +164,20 @@ impl<M: MovingAverageConstructor> IndicatorInstance for RelativeStrengthIndexIns
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["rsi"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["enters_zone", "leaves_zone"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
-		let src = candle.source(self.cfg.source);
+		let mut src = candle.source(self.cfg.source);
+
+		if let Some(ema) = &mut self.smoothing {
+			src = ema.next(&src);
+		}
 
 		let change = src - replace(&mut self.previous_input, src);
 
@@ -162,3 +200,42 @@ impl<M: MovingAverageConstructor> IndicatorInstance for RelativeStrengthIndexIns
 		IndicatorResult::new(&[value], &[signal1.into(), signal2.into()])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::RelativeStrengthIndex;
+	use crate::core::{IndicatorConfig, IndicatorInstance, PeriodType, Source, ValueType};
+	use crate::helpers::RandomCandles;
+
+	// sum of squared bar-to-bar changes of RSI over noisy `high` prices
+	fn jaggedness(source_smoothing: PeriodType) -> ValueType {
+		let cfg = RelativeStrengthIndex {
+			source: Source::High,
+			source_smoothing,
+			..RelativeStrengthIndex::default()
+		};
+
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let mut instance = cfg.init(&candles[0]).unwrap();
+
+		let values: Vec<ValueType> = candles.iter().map(|c| instance.next(c).value(0)).collect();
+
+		values.windows(2).map(|w| (w[1] - w[0]).powi(2)).sum()
+	}
+
+	#[test]
+	fn test_rsi_source_smoothing() {
+		let raw = jaggedness(0);
+
+		assert!((jaggedness(1) - raw).abs() < ValueType::EPSILON);
+
+		for source_smoothing in 2..20 {
+			let smoothed = jaggedness(source_smoothing);
+
+			assert!(
+				smoothed < raw,
+				"smoothing={source_smoothing}, smoothed={smoothed}, raw={raw}"
+			);
+		}
+	}
+}
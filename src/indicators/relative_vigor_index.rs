@@ -161,6 +161,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for RelativeVigorIndexInstan
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["main", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["signal_line_cross", "signal_line_cross_outside_zone"]
+	}
+
 	#[allow(clippy::similar_names)]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let close_open = candle.close() - self.prev_close;
@@ -0,0 +1,323 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::MA;
+use crate::methods::{CrossAbove, CrossUnder, Highest, Lowest};
+
+/// Stochastic normalization of the `value` over the window, which keeps the `prev` value when the window is flat
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Stochastic {
+	highest: Highest,
+	lowest: Lowest,
+	prev: ValueType,
+}
+
+impl Stochastic {
+	fn new(period: PeriodType, value: ValueType) -> Result<Self, Error> {
+		Ok(Self {
+			highest: Highest::new(period, &value)?,
+			lowest: Lowest::new(period, &value)?,
+			prev: 0.5,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: ValueType) -> ValueType {
+		let highest = self.highest.next(&value);
+		let lowest = self.lowest.next(&value);
+
+		// we need to check division by zero, so we can really just check if `highest` is equal to `lowest` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		if highest != lowest {
+			self.prev = (value - lowest) / (highest - lowest);
+		}
+
+		self.prev
+	}
+}
+
+/// Schaff Trend Cycle (STC)
+///
+/// Applies double stochastic normalization to the MACD line:
+///
+/// 1. `MACD` = `macd_fast`\(`source`\) - `macd_slow`\(`source`\);
+/// 2. `%K1` = stochastic of the `MACD` over `cycle` periods, `%D1` = `d1`\(`%K1`\);
+/// 3. `%K2` = stochastic of the `%D1` over `cycle` periods, `STC` = `d2`\(`%K2`\).
+///
+/// When the window of the stochastic is flat (highest value is equal to the lowest value), previous stochastic value is used.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/articles/forex/10/schaff-trend-cycle-indicator.asp>
+/// * <https://www.tradingview.com/support/solutions/43000592286-schaff-trend-cycle-stc/>
+///
+/// # 1 value
+///
+/// * `STC` value
+///
+/// Range in \[`0.0`; `1.0`\] (instead of classic \[`0`; `100`\]) for moving averages which do not overshoot (f.e. [`EMA`](crate::methods::EMA) or [`SMA`](crate::methods::SMA)).
+///
+/// # 1 signal
+///
+/// When `STC` value crosses lower bound (`zone`) upwards, returns full buy signal.
+/// When `STC` value crosses upper bound (`1.0` - `zone`) downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SchaffTrendCycle<M: MovingAverageConstructor = MA> {
+	/// Fast moving average for MACD line. Default is [`EMA(23)`](crate::methods::EMA).
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub macd_fast: M,
+
+	/// Slow moving average for MACD line. Default is [`EMA(50)`](crate::methods::EMA).
+	///
+	/// Period range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub macd_slow: M,
+
+	/// Period for searching highest and lowest values in both stochastic passes. Default is `10`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub cycle: PeriodType,
+
+	/// Moving average for smoothing the first stochastic pass. Default is [`EMA(3)`](crate::methods::EMA).
+	///
+	/// Period range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub d1: M,
+
+	/// Moving average for smoothing the second stochastic pass. Default is [`EMA(3)`](crate::methods::EMA).
+	///
+	/// Period range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub d2: M,
+
+	/// Zone size for overbought and oversold signals. Default is `0.25` (same as `75`/`25` on the classic scale).
+	///
+	/// Range in \[`0.0`; `0.5`\].
+	pub zone: ValueType,
+
+	/// Source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(SchaffTrendCycle<M>);
+
+impl<M: MovingAverageConstructor> IndicatorConfig for SchaffTrendCycle<M> {
+	type Instance = SchaffTrendCycleInstance<M>;
+
+	const NAME: &'static str = "SchaffTrendCycle";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let src = candle.source(cfg.source);
+
+		Ok(Self::Instance {
+			macd_fast: cfg.macd_fast.init(src)?,
+			macd_slow: cfg.macd_slow.init(src)?,
+			stoch1: Stochastic::new(cfg.cycle, 0.0)?,
+			d1: cfg.d1.init(0.5)?,
+			stoch2: Stochastic::new(cfg.cycle, 0.5)?,
+			d2: cfg.d2.init(0.5)?,
+			upper_zone: 1.0 - cfg.zone,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.macd_fast.ma_period() > 1
+			&& self.macd_fast.ma_period() < self.macd_slow.ma_period()
+			&& self.macd_slow.ma_period() < PeriodType::MAX
+			&& self.cycle > 1
+			&& self.cycle < PeriodType::MAX
+			&& self.d1.ma_period() > 0
+			&& self.d1.ma_period() < PeriodType::MAX
+			&& self.d2.ma_period() > 0
+			&& self.d2.ma_period() < PeriodType::MAX
+			&& (0.0..=0.5).contains(&self.zone)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"macd_fast" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.macd_fast = value,
+			},
+			"macd_slow" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.macd_slow = value,
+			},
+			"cycle" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.cycle = value,
+			},
+			"d1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.d1 = value,
+			},
+			"d2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.d2 = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(1, 1)
+	}
+}
+
+impl Default for SchaffTrendCycle<MA> {
+	fn default() -> Self {
+		Self {
+			macd_fast: MA::EMA(23),
+			macd_slow: MA::EMA(50),
+			cycle: 10,
+			d1: MA::EMA(3),
+			d2: MA::EMA(3),
+			zone: 0.25,
+			source: Source::Close,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SchaffTrendCycleInstance<M: MovingAverageConstructor = MA> {
+	cfg: SchaffTrendCycle<M>,
+
+	macd_fast: M::Instance,
+	macd_slow: M::Instance,
+	stoch1: Stochastic,
+	d1: M::Instance,
+	stoch2: Stochastic,
+	d2: M::Instance,
+	upper_zone: ValueType,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for SchaffTrendCycleInstance<M> {
+	type Config = SchaffTrendCycle<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["stc"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["bounds_cross"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let src = candle.source(self.cfg.source);
+
+		let macd = self.macd_fast.next(&src) - self.macd_slow.next(&src);
+
+		let k1 = self.stoch1.next(macd);
+		let d1 = self.d1.next(&k1);
+
+		let k2 = self.stoch2.next(d1);
+		let stc = self.d2.next(&k2);
+
+		let signal = self.cross_above.next(&(stc, self.cfg.zone))
+			- self.cross_under.next(&(stc, self.upper_zone));
+
+		IndicatorResult::new(&[stc], &[signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SchaffTrendCycle;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_schaff_trend_cycle_range() {
+		let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+		let mut instance = SchaffTrendCycle::default().init(&candles[0]).unwrap();
+
+		let mut signals = 0;
+		for candle in &candles {
+			let result = instance.next(candle);
+
+			assert!(
+				(0.0..=1.0).contains(&result.value(0)),
+				"{}",
+				result.value(0)
+			);
+			signals += result.signal(0).is_some() as usize;
+		}
+
+		assert!(signals > 0);
+	}
+
+	#[test]
+	fn test_schaff_trend_cycle_flat() {
+		let candle = Candle {
+			open: 5.0,
+			high: 6.0,
+			low: 4.0,
+			close: 5.0,
+			volume: 1.0,
+		};
+		let mut instance = SchaffTrendCycle::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq_float(0.5, result.value(0));
+			assert!(result.signal(0).is_none());
+		}
+	}
+
+	#[test]
+	fn test_schaff_trend_cycle_uptrend() {
+		let candles: Vec<_> = (0..200)
+			.map(|i| {
+				let price = (i as ValueType).mul_add(0.1, 10.0);
+				Candle {
+					open: price,
+					high: price,
+					low: price,
+					close: price,
+					volume: 1.0,
+				}
+			})
+			.collect();
+		let mut instance = SchaffTrendCycle::default().init(&candles[0]).unwrap();
+
+		let last = candles
+			.iter()
+			.map(|candle| instance.next(candle).value(0))
+			.last()
+			.unwrap();
+
+		assert!(last > 0.75);
+	}
+}
@@ -63,6 +63,8 @@ pub struct SMIErgodicIndicator<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(SMIErgodicIndicator<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for SMIErgodicIndicator<M> {
 	type Instance = SMIErgodicIndicatorInstance<M>;
 
@@ -161,6 +163,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for SMIErgodicIndicatorInsta
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["smi", "signal_line", "oscillator"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["signal_line_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 		let tsi = self.tsi.next(&src);
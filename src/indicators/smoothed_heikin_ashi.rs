@@ -0,0 +1,256 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+	Action, Candle, Error, Method, MovingAverageConstructor, PeriodType, ValueType, OHLCV,
+};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::{sign, MA};
+use crate::methods::HeikinAshi;
+
+/// Smoothed Heikin Ashi
+///
+/// Smooths every price of the real candle with the first moving average, converts smoothed candles into
+/// [Heikin Ashi](crate::methods::HeikinAshi) candles and then smooths every price of Heikin Ashi candles with the second moving average.
+///
+/// ## Links
+///
+/// * <https://en.wikipedia.org/wiki/Candlestick_chart#Heikin-Ashi_candlesticks>
+///
+/// # 4 values
+///
+/// * Smoothed Heikin Ashi `open` value
+/// * Smoothed Heikin Ashi `high` value
+/// * Smoothed Heikin Ashi `low` value
+/// * Smoothed Heikin Ashi `close` value
+///
+/// Range of all the values is the same as the range of the prices.
+///
+/// `high` is always >= max(`open`, `close`) and `low` is always <= min(`open`, `close`).
+///
+/// # 1 signal
+///
+/// * Bar color
+///
+/// When smoothed Heikin Ashi `close` is above its `open` (bullish bar), returns full buy signal.
+/// When smoothed Heikin Ashi `close` is below its `open` (bearish bar), returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SmoothedHeikinAshi<M: MovingAverageConstructor = MA> {
+	/// Moving average for smoothing the real candles. Default is [`EMA(10)`](crate::methods::EMA).
+	///
+	/// Period range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub ma1: M,
+
+	/// Moving average for smoothing Heikin Ashi candles. Default is [`EMA(10)`](crate::methods::EMA).
+	///
+	/// Period range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub ma2: M,
+}
+
+impl<M: MovingAverageConstructor> IndicatorConfig for SmoothedHeikinAshi<M> {
+	type Instance = SmoothedHeikinAshiInstance<M>;
+
+	const NAME: &'static str = "SmoothedHeikinAshi";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let smoothed = smoother(&cfg.ma1, candle)?;
+		let heikin_ashi = HeikinAshi::new((), candle)?;
+		let first = HeikinAshi::new((), candle)?.next(candle);
+
+		Ok(Self::Instance {
+			ma1: smoothed,
+			heikin_ashi,
+			ma2: smoother(&cfg.ma2, &first)?,
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.ma1.ma_period() > 0
+			&& self.ma1.ma_period() < PeriodType::MAX
+			&& self.ma2.ma_period() > 0
+			&& self.ma2.ma_period() < PeriodType::MAX
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"ma1" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.ma1 = value,
+			},
+			"ma2" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.ma2 = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(4, 1)
+	}
+}
+
+impl Default for SmoothedHeikinAshi {
+	fn default() -> Self {
+		Self {
+			ma1: MA::EMA(10),
+			ma2: MA::EMA(10),
+		}
+	}
+}
+
+/// Creates moving averages for smoothing every price of the candle
+fn smoother<M, T>(ma: &M, candle: &T) -> Result<[M::Instance; 4], Error>
+where
+	M: MovingAverageConstructor,
+	T: OHLCV + ?Sized,
+{
+	Ok([
+		ma.init(candle.open())?,
+		ma.init(candle.high())?,
+		ma.init(candle.low())?,
+		ma.init(candle.close())?,
+	])
+}
+
+/// Smooths every price of the candle with its own moving average
+fn smooth<I, T>(mas: &mut [I; 4], candle: &T) -> Candle
+where
+	I: Method<Input = ValueType, Output = ValueType>,
+	T: OHLCV + ?Sized,
+{
+	let open = mas[0].next(&candle.open());
+	let close = mas[3].next(&candle.close());
+
+	Candle {
+		open,
+		high: mas[1].next(&candle.high()).max(open).max(close),
+		low: mas[2].next(&candle.low()).min(open).min(close),
+		close,
+		volume: candle.volume(),
+	}
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "M: Serialize, M::Instance: Serialize",
+		deserialize = "M: Deserialize<'de>, M::Instance: Deserialize<'de>"
+	))
+)]
+pub struct SmoothedHeikinAshiInstance<M: MovingAverageConstructor = MA> {
+	cfg: SmoothedHeikinAshi<M>,
+
+	ma1: [M::Instance; 4],
+	heikin_ashi: HeikinAshi,
+	ma2: [M::Instance; 4],
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for SmoothedHeikinAshiInstance<M> {
+	type Config = SmoothedHeikinAshi<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["open", "high", "low", "close"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["color"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let smoothed = smooth(&mut self.ma1, candle);
+		let heikin_ashi = self.heikin_ashi.next(&smoothed);
+		let result = smooth(&mut self.ma2, &heikin_ashi);
+
+		let color = Action::from(sign(result.close - result.open));
+
+		IndicatorResult::new(
+			&[result.open, result.high, result.low, result.close],
+			&[color],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SmoothedHeikinAshi;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, Method, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles, MA};
+	use crate::methods::HeikinAshi;
+
+	#[test]
+	fn test_smoothed_heikin_ashi_const() {
+		let candle = Candle {
+			open: 10.0,
+			high: 12.0,
+			low: 9.0,
+			close: 11.0,
+			volume: 1.0,
+		};
+
+		let mut instance = SmoothedHeikinAshi::default().init(&candle).unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq_float(candle.ohlc4(), result.value(0));
+			assert_eq_float(candle.ohlc4(), result.value(3));
+			assert!(result.value(1) >= result.value(0).max(result.value(3)));
+			assert!(result.value(2) <= result.value(0).min(result.value(3)));
+		}
+	}
+
+	#[test]
+	fn test_smoothed_heikin_ashi_smoother() {
+		let candles: Vec<Candle> = RandomCandles::new().take(500).collect();
+
+		let mut heikin_ashi = HeikinAshi::new((), &candles[0]).unwrap();
+		let mut smoothed = SmoothedHeikinAshi {
+			ma1: MA::EMA(5),
+			ma2: MA::EMA(5),
+		}
+		.init(&candles[0])
+		.unwrap();
+
+		let count_flips = |colors: Vec<bool>| colors.windows(2).filter(|w| w[0] != w[1]).count();
+
+		let raw_colors = candles
+			.iter()
+			.map(|c| {
+				let ha = heikin_ashi.next(c);
+				ha.close() > ha.open()
+			})
+			.collect();
+		let smoothed_colors = candles
+			.iter()
+			.map(|c| smoothed.next(c).signal(0).is_buy())
+			.collect();
+
+		let raw_flips = count_flips(raw_colors);
+		let smoothed_flips = count_flips(smoothed_colors);
+
+		assert!(
+			smoothed_flips < raw_flips,
+			"{smoothed_flips} vs {raw_flips}"
+		);
+	}
+}
@@ -175,6 +175,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for StochasticOscillatorInst
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["main", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["main_bounds_cross", "signal_line_bounds_cross", "signal_line_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let (close, high, low) = (candle.close(), candle.high(), candle.low());
 
@@ -0,0 +1,314 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
+use crate::helpers::MA;
+use crate::methods::{Cross, CrossAbove, CrossUnder, Highest, Lowest};
+
+use super::{RelativeStrengthIndex, RelativeStrengthIndexInstance};
+
+/// Stochastic Relative Strength Index
+///
+/// Applies [Stochastic Oscillator](crate::indicators::StochasticOscillator) formula to the
+/// [Relative Strength Index](crate::indicators::RelativeStrengthIndex) values instead of the price.
+///
+/// ## Links
+///
+/// * <https://www.investopedia.com/terms/s/stochrsi.asp>
+///
+/// # 2 values
+///
+/// * `%K` value
+///
+/// Range in \[`0.0`; `1.0`\] (instead of classic \[`0`; `100`\]).
+///
+/// When RSI is flat over the whole `stoch_period` window, raw stochastic value is `0.5`.
+///
+/// * `%D` value
+///
+/// Range in \[`0.0`; `1.0`\] (instead of classic \[`0`; `100`\]).
+///
+/// # 2 signals
+///
+/// * Signal #1
+///
+/// When `%K` value crosses lower bound upwards, returns full buy signal.
+/// When `%K` value crosses upper bound downwards, returns full sell signal.
+/// Otherwise returns no signal.
+///
+/// * Signal #2
+///
+/// When `%K` value crosses `%D` value upwards, returns full buy signal.
+/// When `%K` value crosses `%D` value downwards, returns full sell signal.
+/// Otherwise returns no signal.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StochasticRelativeStrengthIndex<M: MovingAverageConstructor = MA> {
+	/// RSI moving average type. Default is [`EMA(14)`](crate::methods::EMA).
+	///
+	/// Period range in \[`3`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub rsi: M,
+
+	/// Period for searching highest and lowest RSI values. Default is `14`.
+	///
+	/// Range in \[`2`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub stoch_period: PeriodType,
+
+	/// Moving average for smoothing raw stochastic value into `%K`. Default is [`SMA(3)`](crate::methods::SMA).
+	///
+	/// Period range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub k_smooth: M,
+
+	/// Moving average for smoothing `%K` into `%D`. Default is [`SMA(3)`](crate::methods::SMA).
+	///
+	/// Period range in \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub d_smooth: M,
+
+	/// Zone size for overbought and oversold signals. Default is `0.2` (same as `80`/`20` on the classic scale).
+	///
+	/// Range in \[`0.0`; `0.5`\].
+	pub zone: ValueType,
+
+	/// Source type. Default is [`Close`](crate::core::Source::Close)
+	pub source: Source,
+}
+
+impl_candle_source!(StochasticRelativeStrengthIndex<M>);
+
+impl<M: MovingAverageConstructor> IndicatorConfig for StochasticRelativeStrengthIndex<M> {
+	type Instance = StochasticRelativeStrengthIndexInstance<M>;
+
+	const NAME: &'static str = "StochasticRelativeStrengthIndex";
+
+	fn init<T: OHLCV>(self, candle: &T) -> Result<Self::Instance, Error> {
+		if !self.validate() {
+			return Err(Error::WrongConfig);
+		}
+
+		let cfg = self;
+		let rsi = RelativeStrengthIndex {
+			ma: cfg.rsi.clone(),
+			zone: 0.3,
+			source: cfg.source,
+			source_smoothing: 0,
+		};
+
+		Ok(Self::Instance {
+			rsi: rsi.init(candle)?,
+			highest: Highest::new(cfg.stoch_period, &0.5)?,
+			lowest: Lowest::new(cfg.stoch_period, &0.5)?,
+			k: cfg.k_smooth.init(0.5)?,
+			d: cfg.d_smooth.init(0.5)?,
+			upper_zone: 1.0 - cfg.zone,
+			cross_above: CrossAbove::default(),
+			cross_under: CrossUnder::default(),
+			cross: Cross::default(),
+			cfg,
+		})
+	}
+
+	fn validate(&self) -> bool {
+		self.rsi.ma_period() > 2
+			&& self.rsi.ma_period() < PeriodType::MAX
+			&& self.stoch_period > 1
+			&& self.stoch_period < PeriodType::MAX
+			&& self.k_smooth.ma_period() > 0
+			&& self.k_smooth.ma_period() < PeriodType::MAX
+			&& self.d_smooth.ma_period() > 0
+			&& self.d_smooth.ma_period() < PeriodType::MAX
+			&& (0.0..=0.5).contains(&self.zone)
+	}
+
+	fn set(&mut self, name: &str, value: String) -> Result<(), Error> {
+		match name {
+			"rsi" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.rsi = value,
+			},
+			"stoch_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.stoch_period = value,
+			},
+			"k_smooth" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.k_smooth = value,
+			},
+			"d_smooth" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.d_smooth = value,
+			},
+			"zone" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.zone = value,
+			},
+			"source" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value)),
+				Ok(value) => self.source = value,
+			},
+
+			_ => {
+				return Err(Error::ParameterParse(name.to_string(), value));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn size(&self) -> (u8, u8) {
+		(2, 2)
+	}
+}
+
+impl Default for StochasticRelativeStrengthIndex {
+	fn default() -> Self {
+		Self {
+			rsi: MA::EMA(14),
+			stoch_period: 14,
+			k_smooth: MA::SMA(3),
+			d_smooth: MA::SMA(3),
+			zone: 0.2,
+			source: Source::Close,
+		}
+	}
+}
+
+/// Just an alias for `StochasticRelativeStrengthIndex`
+pub type StochRSI<M = MA> = StochasticRelativeStrengthIndex<M>;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StochasticRelativeStrengthIndexInstance<M: MovingAverageConstructor = MA> {
+	cfg: StochasticRelativeStrengthIndex<M>,
+
+	rsi: RelativeStrengthIndexInstance<M>,
+	highest: Highest,
+	lowest: Lowest,
+	k: M::Instance,
+	d: M::Instance,
+	upper_zone: ValueType,
+	cross_above: CrossAbove,
+	cross_under: CrossUnder,
+	cross: Cross,
+}
+
+impl<M: MovingAverageConstructor> IndicatorInstance for StochasticRelativeStrengthIndexInstance<M> {
+	type Config = StochasticRelativeStrengthIndex<M>;
+
+	fn config(&self) -> &Self::Config {
+		&self.cfg
+	}
+
+	fn value_names(&self) -> &'static [&'static str] {
+		&["k", "d"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["k_bounds_cross", "kd_cross"]
+	}
+
+	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
+		let rsi = self.rsi.next(candle).value(0);
+
+		let highest = self.highest.next(&rsi);
+		let lowest = self.lowest.next(&rsi);
+
+		// we need to check division by zero, so we can really just check if `highest` is equal to `lowest` without using any kind of round error checks
+		#[allow(clippy::float_cmp)]
+		let stoch = if highest == lowest {
+			0.5
+		} else {
+			(rsi - lowest) / (highest - lowest)
+		};
+
+		let k = self.k.next(&stoch);
+		let d = self.d.next(&k);
+
+		let s1 = self.cross_above.next(&(k, self.cfg.zone))
+			- self.cross_under.next(&(k, self.upper_zone));
+		let s2 = self.cross.next(&(k, d));
+
+		IndicatorResult::new(&[k, d], &[s1, s2])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::StochasticRelativeStrengthIndex;
+	use crate::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::indicators::RelativeStrengthIndex;
+
+	#[test]
+	fn test_stoch_rsi_range() {
+		let candles: Vec<_> = RandomCandles::new().take(1000).collect();
+		let mut instance = StochasticRelativeStrengthIndex::default()
+			.init(&candles[0])
+			.unwrap();
+
+		for candle in &candles {
+			let result = instance.next(candle);
+
+			assert!((0.0..=1.0).contains(&result.value(0)), "{result:?}");
+			assert!((0.0..=1.0).contains(&result.value(1)), "{result:?}");
+		}
+	}
+
+	#[test]
+	fn test_stoch_rsi_flat() {
+		let candle = Candle {
+			open: 5.0,
+			high: 6.0,
+			low: 4.0,
+			close: 5.0,
+			volume: 1.0,
+		};
+		let mut instance = StochasticRelativeStrengthIndex::default()
+			.init(&candle)
+			.unwrap();
+
+		for _ in 0..100 {
+			let result = instance.next(&candle);
+
+			assert_eq_float(0.5, result.value(0));
+			assert_eq_float(0.5, result.value(1));
+		}
+	}
+
+	#[test]
+	fn test_stoch_rsi_reacts_faster() {
+		// noisy uptrend followed by noisy downtrend
+		let candles: Vec<Candle> = (0..200)
+			.map(|i| {
+				let trend = if i < 100 { i } else { 200 - i } as ValueType;
+				let noise = if i % 2 == 0 { 0.5 } else { -0.5 };
+				let close = trend + noise + 100.0;
+
+				Candle {
+					open: close,
+					high: close,
+					low: close,
+					close,
+					volume: 1.0,
+				}
+			})
+			.collect();
+
+		let mut stoch_rsi = StochasticRelativeStrengthIndex::default()
+			.init(&candles[0])
+			.unwrap();
+		let mut rsi = RelativeStrengthIndex::default().init(&candles[0]).unwrap();
+
+		let bars_to_turn = |values: Vec<ValueType>| values[100..].iter().position(|&x| x < 0.5);
+
+		let (stoch_rsi_values, rsi_values): (Vec<_>, Vec<_>) = candles
+			.iter()
+			.map(|c| (stoch_rsi.next(c).value(0), rsi.next(c).value(0)))
+			.unzip();
+
+		let stoch_rsi_turn = bars_to_turn(stoch_rsi_values).unwrap();
+		let rsi_turn = bars_to_turn(rsi_values).unwrap();
+
+		assert!(stoch_rsi_turn < rsi_turn, "{stoch_rsi_turn} vs {rsi_turn}");
+	}
+}
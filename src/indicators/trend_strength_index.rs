@@ -47,6 +47,8 @@ pub struct TrendStrengthIndex {
 	pub source: Source,
 }
 
+impl_candle_source!(TrendStrengthIndex);
+
 impl IndicatorConfig for TrendStrengthIndex {
 	type Instance = TrendStrengthIndexInstance;
 
@@ -162,6 +164,14 @@ impl IndicatorInstance for TrendStrengthIndexInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["main"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zone_cross", "reversal"]
+	}
+
 	#[inline]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
@@ -56,6 +56,8 @@ pub struct Trix<M: MovingAverageConstructor = MA> {
 	pub source: Source,
 }
 
+impl_candle_source!(Trix<M>);
+
 impl<M: MovingAverageConstructor> IndicatorConfig for Trix<M> {
 	type Instance = TRIXInstance<M>;
 
@@ -141,6 +143,14 @@ impl<M: MovingAverageConstructor> IndicatorInstance for TRIXInstance<M> {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["main", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["reversal", "signal_line_cross", "zero_cross"]
+	}
+
 	#[inline]
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
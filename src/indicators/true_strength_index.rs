@@ -65,6 +65,8 @@ pub struct TrueStrengthIndex {
 	pub source: Source,
 }
 
+impl_candle_source!(TrueStrengthIndex);
+
 impl IndicatorConfig for TrueStrengthIndex {
 	type Instance = TrueStrengthIndexInstance;
 
@@ -167,6 +169,14 @@ impl IndicatorInstance for TrueStrengthIndexInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["main", "signal_line"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["zone_cross", "zero_cross", "signal_line_cross"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = candle.source(self.cfg.source);
 
@@ -45,6 +45,8 @@ pub struct WoodiesCCI {
 	pub source: Source,
 }
 
+impl_candle_source!(WoodiesCCI);
+
 impl IndicatorConfig for WoodiesCCI {
 	type Instance = WoodiesCCIInstance;
 
@@ -135,6 +137,14 @@ impl IndicatorInstance for WoodiesCCIInstance {
 		&self.cfg
 	}
 
+	fn value_names(&self) -> &'static [&'static str] {
+		&["turbo_cci", "trend_cci"]
+	}
+
+	fn signal_names(&self) -> &'static [&'static str] {
+		&["trend"]
+	}
+
 	fn next<T: OHLCV>(&mut self, candle: &T) -> IndicatorResult {
 		let src = &candle.source(self.cfg.source);
 
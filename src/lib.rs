@@ -58,11 +58,13 @@ limitations under the License.
 //! - [Symmetrically weighted moving average (SWMA)](crate::methods::SWMA);
 //! - [Hull moving average (HMA)](crate::methods::HMA);
 //! - [Running Moving Average (RMA)](crate::methods::RMA);
-//! - [Triangular Moving Average (TRIMA)](crate::methods::TRIMA);
+//! - Triangular Moving Average: [TRIMA](crate::methods::TRIMA), [`TriangularMA`](crate::methods::TriangularMA);
 //! - [Wilder’s Smoothing Average (WSMA)](crate::methods::WSMA);
 //! - [Kaufman Adaptive Moving Average (KAMA)](crate::indicators::Kaufman);
 //! - [Convolution Moving Average](crate::methods::Conv);
 //! - [Variable Index Dynamic Average (Vidya)](crate::methods::Vidya);
+//! - [Zero Lag Exponential Moving Average (ZLEMA)](crate::methods::ZLEMA);
+//! - [Tillson T3 moving average (T3)](crate::methods::T3);
 //!
 //! [See all](crate::methods#structs)
 //!
@@ -162,6 +164,18 @@ limitations under the License.
 //!
 //! `usafe_performance` enables some unsafe code blocks, most of them are unsafe access to a vector's elements. For some methods it may increase performance by ~5-10%.
 //!
+//! ## Strict mode
+//!
+//! Any `NaN` or infinite input value silently poisons the whole internal state of methods and indicators.
+//! You can optionally enable `strict` feature to get `Method::next_checked` and
+//! `IndicatorInstance::next_checked` entry points, which check input values before processing them.
+//!
+//! ## JSON schema
+//!
+//! You can optionally enable `json_schema` feature to get `IndicatorConfig::json_schema`,
+//! which describes indicator's parameters (see [`IndicatorConfig::parameters`](crate::core::IndicatorConfig::parameters)) for building UIs.
+//! It adds `serde_json` dependency.
+//!
 //! ## Suggestions
 //!
 //! You are welcome to give any suggestions about new indicators and methods
@@ -174,6 +188,7 @@ pub mod core;
 pub mod helpers;
 pub mod indicators;
 pub mod methods;
+pub mod risk;
 
 #[cfg(all(feature = "period_type_u64", target_pointer_width = "32"))]
 compile_error!("Feature `period_type_u64` can't be used on 32-bit machines");
@@ -187,13 +202,14 @@ compile_error!("Features `period_type_u64` and `period_type_u32` can't be used o
 /// Contains main traits you need to start using this library
 pub mod prelude {
 	pub use super::core::{
-		Candle, Error, IndicatorConfig, IndicatorInstance, Method, Sequence, OHLCV,
+		BufferedMethod, Candle, CandleSource, Error, IndicatorConfig, IndicatorInstance, Method,
+		Sequence, OHLCV,
 	};
 
 	pub use super::helpers::{Buffered, Peekable};
 
 	/// Dynamically dispatchable traits for indicators creation
 	pub mod dd {
-		pub use crate::core::{IndicatorConfigDyn, IndicatorInstanceDyn};
+		pub use crate::core::{IndicatorConfigDyn, IndicatorConfigDynClone, IndicatorInstanceDyn};
 	}
 }
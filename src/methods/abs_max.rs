@@ -0,0 +1,223 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use crate::methods::Highest;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns highest absolute value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always >= `0.0`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::AbsMax;
+///
+/// let values = [1.0, -2.0, 0.5, 1.5, -0.5, 0.0];
+/// let r      = [1.0,  2.0, 2.0, 2.0,  1.5, 1.5];
+///
+/// let mut abs_max = AbsMax::new(3, &values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = abs_max.next(&values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`Highest`], [`NormalizeByAbsMax`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Highest`]: crate::methods::Highest
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AbsMax(Highest);
+
+impl Method for AbsMax {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Highest::new(length, &value.abs()).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.0.next(&value.abs())
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for AbsMax {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+/// Divides value by the highest absolute value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// It is useful for auto-scaling any unbounded oscillator into range \[`-1.0`; `1.0`\].
+///
+/// When the highest absolute value is `0.0` (all the values inside the window are zeros), returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is in range \[`-1.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::NormalizeByAbsMax;
+///
+/// let values = [0.0, 2.0, -4.0, 1.0, 0.5, 0.5];
+/// let r      = [0.0, 1.0, -1.0, 0.25, 0.125, 0.5];
+///
+/// let mut normalize = NormalizeByAbsMax::new(3, &values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = normalize.next(&values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// # See also
+///
+/// [`AbsMax`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NormalizeByAbsMax(AbsMax);
+
+impl Method for NormalizeByAbsMax {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		AbsMax::new(length, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let abs_max = self.0.next(value);
+
+		if abs_max > 0.0 {
+			value / abs_max
+		} else {
+			0.0
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{AbsMax as TestingMethod, Method, NormalizeByAbsMax};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{test_const, test_method_against_naive};
+
+	const SIGMA: ValueType = 1e-8;
+
+	#[test]
+	fn test_abs_max_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / -16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(input.abs(), output);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_abs_max() {
+		test_method_against_naive(
+			1..255,
+			TestingMethod::new,
+			|src, i, length| {
+				(0..length)
+					.map(|j| src[i.saturating_sub(j)].abs())
+					.fold(0.0, ValueType::max)
+			},
+			SIGMA,
+		);
+	}
+
+	#[test]
+	fn test_normalize_by_abs_max_zero() {
+		let mut method = NormalizeByAbsMax::new(10, &0.0).unwrap();
+
+		for _ in 0..100 {
+			assert_eq_float(0.0, method.next(&0.0));
+		}
+	}
+
+	#[test]
+	fn test_normalize_by_abs_max() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close - 1.0).collect();
+
+		for length in 1..100 {
+			let mut method = NormalizeByAbsMax::new(length, &src[0]).unwrap();
+
+			for (i, x) in src.iter().enumerate() {
+				let value = method.next(x);
+
+				assert!((-1.0..=1.0).contains(&value), "value={value}");
+
+				if i + 1 >= length as usize {
+					let window_start = i + 1 - length as usize;
+					let abs_max = src[window_start..=i]
+						.iter()
+						.map(|v| v.abs())
+						.fold(0.0, ValueType::max);
+					let expected = if abs_max > 0.0 { x / abs_max } else { 0.0 };
+					assert_eq_float(expected, value);
+				}
+			}
+		}
+	}
+}
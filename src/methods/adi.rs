@@ -172,7 +172,7 @@ mod tests {
 
 	#[test]
 	fn test_adi() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 		let first_candle = candles.first();
 		let mut adi = ADI::new(0, &first_candle).unwrap();
 
@@ -184,7 +184,7 @@ mod tests {
 
 	#[test]
 	fn test_adi_windowed() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 		let first = candles.first();
 		let mut adi = ADI::new(0, &first).unwrap();
 		let mut adiw = [
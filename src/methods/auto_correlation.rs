@@ -0,0 +1,291 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling [autocorrelation](https://en.wikipedia.org/wiki/Autocorrelation) of timeseries of type [`ValueType`] at lag `lag` over the window of size `length`
+///
+/// It is the [Pearson correlation coefficient](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient) between the last `length` values `x`
+/// and the same values shifted by `lag` bars back `y`:
+///
+/// `output` = Σ\(\(`x` - mean\(`x`\)\) \* \(`y` - mean\(`y`\)\)\) / √\(Σ\(`x` - mean\(`x`\)\)² \* Σ\(`y` - mean\(`y`\)\)²\)
+///
+/// Output is in range \[`-1.0`; `1.0`\]. Positive values mean that the series tends to continue its moves (momentum regime),
+/// negative values mean that the series tends to revert its moves (mean-reversion regime).
+///
+/// When any of `x` or `y` has zero variance over the window (e.g. on constant input, up to float rounding errors), returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `lag`: [`PeriodType`]\)
+///
+/// `length` should be > `1`
+///
+/// `lag` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::ValueType;
+/// use yata::methods::AutoCorrelation;
+///
+/// let mut acf = AutoCorrelation::new((5, 1), &0.0).unwrap();
+///
+/// let mut value = 0.0;
+/// for i in 1..=10 {
+///     value = acf.next(&(i as ValueType));
+/// }
+///
+/// // linear trend is perfectly correlated with itself shifted by one bar
+/// assert!((value - 1.0).abs() < 1e-5);
+/// ```
+///
+/// # Performance
+///
+/// O(1) amortized, O(`length`) when variance drops sharply
+///
+/// # See also
+///
+/// [`CovarianceMatrix`](crate::methods::CovarianceMatrix)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "ACF")]
+pub struct AutoCorrelation {
+	divider: ValueType,
+	mean_x: ValueType,
+	mean_y: ValueType,
+	/// sums of squared deviations and of products of deviations from the means
+	var_x: ValueType,
+	var_y: ValueType,
+	cov: ValueType,
+	/// count of inputs since the last recalculation of the sums
+	steps: PeriodType,
+	lagged: Window<ValueType>,
+	window: Window<(ValueType, ValueType)>,
+}
+
+impl AutoCorrelation {
+	fn recalculate(&mut self) {
+		let (sum_x, sum_y) = self
+			.window
+			.iter()
+			.fold((0.0, 0.0), |(sum_x, sum_y), &(x, y)| (sum_x + x, sum_y + y));
+		self.mean_x = sum_x * self.divider;
+		self.mean_y = sum_y * self.divider;
+
+		let (mut var_x, mut var_y, mut cov) = (0.0, 0.0, 0.0);
+		for &(x, y) in &self.window {
+			let (dx, dy) = (x - self.mean_x, y - self.mean_y);
+
+			var_x = dx.mul_add(dx, var_x);
+			var_y = dy.mul_add(dy, var_y);
+			cov = dx.mul_add(dy, cov);
+		}
+
+		self.var_x = var_x;
+		self.var_y = var_y;
+		self.cov = cov;
+	}
+}
+
+impl Method for AutoCorrelation {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, lag): Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		if length < 2 || lag == 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			divider: (length as ValueType).recip(),
+			mean_x: value,
+			mean_y: value,
+			var_x: 0.0,
+			var_y: 0.0,
+			cov: 0.0,
+			steps: 0,
+			lagged: Window::new(lag, value),
+			window: Window::new(length, (value, value)),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &x: &Self::Input) -> Self::Output {
+		let y = self.lagged.push(x);
+		let (prev_x, prev_y) = self.window.push((x, y));
+
+		// sliding Welford update by the deviations from the old and the new means
+		let mean_x = (x - prev_x).mul_add(self.divider, self.mean_x);
+		let mean_y = (y - prev_y).mul_add(self.divider, self.mean_y);
+
+		let removed_x = (prev_x - mean_x) * (prev_x - self.mean_x);
+		let removed_y = (prev_y - mean_y) * (prev_y - self.mean_y);
+
+		self.var_x += (x - mean_x).mul_add(x - self.mean_x, -removed_x);
+		self.var_y += (y - mean_y).mul_add(y - self.mean_y, -removed_y);
+		self.cov +=
+			(x - mean_x).mul_add(y - self.mean_y, -(prev_x - mean_x) * (prev_y - self.mean_y));
+
+		self.mean_x = mean_x;
+		self.mean_y = mean_y;
+
+		// rounding errors accumulated by the updates are dropped once per `length` inputs
+		// and whenever a sharp drop of variance leaves too few significant digits in the sums
+		let cancellation = ValueType::EPSILON.sqrt();
+		self.steps += 1;
+		if self.steps == self.window.len()
+			|| self.var_x < removed_x.abs() * cancellation
+			|| self.var_y < removed_y.abs() * cancellation
+		{
+			self.steps = 0;
+			self.recalculate();
+		}
+
+		self.peek()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for AutoCorrelation {
+	fn peek(&self) -> <Self as Method>::Output {
+		// float rounding errors may produce tiny non-zero variance on constant input
+		let tolerance = ValueType::EPSILON * self.divider.recip();
+		if self.var_x <= tolerance * self.mean_x * self.mean_x
+			|| self.var_y <= tolerance * self.mean_y * self.mean_y
+		{
+			return 0.0;
+		}
+
+		(self.cov / (self.var_x * self.var_y).sqrt()).clamp(-1.0, 1.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{AutoCorrelation as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_auto_correlation_const() {
+		for length in 2..255 {
+			for lag in [1, 2, 5, 10] {
+				let input = (length as ValueType + 56.0) / 16.3251;
+				let mut method = TestingMethod::new((length, lag), &input).unwrap();
+
+				test_const_float(&mut method, &input, 0.0);
+			}
+		}
+	}
+
+	#[test]
+	fn test_auto_correlation_trend() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.enumerate()
+			.map(|(i, x)| x.close.mul_add(0.1, i as ValueType))
+			.collect();
+
+		let mut method = TestingMethod::new((20, 1), &src[0]).unwrap();
+
+		for (i, x) in src.iter().enumerate() {
+			let value = method.next(x);
+
+			if i > 20 {
+				assert!(value > 0.9, "{value} at {i}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_auto_correlation_alternating() {
+		let mut method = TestingMethod::new((10, 1), &0.0).unwrap();
+
+		let mut value = 0.0;
+		for i in 0..50 {
+			value = method.next(&if i % 2 == 0 { 1.0 } else { -1.0 });
+		}
+
+		assert_eq_float(-1.0, value);
+	}
+
+	#[test]
+	#[cfg_attr(not(feature = "value_type_f32"), allow(clippy::useless_conversion))]
+	fn test_auto_correlation() {
+		const SIGMA: f64 = if cfg!(feature = "value_type_f32") {
+			1e-3
+		} else {
+			1e-5
+		};
+
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 2..30 {
+			for lag in 1..5 {
+				let mut method = TestingMethod::new((length, lag), &src[0]).unwrap();
+				let length = length as usize;
+				let lag = lag as usize;
+
+				for i in 0..src.len() {
+					let value = method.next(&src[i]);
+
+					// reference is always evaluated in `f64`
+					let x: Vec<f64> = (0..length)
+						.map(|j| f64::from(src[i.saturating_sub(j)]))
+						.collect();
+					let y: Vec<f64> = (0..length)
+						.map(|j| f64::from(src[i.saturating_sub(j + lag)]))
+						.collect();
+
+					let n = length as f64;
+					let mean_x = x.iter().sum::<f64>() / n;
+					let mean_y = y.iter().sum::<f64>() / n;
+
+					let cov: f64 = x
+						.iter()
+						.zip(&y)
+						.map(|(a, b)| (a - mean_x) * (b - mean_y))
+						.sum();
+					let var_x: f64 = x.iter().map(|a| (a - mean_x).powi(2)).sum();
+					let var_y: f64 = y.iter().map(|b| (b - mean_y).powi(2)).sum();
+
+					if var_x < 1e-8 || var_y < 1e-8 {
+						continue;
+					}
+
+					let expected = cov / (var_x * var_y).sqrt();
+					let value = f64::from(value);
+					assert!(
+						(expected - value).abs() < SIGMA,
+						"length={length}, lag={lag}, i={i}: {expected} != {value}"
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_auto_correlation_wrong_params() {
+		assert!(TestingMethod::new((1, 1), &1.0).is_err());
+		assert!(TestingMethod::new((10, 0), &1.0).is_err());
+	}
+}
@@ -0,0 +1,167 @@
+use crate::core::{Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::SMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling averages of candles' body, upper shadow (wick) and lower shadow sizes over the last `length` candles
+///
+/// * `body` = |`close` - `open`|;
+/// * `upper shadow` = `high` - max(`open`, `close`);
+/// * `lower shadow` = min(`open`, `close`) - `low`.
+///
+/// Every average is a simple moving average ([`SMA`](crate::methods::SMA)).
+///
+/// Small average body relative to the average shadows may be used for detecting indecision regimes.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is a tuple of \(`average body`, `average upper shadow`, `average lower shadow`\) of type [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::CandleStats;
+///
+/// let candle = Candle { open: 10.0, high: 13.0, low: 9.0, close: 12.0, volume: 1.0 };
+/// let mut stats = CandleStats::new(3, &candle).unwrap();
+///
+/// let (body, upper, lower) = stats.next(&candle);
+/// assert_eq!(body, 2.0);
+/// assert_eq!(upper, 1.0);
+/// assert_eq!(lower, 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CandleStats {
+	body: SMA,
+	upper: SMA,
+	lower: SMA,
+}
+
+/// Returns `body`, `upper shadow` and `lower shadow` sizes of the candle
+fn parts(candle: &dyn OHLCV) -> (ValueType, ValueType, ValueType) {
+	let (open, close) = (candle.open(), candle.close());
+	let (bottom, top) = if open < close {
+		(open, close)
+	} else {
+		(close, open)
+	};
+
+	(top - bottom, candle.high() - top, bottom - candle.low())
+}
+
+impl Method for CandleStats {
+	type Params = PeriodType;
+	type Input = dyn OHLCV;
+	type Output = (ValueType, ValueType, ValueType);
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		let (body, upper, lower) = parts(value);
+
+		Ok(Self {
+			body: SMA::new(length, &body)?,
+			upper: SMA::new(length, &upper)?,
+			lower: SMA::new(length, &lower)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let (body, upper, lower) = parts(value);
+
+		(
+			self.body.next(&body),
+			self.upper.next(&upper),
+			self.lower.next(&lower),
+		)
+	}
+
+	fn output_name(&self, index: usize) -> Option<&'static str> {
+		["body", "upper_shadow", "lower_shadow"].get(index).copied()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CandleStats as TestingMethod, Method};
+	use crate::core::{Candle, ValueType, OHLCV};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_candle_stats_doji() {
+		let candles: Vec<Candle> = (0..100)
+			.map(|i| {
+				let price = (i as ValueType * 0.3).sin().mul_add(5.0, 100.0);
+
+				Candle {
+					open: price,
+					high: price + 2.0,
+					low: price - 1.5,
+					close: price + if i % 2 == 0 { 0.05 } else { -0.05 },
+					volume: 1.0,
+				}
+			})
+			.collect();
+
+		let mut method = TestingMethod::new(10, &candles[0]).unwrap();
+
+		for candle in &candles {
+			let (body, upper, lower) = method.next(candle);
+
+			assert!(body * 10.0 < upper);
+			assert!(body * 10.0 < lower);
+		}
+	}
+
+	#[test]
+	fn test_candle_stats() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let parts: Vec<_> = candles
+			.iter()
+			.map(|c| {
+				let top = c.open().max(c.close());
+				let bottom = c.open().min(c.close());
+
+				(top - bottom, c.high() - top, bottom - c.low())
+			})
+			.collect();
+
+		for length in 1..30 {
+			let mut method = TestingMethod::new(length, &candles[0]).unwrap();
+
+			for (i, candle) in candles.iter().enumerate() {
+				let (body, upper, lower) = method.next(candle);
+
+				let window = (0..length as usize).map(|j| parts[i.saturating_sub(j)]);
+				let count = length as ValueType;
+
+				assert_eq_float(window.clone().map(|x| x.0).sum::<ValueType>() / count, body);
+				assert_eq_float(
+					window.clone().map(|x| x.1).sum::<ValueType>() / count,
+					upper,
+				);
+				assert_eq_float(window.map(|x| x.2).sum::<ValueType>() / count, lower);
+			}
+		}
+	}
+}
@@ -0,0 +1,168 @@
+use crate::core::{Error, Method, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers' [Center of Gravity](https://www.mesasoftware.com/papers/TheCGOscillator.pdf) oscillator of specified `length` for timeseries of type [`ValueType`]
+///
+/// `CG` = -sum((`i` + 1) * `value`\[`i`\]) / sum(`value`\[`i`\]), where `value`\[`0`\] is the most recent value
+/// and `i` goes over the last `length` values.
+///
+/// When sum of the values is `0.0` (up to float rounding errors), returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// For positive values range of output values is \[-`length`; `-1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::CenterOfGravity;
+///
+/// let mut cg = CenterOfGravity::new(3, &1.0).unwrap();
+///
+/// assert_eq!(cg.next(&1.0), -2.0);
+/// // -(1 * 4 + 2 * 1 + 3 * 1) / (4 + 1 + 1)
+/// assert_eq!(cg.next(&4.0), -1.5);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CenterOfGravity {
+	float_length: ValueType,
+	sum: ValueType,
+	abs_sum: ValueType,
+	weighted_sum: ValueType,
+	window: Window<ValueType>,
+}
+
+impl Method for CenterOfGravity {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => {
+				let float_length = length as ValueType;
+
+				Ok(Self {
+					float_length,
+					sum: value * float_length,
+					abs_sum: value.abs() * float_length,
+					weighted_sum: value * float_length * (float_length + 1.0) * 0.5,
+					window: Window::new(length, value),
+				})
+			}
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let past_value = self.window.push(value);
+
+		// every value in the window gets weight greater by one, the oldest value leaves the window and the new value gets weight `1`
+		self.weighted_sum += past_value.mul_add(-(self.float_length + 1.0), self.sum) + value;
+		self.sum += value - past_value;
+		self.abs_sum += value.abs() - past_value.abs();
+
+		self.peek()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for CenterOfGravity {
+	fn peek(&self) -> <Self as Method>::Output {
+		// positive and negative values may cancel each other out only up to float rounding errors
+		if self.sum.abs() <= self.abs_sum * self.float_length * ValueType::EPSILON {
+			0.0
+		} else {
+			-self.weighted_sum / self.sum
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CenterOfGravity as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, Peekable};
+	use crate::methods::tests::{test_const_float, test_method_against_naive};
+
+	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+		1e-4
+	} else {
+		1e-8
+	};
+
+	#[test]
+	fn test_center_of_gravity_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(-(i as ValueType + 1.0) / 2.0, output);
+			test_const_float(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_center_of_gravity_zero_sum() {
+		let mut method = TestingMethod::new(2, &0.0).unwrap();
+
+		assert_eq_float(0.0, method.next(&0.0));
+		assert_eq_float(-2.0, method.next(&1.0));
+		assert_eq_float(0.0, method.next(&-1.0));
+
+		let mut method = TestingMethod::new(3, &0.0).unwrap();
+		for x in [0.1, 0.2, -0.3] {
+			let _ = method.next(&x);
+		}
+		// `0.1 + 0.2 - 0.3` is not exactly zero
+		assert_eq_float(0.0, method.peek());
+	}
+
+	#[test]
+	fn test_center_of_gravity() {
+		test_method_against_naive(
+			1..255,
+			TestingMethod::new,
+			|src, i, length| {
+				let (weighted_sum, sum) = (0..length)
+					.map(|j| src[i.saturating_sub(j)])
+					.enumerate()
+					.fold((0.0, 0.0), |(weighted_sum, sum), (j, x)| {
+						(((j + 1) as ValueType).mul_add(x, weighted_sum), sum + x)
+					});
+
+				if sum == 0.0 {
+					0.0
+				} else {
+					-weighted_sum / sum
+				}
+			},
+			SIGMA,
+		);
+	}
+}
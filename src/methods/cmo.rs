@@ -0,0 +1,211 @@
+use crate::core::{Error, Method, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Chande Momentum Oscillator](https://www.investopedia.com/terms/c/chandemomentumoscillator.asp) over the last `length` changes
+/// of timeseries of type [`ValueType`]
+///
+/// `CMO` = (`sum of gains` - `sum of losses`) / (`sum of gains` + `sum of losses`)
+///
+/// When all the changes in the window are zero, returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output range is \[`-1.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Cmo;
+///
+/// let mut cmo = Cmo::new(3, &1.0).unwrap();
+///
+/// assert_eq!(cmo.next(&2.0), 1.0);
+/// assert_eq!(cmo.next(&3.0), 1.0);
+/// assert_eq!(cmo.next(&1.0), 0.0);
+/// assert_eq!(cmo.next(&0.0), -0.5);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`ChandeMomentumOscillator`](crate::indicators::ChandeMomentumOscillator), [`Vidya`](crate::methods::Vidya)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "ChandeMomentumOscillator")]
+pub struct Cmo {
+	pos_sum: ValueType,
+	neg_sum: ValueType,
+	pos_count: PeriodType,
+	neg_count: PeriodType,
+	last_input: ValueType,
+	window: Window<ValueType>,
+}
+
+impl Cmo {
+	/// Checks if there were no changes over the last `length` values
+	pub(crate) const fn is_flat(&self) -> bool {
+		self.pos_count == 0 && self.neg_count == 0
+	}
+
+	fn value(&self) -> ValueType {
+		if self.is_flat() {
+			return 0.0;
+		}
+
+		let sum = self.pos_sum + self.neg_sum;
+		((self.pos_sum - self.neg_sum) / sum).clamp(-1.0, 1.0)
+	}
+
+	/// Updates the count of the positive or negative changes in the window
+	#[allow(clippy::missing_const_for_fn)]
+	fn count(count: &mut PeriodType, entered: bool, left: bool) {
+		if entered && !left {
+			*count += 1;
+		} else if left && !entered {
+			*count -= 1;
+		}
+	}
+}
+
+impl Method for Cmo {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				pos_sum: 0.,
+				neg_sum: 0.,
+				pos_count: 0,
+				neg_count: 0,
+				last_input: value,
+				window: Window::new(length, 0.),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let change = value - self.last_input;
+		self.last_input = value;
+
+		let left_change = self.window.push(change);
+
+		Self::count(&mut self.pos_count, change > 0., left_change > 0.);
+		Self::count(&mut self.neg_count, change < 0., left_change < 0.);
+
+		// running sums keep a float residue after all the changes of one direction have left the window,
+		// so they are reset explicitly in that case
+		self.pos_sum = if self.pos_count == 0 {
+			0.
+		} else {
+			self.pos_sum + change.max(0.) - left_change.max(0.)
+		};
+		self.neg_sum = if self.neg_count == 0 {
+			0.
+		} else {
+			self.neg_sum + left_change.min(0.) - change.min(0.)
+		};
+
+		self.value()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Cmo {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Cmo as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::assert_eq_float;
+	use crate::methods::tests::{test_const, test_method_against_naive};
+
+	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+		1e-4
+	} else {
+		1e-8
+	};
+
+	#[test]
+	fn test_cmo_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_cmo_flat_after_movement() {
+		let mut method = TestingMethod::new(3, &1.1).unwrap();
+
+		for x in [2.3, 0.7, 3.9, 0.3] {
+			let _ = method.next(&x);
+		}
+
+		for _ in 0..3 {
+			let _ = method.next(&5.0);
+		}
+
+		for _ in 0..10 {
+			assert_eq_float(0.0, method.next(&5.0));
+			assert!(method.is_flat());
+		}
+
+		assert_eq_float(-1.0, method.next(&4.0));
+	}
+
+	#[test]
+	fn test_cmo() {
+		test_method_against_naive(
+			1..255,
+			TestingMethod::new,
+			|src, i, length| {
+				let (pos, neg) = (0..length)
+					.map(|j| i.saturating_sub(j))
+					.filter(|&k| k > 0)
+					.map(|k| src[k] - src[k - 1])
+					.fold((0.0, 0.0), |(pos, neg), change: ValueType| {
+						(pos + change.max(0.0), neg - change.min(0.0))
+					});
+
+				if pos + neg > 0.0 {
+					(pos - neg) / (pos + neg)
+				} else {
+					0.0
+				}
+			},
+			SIGMA,
+		);
+	}
+}
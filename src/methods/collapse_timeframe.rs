@@ -1,6 +1,6 @@
 use std::ops::Add;
 
-use crate::core::{Candle, Error, Method, OHLCV};
+use crate::core::{BufferedMethod, Candle, Error, Method, OHLCV};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -49,6 +49,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// O(1)
 ///
+/// Unfinished candle at the end of the timeseries may be flushed by [`BufferedMethod::finalize`].
+///
 /// See also Sequence's [`collapse_timeframe`](crate::core::Sequence::collapse_timeframe) function.
 ///
 /// [`ValueType`]: crate::core::ValueType
@@ -102,9 +104,19 @@ where
 	}
 }
 
+impl<T> BufferedMethod for CollapseTimeframe<T>
+where
+	T: OHLCV + Clone + Add<Output = T>,
+{
+	fn finalize(&mut self) -> Vec<Self::Output> {
+		self.index = 0;
+		self.current.take().map(Some).into_iter().collect()
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{Candle, CollapseTimeframe as TestingMethod, Method, OHLCV};
+	use super::{BufferedMethod, Candle, CollapseTimeframe as TestingMethod, Method, OHLCV};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 
@@ -165,6 +177,27 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn test_timeframe_collapse_finalize() {
+		let candles = RandomCandles::new().take(7).collect::<Vec<_>>();
+
+		let mut method = TestingMethod::new(3, &candles[0]).unwrap();
+		let converted: Vec<_> = candles.iter().filter_map(|x| method.next(x)).collect();
+		assert_eq!(converted.len(), 2);
+
+		// the last candle forms a partial bar
+		let pending = method.finalize();
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0], Some(candles[6]));
+
+		assert!(method.finalize().is_empty());
+
+		// next bar is accumulated from scratch
+		assert_eq!(method.next(&candles[0]), None);
+		assert_eq!(method.next(&candles[1]), None);
+		assert!(method.next(&candles[2]).is_some());
+	}
+
 	#[test]
 	#[should_panic(expected = "WrongMethodParameters")]
 	fn test_timeframe_collapse_fail() {
@@ -7,11 +7,21 @@ use serde::{Deserialize, Serialize};
 
 /// Convolution Moving Average with specified `weights` for timeseries of [`ValueType`].
 ///
+/// Every tick the `weights` are applied across the last `weights.len()` values and the weighted sum is normalized by the sum of `weights`,
+/// so it generalizes [`SMA`](crate::methods::SMA) (equal weights), [`WMA`](crate::methods::WMA) (weights `1`, `2`, ..., `length`)
+/// and any other weighted moving average.
+///
+/// The first weight is applied to the oldest value inside the window and the last weight is applied to the current value.
+///
+/// Also available as [`CustomWma`].
+///
 /// # Parameters
 ///
 /// Has a single parameter `weights`: Vec<[`ValueType`]>
 ///
-/// `weights` vector's length must be > `0`
+/// `weights` vector's length must be > `0` and <= maximum value of [`PeriodType`]
+///
+/// Every weight must be finite and the sum of `weights` must not be zero.
 ///
 /// # Input type
 ///
@@ -21,9 +31,22 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::CustomWma;
+///
+/// // the current value has weight 3.0, the previous one has weight 1.0
+/// let mut ma = CustomWma::new(vec![1.0, 3.0], &0.0).unwrap();
+///
+/// assert_eq!(ma.next(&4.0), 3.0);
+/// assert_eq!(ma.next(&8.0), 7.0);
+/// ```
+///
 /// # Performance
 ///
-/// O(length(`weights`))
+/// O(length(`weights`)) per tick, because all the `weights` are applied to the whole window every time
 ///
 /// This method is relatively slow compare to the other methods.
 ///
@@ -36,6 +59,7 @@ use serde::{Deserialize, Serialize};
 ///
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "CustomWeights")]
 pub struct Conv {
 	weights: Vec<ValueType>,
 	window: Window<ValueType>,
@@ -50,10 +74,18 @@ impl Method for Conv {
 	fn new(weights: Self::Params, value: &Self::Input) -> Result<Self, Error> {
 		const MAX_WEIGHTS_LEN: usize = PeriodType::MAX as usize;
 
+		if weights.iter().any(|weight| !weight.is_finite()) {
+			return Err(Error::WrongMethodParameters);
+		}
+
 		match weights.len() {
 			1..=MAX_WEIGHTS_LEN => {
 				let wsum_invert = weights.iter().sum::<ValueType>().recip();
 
+				if !wsum_invert.is_finite() {
+					return Err(Error::WrongMethodParameters);
+				}
+
 				#[allow(clippy::cast_possible_truncation)]
 				Ok(Self {
 					window: Window::new(weights.len() as PeriodType, *value),
@@ -72,6 +104,9 @@ impl Method for Conv {
 	}
 }
 
+/// Just an alias for [`Conv`] moving average with custom weights
+pub type CustomWma = Conv;
+
 impl Peekable<<Self as Method>::Output> for Conv {
 	fn peek(&self) -> <Self as Method>::Output {
 		self.window
@@ -89,6 +124,7 @@ mod tests {
 	use super::{Conv as TestingMethod, Method};
 	use crate::core::{PeriodType, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{SMA, WMA};
 
 	fn get_weights(length: PeriodType) -> Vec<ValueType> {
 		(0..length)
@@ -117,7 +153,7 @@ mod tests {
 
 	#[test]
 	fn test_conv1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let weights = get_weights(1);
 		let mut ma = TestingMethod::new(weights, &candles.first().close).unwrap();
@@ -152,4 +188,36 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_conv_sma_wma() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..255 {
+			let uniform = vec![2.5; length as usize];
+			let ascending = (1..=length).map(|x| x as ValueType).collect();
+
+			let mut uniform = TestingMethod::new(uniform, &src[0]).unwrap();
+			let mut ascending = TestingMethod::new(ascending, &src[0]).unwrap();
+			let mut sma = SMA::new(length, &src[0]).unwrap();
+			let mut wma = WMA::new(length, &src[0]).unwrap();
+
+			for x in &src {
+				assert_eq_float(sma.next(x), uniform.next(x));
+				assert_eq_float(wma.next(x), ascending.next(x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_conv_wrong_params() {
+		assert!(TestingMethod::new(vec![], &1.0).is_err());
+		assert!(TestingMethod::new(vec![1.0, -1.0], &1.0).is_err());
+		assert!(TestingMethod::new(vec![0.0, 0.0], &1.0).is_err());
+		assert!(TestingMethod::new(vec![1.0, ValueType::NAN], &1.0).is_err());
+		assert!(TestingMethod::new(vec![1.0, ValueType::INFINITY], &1.0).is_err());
+	}
 }
@@ -0,0 +1,298 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling sample covariance matrix of `K` timeseries over the window of size `length`
+///
+/// cov\(`i`, `j`\) = Σ\(\(`x_i` - mean\(`x_i`\)\) \* \(`x_j` - mean\(`x_j`\)\)\) / \(`length` - `1`\)
+///
+/// Diagonal elements are the variances of each input, so cov\(`i`, `i`\) is equal to the square of [`StDev`]\(`length`\) of the `i`-th input.
+///
+/// `K` is defined by the length of the initial value and must remain the same for every next input.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is a slice of [`ValueType`] of length `K` > `0`
+///
+/// # Output type
+///
+/// Output type is a [`Vec`] of [`ValueType`] of length `K`\*`K`.
+///
+/// Matrix is stored in row-major order, so cov\(`i`, `j`\) is placed at index `i` \* `K` + `j`.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::CovarianceMatrix;
+///
+/// let mut cov = CovarianceMatrix::new(2, &[0.0, 0.0][..]).unwrap();
+///
+/// let matrix = cov.next(&[1.0, -2.0][..]);
+///
+/// assert_eq!(matrix, [0.5, -1.0, -1.0, 2.0]);
+/// ```
+///
+/// # Performance
+///
+/// O(`K`²) amortized for each input value. [`next`](Method::next) allocates a new output vector on every call,
+/// while [`next_matrix`](CovarianceMatrix::next_matrix) reuses an internal buffer.
+///
+/// # Panics
+///
+/// Panics if the length of the input differs from the length of the initial value.
+///
+/// [`StDev`]: crate::methods::StDev
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CovarianceMatrix {
+	k: usize,
+	/// last `length` inputs, `k` values each
+	history: Vec<ValueType>,
+	index: usize,
+	means: Vec<ValueType>,
+	/// sums of cross-products of deviations from the means in row-major order, only upper triangle is used
+	comoments: Vec<ValueType>,
+	/// output buffer
+	matrix: Vec<ValueType>,
+	divider: ValueType,
+	k_sample: ValueType,
+}
+
+impl CovarianceMatrix {
+	/// Same as [`next`](Method::next), but returns the matrix in the internal buffer instead of allocating a new vector
+	///
+	/// # Panics
+	///
+	/// Panics if the length of the input differs from the length of the initial value.
+	pub fn next_matrix(&mut self, value: &[ValueType]) -> &[ValueType] {
+		let k = self.k;
+
+		assert_eq!(
+			value.len(),
+			k,
+			"CovarianceMatrix input length must be the same as initial value length"
+		);
+
+		let prev = &mut self.history[self.index..self.index + k];
+
+		// sliding Welford update: each co-moment is updated by the deviations from the old and the new means,
+		// so it never subtracts big raw sums from each other. The output buffer temporarily holds the old means.
+		let old_means = &mut self.matrix[..k];
+		old_means.copy_from_slice(&self.means);
+
+		for (i, mean) in self.means.iter_mut().enumerate() {
+			*mean += (value[i] - prev[i]) * self.divider;
+		}
+
+		for i in 0..k {
+			let (new_dev, old_dev) = (value[i] - self.means[i], prev[i] - self.means[i]);
+
+			for j in i..k {
+				let mean = old_means[j];
+				self.comoments[i * k + j] +=
+					new_dev.mul_add(value[j] - mean, -old_dev * (prev[j] - mean));
+			}
+		}
+
+		prev.copy_from_slice(value);
+		self.index = (self.index + k) % self.history.len();
+
+		// once per `length` inputs rounding errors accumulated by the updates are dropped,
+		// so the amortized complexity stays the same
+		if self.index == 0 {
+			self.recalculate();
+		}
+
+		for i in 0..k {
+			for j in i..k {
+				let cov = self.comoments[i * k + j] * self.k_sample;
+
+				self.matrix[i * k + j] = cov;
+				self.matrix[j * k + i] = cov;
+			}
+
+			// float rounding errors must not make variance negative
+			self.matrix[i * k + i] = self.matrix[i * k + i].max(0.0);
+		}
+
+		&self.matrix
+	}
+}
+
+impl CovarianceMatrix {
+	fn recalculate(&mut self) {
+		let k = self.k;
+
+		for (i, mean) in self.means.iter_mut().enumerate() {
+			*mean = self.history.iter().skip(i).step_by(k).sum::<ValueType>() * self.divider;
+		}
+
+		self.comoments.fill(0.0);
+		for row in self.history.chunks_exact(k) {
+			for i in 0..k {
+				let dev = row[i] - self.means[i];
+
+				for (j, (x, mean)) in row.iter().zip(&self.means).enumerate().skip(i) {
+					self.comoments[i * k + j] = dev.mul_add(x - mean, self.comoments[i * k + j]);
+				}
+			}
+		}
+	}
+}
+
+impl Method for CovarianceMatrix {
+	type Params = PeriodType;
+	type Input = [ValueType];
+	type Output = Vec<ValueType>;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		if length < 2 || value.is_empty() {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let k = value.len();
+
+		Ok(Self {
+			k,
+			history: value.repeat(length as usize),
+			index: 0,
+			means: value.to_vec(),
+			comoments: vec![0.0; k * k],
+			matrix: vec![0.0; k * k],
+			divider: (length as ValueType).recip(),
+			k_sample: ((length - 1) as ValueType).recip(),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.next_matrix(value).to_vec()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CovarianceMatrix as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::StDev;
+
+	#[test]
+	fn test_covariance_matrix_const() {
+		for length in 2..255 {
+			let input = [1.5, -2.0, (length as ValueType + 56.0) / 16.3251];
+			let mut method = TestingMethod::new(length, &input[..]).unwrap();
+
+			for _ in 0..100 {
+				for x in method.next(&input[..]) {
+					assert!(x.abs() < 1e-6);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_covariance_matrix_correlated() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 2..50 {
+			let mut method = TestingMethod::new(length, &[src[0], src[0]][..]).unwrap();
+			let mut st_dev = StDev::new(length, &src[0]).unwrap();
+
+			for &x in &src {
+				let matrix = method.next(&[x, x][..]);
+				let variance = st_dev.next(&x).powi(2);
+
+				assert_eq_float(matrix[0], matrix[1]);
+				assert_eq_float(matrix[0], matrix[2]);
+				assert_eq_float(matrix[3], matrix[1]);
+				assert_eq_float(variance, matrix[0]);
+			}
+		}
+	}
+
+	#[test]
+	#[cfg_attr(not(feature = "value_type_f32"), allow(clippy::useless_conversion))]
+	fn test_covariance_matrix() {
+		const SIGMA: f64 = if cfg!(feature = "value_type_f32") {
+			1e-5
+		} else {
+			1e-12
+		};
+
+		let src: Vec<[ValueType; 3]> = RandomCandles::default()
+			.take(300)
+			.map(|x| [x.close, x.high, x.volume])
+			.collect();
+
+		for length in 2..50 {
+			let mut method = TestingMethod::new(length, &src[0][..]).unwrap();
+			let length = length as usize;
+
+			for i in 0..src.len() {
+				let matrix = method.next(&src[i][..]);
+
+				let window: Vec<_> = (0..length).map(|j| src[i.saturating_sub(j)]).collect();
+				let n = length as f64;
+				// reference is always evaluated in `f64`
+				let mean = |a: usize| window.iter().map(|x| f64::from(x[a])).sum::<f64>() / n;
+				let naive_cov = |a: usize, b: usize| {
+					let (mean_a, mean_b) = (mean(a), mean(b));
+
+					window
+						.iter()
+						.map(|x| (f64::from(x[a]) - mean_a) * (f64::from(x[b]) - mean_b))
+						.sum::<f64>() / (n - 1.0)
+				};
+				// rounding errors are proportional to the magnitude of the inputs since the last recalculation,
+				// including the ones which have already left the window
+				let magnitude = |a: usize| {
+					(0..2 * length)
+						.map(|j| f64::from(src[i.saturating_sub(j)][a]).abs())
+						.fold(1.0, f64::max)
+				};
+
+				for a in 0..3 {
+					for b in 0..3 {
+						let cov = naive_cov(a, b);
+						let value = f64::from(matrix[a * 3 + b]);
+						let scale = magnitude(a) * magnitude(b);
+						assert!(
+							(cov - value).abs() <= SIGMA * scale,
+							"length={length}, i={i}, ({a}, {b}): {cov} != {value}"
+						);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_covariance_matrix_next_matrix() {
+		let src: Vec<[ValueType; 2]> = RandomCandles::default()
+			.take(100)
+			.map(|x| [x.close, x.volume])
+			.collect();
+
+		let mut a = TestingMethod::new(10, &src[0][..]).unwrap();
+		let mut b = a.clone();
+
+		for x in &src {
+			assert_eq!(a.next(&x[..]), b.next_matrix(&x[..]));
+		}
+	}
+}
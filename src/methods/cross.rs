@@ -12,6 +12,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// Else (if series did not cross each other) returns [`Action::None`](crate::core::Action::None)
 ///
+/// # NaN policy
+///
+/// Cross is defined only by two consecutive bars where both `value` and `base` are not `NaN`.
+/// So if either of the inputs is `NaN` (f.e. not yet warmed up indicator), returns [`Action::None`](crate::core::Action::None)
+/// for the current bar and for the next bar after it, because there is no defined previous state to compare with.
+///
 /// # Parameters
 ///
 /// Has no parameters
@@ -92,6 +98,11 @@ impl Method for Cross {
 ///
 /// Else returns [`Action::None`](crate::core::Action::None)
 ///
+/// # NaN policy
+///
+/// If either of the inputs is `NaN`, returns [`Action::None`](crate::core::Action::None) for the current bar and for the next bar after it.
+/// See more at [`Cross`].
+///
 /// # Parameters
 ///
 /// Has no parameters
@@ -181,6 +192,11 @@ impl Method for CrossAbove {
 ///
 /// Else returns [`Action::None`](crate::core::Action::None)
 ///
+/// # NaN policy
+///
+/// If either of the inputs is `NaN`, returns [`Action::None`](crate::core::Action::None) for the current bar and for the next bar after it.
+/// See more at [`Cross`].
+///
 /// # Parameters
 ///
 /// Has no parameters
@@ -307,6 +323,50 @@ mod tests {
 			assert_eq!(value1, value2, "{value2}, {value1} at index {i}");
 		});
 	}
+
+	#[test]
+	fn test_cross_nan() {
+		use super::Cross as TestingMethod;
+
+		for (value, base) in [
+			(ValueType::NAN, 1.0),
+			(1.0, ValueType::NAN),
+			(ValueType::NAN, ValueType::NAN),
+		] {
+			let mut cross = TestingMethod::new((), &(-1.0, 0.0)).unwrap();
+
+			assert!(cross.next(&(value, base)).is_none());
+			// no defined previous state
+			assert!(cross.next(&(1.0, 0.0)).is_none());
+			assert!(cross.next(&(-1.0, 0.0)).is_sell());
+			assert!(cross.next(&(1.0, 0.0)).is_buy());
+		}
+
+		let mut cross = TestingMethod::new((), &(ValueType::NAN, 0.0)).unwrap();
+		assert!(cross.next(&(1.0, 0.0)).is_none());
+		assert!(cross.next(&(-1.0, 0.0)).is_sell());
+	}
+
+	#[test]
+	fn test_cross_above_under_nan() {
+		use super::{CrossAbove, CrossUnder};
+
+		let mut above = CrossAbove::new((), &(-1.0, 0.0)).unwrap();
+		let mut under = CrossUnder::new((), &(1.0, 0.0)).unwrap();
+
+		assert!(above.next(&(ValueType::NAN, 0.0)).is_none());
+		assert!(under.next(&(0.0, ValueType::NAN)).is_none());
+
+		assert!(above.next(&(1.0, 0.0)).is_none());
+		assert!(under.next(&(-1.0, 0.0)).is_none());
+
+		let _ = above.next(&(-1.0, 0.0));
+		let _ = under.next(&(1.0, 0.0));
+
+		assert!(above.next(&(1.0, 0.0)).is_buy());
+		assert!(under.next(&(-1.0, 0.0)).is_buy());
+	}
+
 	#[test]
 	fn test_cross_above_const() {
 		use super::CrossAbove as TestingMethod;
@@ -0,0 +1,182 @@
+use crate::core::{Error, Method, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Running total of the outputs of any wrapped method `M`, which produces [`ValueType`] values
+///
+/// May be used for building cumulative curves over per-candle values, f.e. cumulative volume delta or
+/// cumulative return (see example below).
+///
+/// Summation starts from `0.0`: the wrapped method is created on the initial value, but its output is not accumulated
+/// until the first call of [`next`](Method::next).
+/// The running total may be set back to `0.0` at any time by [`reset`](Cumulative::reset), f.e. at the session start.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`params`: `M::Params`, `length`: [`PeriodType`]\)
+///
+/// `params` are the parameters of the wrapped method.
+///
+/// If `length == 0`, then sums outputs since the beginning (or since the last reset), otherwise sums the last `length` outputs.
+///
+/// # Input type
+///
+/// Input type is the input type of the wrapped method
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::{Cumulative, Derivative};
+///
+/// // sums all the changes of the value
+/// let mut cumulative = Cumulative::<Derivative>::new((1, 0), &1.0).unwrap();
+///
+/// assert_eq!(cumulative.next(&2.0), 1.0);
+/// assert_eq!(cumulative.next(&4.0), 3.0);
+/// assert_eq!(cumulative.next(&3.0), 2.0);
+///
+/// cumulative.reset();
+/// assert_eq!(cumulative.next(&5.0), 2.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) + performance of the wrapped method
+///
+/// # See also
+///
+/// [`Integral`](crate::methods::Integral)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cumulative<M> {
+	method: M,
+	sum: ValueType,
+	window: Window<ValueType>,
+}
+
+impl<M> Cumulative<M> {
+	/// Resets the running total to `0.0`
+	///
+	/// The state of the wrapped method stays untouched.
+	pub fn reset(&mut self) {
+		self.sum = 0.0;
+		self.window = Window::new(self.window.len(), 0.0);
+	}
+
+	/// Returns a reference to the wrapped method
+	#[must_use]
+	pub const fn method(&self) -> &M {
+		&self.method
+	}
+}
+
+impl<M: Method<Output = ValueType>> Method for Cumulative<M> {
+	type Params = (M::Params, PeriodType);
+	type Input = M::Input;
+	type Output = ValueType;
+
+	fn new((params, length): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			method: M::new(params, value)?,
+			sum: 0.0,
+			window: Window::new(length, 0.0),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let value = self.method.next(value);
+		self.sum += value;
+
+		if !self.window.is_empty() {
+			self.sum -= self.window.push(value);
+		}
+
+		self.sum
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Cumulative, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::ROC;
+
+	#[test]
+	fn test_cumulative_return() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		// rate of change over 1 period is a simple return
+		let mut method = Cumulative::<ROC>::new((1, 0), &src[0]).unwrap();
+		let mut sum = 0.0;
+
+		for window in src.windows(2) {
+			let value = method.next(&window[1]);
+			sum += (window[1] - window[0]) / window[0];
+
+			assert_eq_float(sum, value);
+		}
+	}
+
+	#[test]
+	fn test_cumulative_window() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+		let returns: Vec<ValueType> = src
+			.iter()
+			.enumerate()
+			.map(|(i, x)| {
+				let prev = src[i.saturating_sub(1)];
+				(x - prev) / prev
+			})
+			.collect();
+
+		for length in 1..30 {
+			let mut method = Cumulative::<ROC>::new((1, length), &src[0]).unwrap();
+			let length = length as usize;
+
+			for (i, x) in src.iter().enumerate() {
+				let value = method.next(x);
+				let sum: ValueType = returns[(i + 1).saturating_sub(length)..=i].iter().sum();
+
+				assert_eq_float(sum, value);
+			}
+		}
+	}
+
+	#[test]
+	fn test_cumulative_reset() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(100)
+			.map(|x| x.close)
+			.collect();
+
+		let mut method = Cumulative::<ROC>::new((1, 0), &src[0]).unwrap();
+		let mut sum = 0.0;
+
+		for (i, window) in src.windows(2).enumerate() {
+			if i % 20 == 0 {
+				method.reset();
+				sum = 0.0;
+			}
+
+			sum += (window[1] - window[0]) / window[0];
+			assert_eq_float(sum, method.next(&window[1]));
+		}
+	}
+}
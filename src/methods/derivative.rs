@@ -100,7 +100,7 @@ mod tests {
 
 	#[test]
 	fn test_derivative1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 		let mut prev = None;
@@ -0,0 +1,165 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::{Change, LinearVolatility};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kaufman's [Efficiency Ratio](https://school.stockcharts.com/doku.php?id=technical_indicators:kaufman_s_adaptive_moving_average) over the window of size `length` for timeseries of type [`ValueType`]
+///
+/// ER = abs([`Change`]\(`length`\)) / [`LinearVolatility`]\(`length`\)
+///
+/// Efficiency ratio shows how trendy the values are. It is `1.0` when values move in one direction only
+/// and tends to `0.0` when values move chaotically.
+///
+/// When there is no movement at all inside the window (zero noise), returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Range of output values is \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::EfficiencyRatio;
+///
+/// let mut er = EfficiencyRatio::new(3, &1.0).unwrap();
+///
+/// er.next(&2.0);
+/// er.next(&3.0);
+/// assert_eq!(er.next(&4.0), 1.0);
+/// assert_eq!(er.next(&1.0), 0.2); // change = 1.0, volatility = 5.0
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Kaufman`](crate::indicators::Kaufman)
+///
+/// [`Change`]: crate::methods::Change
+/// [`LinearVolatility`]: crate::methods::LinearVolatility
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EfficiencyRatio {
+	change: Change,
+	volatility: LinearVolatility,
+}
+
+impl Method for EfficiencyRatio {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			change: Change::new(length, value)?,
+			volatility: LinearVolatility::new(length, value)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let direction = self.change.next(value).abs();
+		let volatility = self.volatility.next(value);
+
+		if volatility > 0.0 {
+			// sometimes float rounding may produce values a bit greater than 1.0
+			(direction / volatility).min(1.0)
+		} else {
+			0.0
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{EfficiencyRatio as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_efficiency_ratio_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			test_const(&mut method, &input, &0.0);
+		}
+	}
+
+	#[test]
+	fn test_efficiency_ratio_monotone() {
+		for length in 1..50 {
+			let mut method = TestingMethod::new(length, &0.0).unwrap();
+
+			for i in 1..200 {
+				let value = method.next(&(i as ValueType).powi(2));
+
+				if i >= length {
+					assert_eq_float(1.0, value);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_efficiency_ratio_zig_zag() {
+		for length in [10, 20, 50] {
+			let mut method = TestingMethod::new(length, &0.0).unwrap();
+
+			for i in 1..200 {
+				let value = method.next(&((i % 2) as ValueType));
+
+				if i >= length {
+					assert!(value <= 1.0 / length as ValueType);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_efficiency_ratio() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in 1..255 {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			for i in 0..src.len() {
+				let direction = (src[i] - src[i.saturating_sub(length)]).abs();
+				let volatility: ValueType = (0..length)
+					.map(|j| (src[i.saturating_sub(j)] - src[i.saturating_sub(j + 1)]).abs())
+					.sum();
+
+				let value = method.next(&src[i]);
+
+				assert!((0.0..=1.0).contains(&value));
+				if volatility > 0.0 {
+					assert_eq_float((direction / volatility).min(1.0), value);
+				} else {
+					assert_eq_float(0.0, value);
+				}
+			}
+		}
+	}
+}
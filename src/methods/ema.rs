@@ -233,7 +233,7 @@ impl Method for DEMA {
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		let e_ma = self.ema.next(value);
-		self.dma.next(&e_ma);
+		let _ = self.dma.next(&e_ma);
 
 		self.peek()
 	}
@@ -321,7 +321,7 @@ impl Method for TEMA {
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
 		let e_ma = self.ema.next(value);
 		let d_ma = self.dma.next(&e_ma);
-		self.tma.next(&d_ma);
+		let _ = self.tma.next(&d_ma);
 
 		self.peek()
 	}
@@ -362,7 +362,7 @@ mod tests {
 	#[test]
 	fn test_ema1() {
 		use super::EMA as TestingMethod;
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -410,7 +410,7 @@ mod tests {
 	#[test]
 	fn test_dma1() {
 		use super::DMA as TestingMethod;
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -464,7 +464,7 @@ mod tests {
 	#[test]
 	fn test_dema1() {
 		use super::DEMA as TestingMethod;
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -518,7 +518,7 @@ mod tests {
 	#[test]
 	fn test_tma1() {
 		use super::TMA as TestingMethod;
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -575,7 +575,7 @@ mod tests {
 	#[test]
 	fn test_tema1() {
 		use super::{Method, TEMA as TestingMethod};
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
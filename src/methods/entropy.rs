@@ -0,0 +1,217 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling [Shannon entropy](https://en.wikipedia.org/wiki/Entropy_(information_theory)) of values over the window of size `length`
+///
+/// At each step values inside the window are distributed into `bins` buckets of equal width and then
+/// entropy is calculated as `-sum(p * log2(p))`, where `p` is a relative count of values in a bucket.
+///
+/// Bucket edges are chosen by the lowest and the highest values inside the current window:
+/// the range \[`min`; `max`\] is split into `bins` equal parts, and the highest value falls into the last bucket.
+/// If all the values inside the window are equal, then they all fall into a single bucket and entropy is `0.0`.
+///
+/// Usually it is applied to returns (f.e. [`RateOfChange`](crate::methods::RateOfChange)) rather than to raw prices.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `bins`: [`PeriodType`]\)
+///
+/// `length` should be > `1`
+///
+/// `bins` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is in range \[`0.0`; `log2(min(length, bins))`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Entropy;
+///
+/// // Entropy over the window with length=4 and 2 buckets
+/// let mut entropy = Entropy::new((4, 2), &1.0).unwrap();
+///
+/// assert_eq!(entropy.next(&1.0), 0.0);
+/// entropy.next(&1.0);
+/// entropy.next(&2.0);
+/// assert_eq!(entropy.next(&2.0), 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length` + `bins`)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Entropy {
+	window: Window<ValueType>,
+	counts: Box<[PeriodType]>,
+	divider: ValueType,
+}
+
+impl Method for Entropy {
+	type Params = (PeriodType, PeriodType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, bins): Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		if length < 2 || bins < 2 || length == PeriodType::MAX {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			window: Window::new(length, value),
+			counts: vec![0; bins as usize].into(),
+			divider: (length as ValueType).recip(),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		self.window.push(value);
+
+		let (min, max) = self
+			.window
+			.iter()
+			.fold((value, value), |(min, max), &v| (min.min(v), max.max(v)));
+
+		let range = max - min;
+
+		if range <= 0.0 {
+			return 0.0;
+		}
+
+		let bins = self.counts.len();
+		let k = bins as ValueType / range;
+
+		self.counts.fill(0);
+		self.window.iter().for_each(|&v| {
+			#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+			let index = (((v - min) * k) as usize).min(bins - 1);
+			self.counts[index] += 1;
+		});
+
+		let divider = self.divider;
+
+		self.counts
+			.iter()
+			.filter(|&&count| count > 0)
+			.map(|&count| {
+				let p = count as ValueType * divider;
+				-p * p.log2()
+			})
+			.sum()
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+mod tests {
+	use super::{Entropy as TestingMethod, Method};
+	use crate::core::{PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_entropy_const() {
+		for length in 2..255 {
+			for bins in [2, 3, 10, 100] {
+				let input = (length as ValueType + 56.0) / 16.3251;
+				let mut method = TestingMethod::new((length, bins), &input).unwrap();
+
+				let output = method.next(&input);
+				assert_eq_float(0.0, output);
+				test_const(&mut method, &input, &output);
+			}
+		}
+	}
+
+	#[test]
+	fn test_entropy_uniform() {
+		for bins in 2..20 {
+			let length: PeriodType = bins * 10;
+			let mut method = TestingMethod::new((length, bins), &0.0).unwrap();
+
+			let max_entropy = (bins as ValueType).log2();
+
+			(0..1000_usize)
+				.map(|x| (x % length as usize) as ValueType)
+				.enumerate()
+				.for_each(|(i, x)| {
+					let value = method.next(&x);
+
+					assert!(value >= 0.0 && value <= max_entropy + 1e-6);
+
+					if i >= length as usize {
+						assert!(
+							(max_entropy - value).abs() < 0.01,
+							"bins={bins}, entropy={value}, expected={max_entropy}"
+						);
+					}
+				});
+		}
+	}
+
+	#[test]
+	fn test_entropy() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 2..50 {
+			for bins in [2, 3, 7, 16] {
+				let mut method = TestingMethod::new((length, bins), &src[0]).unwrap();
+
+				src.iter().enumerate().for_each(|(i, x)| {
+					let value = method.next(x);
+
+					let window: Vec<ValueType> = (0..length as usize)
+						.map(|j| src[i.saturating_sub(j)])
+						.collect();
+					let min = window
+						.iter()
+						.copied()
+						.fold(ValueType::INFINITY, ValueType::min);
+					let max = window
+						.iter()
+						.copied()
+						.fold(ValueType::NEG_INFINITY, ValueType::max);
+
+					let mut counts = vec![0_usize; bins as usize];
+					if max > min {
+						for v in &window {
+							let index =
+								((v - min) / (max - min) * bins as ValueType).floor() as usize;
+							counts[index.min(bins as usize - 1)] += 1;
+						}
+					}
+
+					let expected: ValueType = counts
+						.iter()
+						.filter(|&&c| c > 0)
+						.map(|&c| {
+							let p = c as ValueType / length as ValueType;
+							-p * p.log2()
+						})
+						.sum();
+
+					assert_eq_float(expected, value);
+				});
+			}
+		}
+	}
+}
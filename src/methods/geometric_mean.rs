@@ -0,0 +1,192 @@
+use crate::core::{Error, Method, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Geometric mean](https://en.wikipedia.org/wiki/Geometric_mean) of specified `length` for timeseries of type [`ValueType`]
+///
+/// It is more appropriate than the arithmetic mean for averaging ratios, f.e. `1.0` + `return`.
+///
+/// Geometric mean is calculated in log domain: `GM` = exp(sum(ln(`value`)) / `length`),
+/// so instead of the product of the values it keeps a rolling sum of their logarithms.
+/// That's why all the values must be positive.
+///
+/// When there is any non-positive value inside the window, returns `0.0`.
+///
+/// `NaN` values are not treated as non-positive: like any other method it poisons the state, so output becomes `NaN`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// Values should be > `0.0`
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::GeometricMean;
+///
+/// let mut gm = GeometricMean::new(2, &1.0).unwrap();
+///
+/// assert!((gm.next(&4.0) - 2.0).abs() < 1e-10);
+/// assert!((gm.next(&9.0) - 6.0).abs() < 1e-10);
+/// assert_eq!(gm.next(&0.0), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`SMA`](crate::methods::SMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeometricMean {
+	divider: ValueType,
+	log_sum: ValueType,
+	non_positive: PeriodType,
+	window: Window<ValueType>,
+}
+
+/// Returns logarithm of positive `value` or `None` for non-positive `value`
+///
+/// `NaN` is not comparable to zero, so it is passed through as `NaN`
+#[inline]
+fn log(value: ValueType) -> Option<ValueType> {
+	if value <= 0.0 {
+		None
+	} else {
+		Some(value.ln())
+	}
+}
+
+impl Method for GeometricMean {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => {
+				let log_value = log(value);
+
+				Ok(Self {
+					divider: (length as ValueType).recip(),
+					log_sum: log_value.unwrap_or(0.0) * length as ValueType,
+					non_positive: if log_value.is_some() { 0 } else { length },
+					window: Window::new(length, value),
+				})
+			}
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let past_value = self.window.push(value);
+
+		match log(past_value) {
+			Some(past_log) => self.log_sum -= past_log,
+			None => self.non_positive -= 1,
+		}
+
+		match log(value) {
+			Some(log_value) => self.log_sum += log_value,
+			None => self.non_positive += 1,
+		}
+
+		self.peek()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for GeometricMean {
+	fn peek(&self) -> <Self as Method>::Output {
+		if self.non_positive > 0 {
+			0.0
+		} else {
+			(self.log_sum * self.divider).exp()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{GeometricMean as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::assert_eq_float;
+	use crate::methods::tests::{test_const_float, test_method_against_naive};
+
+	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+		1e-4
+	} else {
+		1e-8
+	};
+
+	#[test]
+	fn test_geometric_mean_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(input, output);
+			test_const_float(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_geometric_mean_non_positive() {
+		let mut method = TestingMethod::new(3, &-1.0).unwrap();
+
+		assert_eq_float(0.0, method.next(&2.0));
+		assert_eq_float(0.0, method.next(&2.0));
+		assert_eq_float(2.0, method.next(&2.0));
+		assert_eq_float(0.0, method.next(&0.0));
+		assert_eq_float(0.0, method.next(&2.0));
+		assert_eq_float(0.0, method.next(&2.0));
+		assert_eq_float(2.0, method.next(&2.0));
+	}
+
+	#[test]
+	fn test_geometric_mean_nan() {
+		let mut method = TestingMethod::new(3, &1.0).unwrap();
+
+		assert!(method.next(&ValueType::NAN).is_nan());
+		assert!(method.next(&2.0).is_nan());
+	}
+
+	#[test]
+	#[cfg_attr(not(feature = "value_type_f32"), allow(clippy::useless_conversion))]
+	#[cfg_attr(not(feature = "value_type_f32"), allow(trivial_numeric_casts))]
+	fn test_geometric_mean() {
+		test_method_against_naive(
+			1..255,
+			TestingMethod::new,
+			|src, i, length| {
+				// product of the values overflows `f32`, so the reference is evaluated in `f64`
+				let product: f64 = (0..length)
+					.map(|j| f64::from(src[i.saturating_sub(j)]))
+					.product();
+
+				product.powf((length as f64).recip()) as ValueType
+			},
+			SIGMA,
+		);
+	}
+}
@@ -50,7 +50,7 @@ mod tests {
 	#[test]
 	#[allow(clippy::inspect_for_each)]
 	fn test_heikin_ashi() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let first = candles.first();
 		let mut heikin_ashi = HeikinAshi::new((), &first).unwrap();
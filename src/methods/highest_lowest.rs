@@ -328,7 +328,21 @@ mod tests {
 	use super::{Highest, HighestLowestDelta, Lowest};
 	use crate::core::{Method, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
-	use crate::methods::tests::test_const;
+	use crate::methods::tests::{test_const, test_method_against_naive};
+
+	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+		1e-6
+	} else {
+		1e-12
+	};
+
+	fn naive_highest(src: &[ValueType], i: usize, length: usize) -> ValueType {
+		(0..length).fold(src[i], |m, j| m.max(src[i.saturating_sub(j)]))
+	}
+
+	fn naive_lowest(src: &[ValueType], i: usize, length: usize) -> ValueType {
+		(0..length).fold(src[i], |m, j| m.min(src[i.saturating_sub(j)]))
+	}
 
 	#[test]
 	fn test_highest_const() {
@@ -345,7 +359,7 @@ mod tests {
 	fn test_highest1() {
 		use super::Highest as TestingMethod;
 
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -356,22 +370,7 @@ mod tests {
 
 	#[test]
 	fn test_highest() {
-		use super::Highest as TestingMethod;
-
-		let candles = RandomCandles::default();
-
-		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
-
-		(2..255).for_each(|length| {
-			let mut ma = TestingMethod::new(length, &src[0]).unwrap();
-			let length = length as usize;
-
-			src.iter().enumerate().for_each(|(i, x)| {
-				let value1 = ma.next(x);
-				let value2 = (0..length).fold(src[i], |m, j| m.max(src[i.saturating_sub(j)]));
-				assert_eq_float(value2, value1);
-			});
-		});
+		test_method_against_naive(2..255, Highest::new, naive_highest, SIGMA);
 	}
 
 	#[test]
@@ -388,7 +387,7 @@ mod tests {
 	#[test]
 	fn test_lowest1() {
 		use super::Lowest as TestingMethod;
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -399,21 +398,7 @@ mod tests {
 
 	#[test]
 	fn test_lowest() {
-		use super::Lowest as TestingMethod;
-		let candles = RandomCandles::default();
-
-		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
-
-		(2..255).for_each(|length| {
-			let mut ma = TestingMethod::new(length, &src[0]).unwrap();
-			let length = length as usize;
-
-			src.iter().enumerate().for_each(|(i, x)| {
-				let value1 = ma.next(x);
-				let value2 = (0..length).fold(src[i], |m, j| m.min(src[i.saturating_sub(j)]));
-				assert_eq_float(value2, value1);
-			});
-		});
+		test_method_against_naive(2..255, Lowest::new, naive_lowest, SIGMA);
 	}
 
 	#[test]
@@ -430,7 +415,7 @@ mod tests {
 	#[test]
 	fn test_highest_lowest_delta1() {
 		use super::HighestLowestDelta as TestingMethod;
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -441,21 +426,11 @@ mod tests {
 
 	#[test]
 	fn test_highest_lowest_delta() {
-		use super::HighestLowestDelta as TestingMethod;
-		let candles = RandomCandles::default();
-
-		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
-
-		(2..255).for_each(|length| {
-			let mut ma = TestingMethod::new(length, &src[0]).unwrap();
-			let length = length as usize;
-
-			src.iter().enumerate().for_each(|(i, x)| {
-				let value1 = ma.next(x);
-				let min = (0..length).fold(src[i], |m, j| m.min(src[i.saturating_sub(j)]));
-				let max = (0..length).fold(src[i], |m, j| m.max(src[i.saturating_sub(j)]));
-				assert_eq_float(max - min, value1);
-			});
-		});
+		test_method_against_naive(
+			2..255,
+			HighestLowestDelta::new,
+			|src, i, length| naive_highest(src, i, length) - naive_lowest(src, i, length),
+			SIGMA,
+		);
 	}
 }
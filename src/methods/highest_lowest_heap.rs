@@ -0,0 +1,349 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct HeapItem {
+	value: ValueType,
+	index: usize,
+}
+
+impl PartialEq for HeapItem {
+	fn eq(&self, other: &Self) -> bool {
+		self.cmp(other) == Ordering::Equal
+	}
+}
+
+impl Eq for HeapItem {}
+
+impl PartialOrd for HeapItem {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for HeapItem {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.value
+			.total_cmp(&other.value)
+			.then(self.index.cmp(&other.index))
+	}
+}
+
+/// Rolling maximum over a binary heap with lazy deletion of the stale items
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct RollingMax {
+	heap: BinaryHeap<HeapItem>,
+	length: usize,
+	index: usize,
+}
+
+impl RollingMax {
+	fn new(length: PeriodType, value: ValueType) -> Result<Self, Error> {
+		if !value.is_finite() {
+			return Err(Error::InvalidCandles);
+		}
+
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => {
+				let mut heap = BinaryHeap::with_capacity(length as usize * 2 + 1);
+				heap.push(HeapItem { value, index: 0 });
+
+				Ok(Self {
+					heap,
+					length: length as usize,
+					index: 0,
+				})
+			}
+		}
+	}
+
+	fn push(&mut self, value: ValueType) -> ValueType {
+		self.index += 1;
+		self.heap.push(HeapItem {
+			value,
+			index: self.index,
+		});
+
+		// the oldest index, which is still inside the window
+		let first = (self.index + 1).saturating_sub(self.length);
+
+		// stale items deep inside the heap are never popped while newer values are greater,
+		// so the heap is rebuilt from time to time to keep its size bounded
+		if self.heap.len() > self.length * 2 {
+			self.heap.retain(|item| item.index >= first);
+		}
+
+		while let Some(top) = self.heap.peek() {
+			if top.index >= first {
+				return top.value;
+			}
+
+			self.heap.pop();
+		}
+
+		value
+	}
+
+	fn peek(&self) -> ValueType {
+		self.heap.peek().map_or(0.0, |item| item.value)
+	}
+}
+
+/// Returns highest value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// It is an alternative implementation of [`Highest`] based on a binary heap with lazy deletion of the values, which left the window.
+///
+/// [`Highest`] keeps just the current maximum and rescans the whole window only when that maximum leaves the window.
+/// It is very fast on average, but each rescan costs O(`length`), so for very large windows over trending
+/// (f.e. steadily falling) timeseries it may become slow.
+/// `HighestHeap` never rescans the window: each step costs O(log(`length`)) amortized, but it has
+/// a higher constant overhead and uses up to twice as much memory.
+///
+/// So prefer [`Highest`] for small and medium windows and `HighestHeap` for very large ones.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::HighestHeap;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 0.5, 2.0, 3.0];
+/// let r      = [1.0, 2.0, 3.0, 3.0, 3.0, 2.0, 2.0, 3.0];
+///
+/// let mut highest = HighestHeap::new(3, &values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = highest.next(&values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(log(`length`)) amortized
+///
+/// # See also
+///
+/// [`Highest`], [`LowestHeap`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Highest`]: crate::methods::Highest
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HighestHeap(RollingMax);
+
+impl Method for HighestHeap {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		RollingMax::new(length, value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> ValueType {
+		assert!(
+			value.is_finite(),
+			"HighestHeap method cannot operate with NAN values"
+		);
+
+		self.0.push(value)
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for HighestHeap {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.0.peek()
+	}
+}
+
+/// Returns lowest value over the last `length` values for timeseries of type [`ValueType`]
+///
+/// It is an alternative implementation of [`Lowest`] based on a binary heap with lazy deletion of the values, which left the window.
+///
+/// See [`HighestHeap`] for the tradeoffs between both implementations.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > 0
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::core::Method;
+/// use yata::methods::LowestHeap;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 0.5, 2.0, 3.0];
+/// let r      = [1.0, 1.0, 1.0, 2.0, 1.0, 0.5, 0.5, 0.5];
+///
+/// let mut lowest = LowestHeap::new(3, &values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = lowest.next(&values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(log(`length`)) amortized
+///
+/// # See also
+///
+/// [`Lowest`], [`HighestHeap`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`Lowest`]: crate::methods::Lowest
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LowestHeap(RollingMax);
+
+impl Method for LowestHeap {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		RollingMax::new(length, -value).map(Self)
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> ValueType {
+		assert!(
+			value.is_finite(),
+			"LowestHeap method cannot operate with NAN values"
+		);
+
+		-self.0.push(-value)
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for LowestHeap {
+	fn peek(&self) -> <Self as Method>::Output {
+		-self.0.peek()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{HighestHeap, LowestHeap};
+	use crate::core::{Method, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{test_const, test_method_against_naive};
+	use crate::methods::{Highest, Lowest};
+
+	const SIGMA: ValueType = if cfg!(feature = "value_type_f32") {
+		1e-6
+	} else {
+		1e-12
+	};
+
+	fn naive_highest(src: &[ValueType], i: usize, length: usize) -> ValueType {
+		(0..length).fold(src[i], |m, j| m.max(src[i.saturating_sub(j)]))
+	}
+
+	fn naive_lowest(src: &[ValueType], i: usize, length: usize) -> ValueType {
+		(0..length).fold(src[i], |m, j| m.min(src[i.saturating_sub(j)]))
+	}
+
+	#[test]
+	fn test_highest_heap_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = HighestHeap::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_lowest_heap_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = LowestHeap::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_highest_heap() {
+		test_method_against_naive(1..255, HighestHeap::new, naive_highest, SIGMA);
+	}
+
+	#[test]
+	fn test_lowest_heap() {
+		test_method_against_naive(1..255, LowestHeap::new, naive_lowest, SIGMA);
+	}
+
+	#[test]
+	fn test_heap_same_as_window() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(1000)
+			.map(|x| x.close)
+			.collect();
+
+		for length in [1, 2, 5, 20, 100, 250] {
+			let mut highest = Highest::new(length, &src[0]).unwrap();
+			let mut lowest = Lowest::new(length, &src[0]).unwrap();
+			let mut highest_heap = HighestHeap::new(length, &src[0]).unwrap();
+			let mut lowest_heap = LowestHeap::new(length, &src[0]).unwrap();
+
+			for x in &src {
+				assert_eq_float(highest.next(x), highest_heap.next(x));
+				assert_eq_float(lowest.next(x), lowest_heap.next(x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_heap_trend() {
+		// on a steadily growing timeseries the maximum never leaves the window,
+		// so the heap must not grow unbounded
+		let mut method = HighestHeap::new(10, &0.0).unwrap();
+
+		for i in 0..1000 {
+			let value = i as ValueType;
+			assert_eq_float(value, method.next(&value));
+			assert!(method.0.heap.len() <= 21);
+		}
+	}
+}
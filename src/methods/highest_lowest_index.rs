@@ -256,7 +256,7 @@ mod tests {
 	fn test_highest_index1() {
 		use super::HighestIndex as TestingMethod;
 
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -317,7 +317,7 @@ mod tests {
 	fn test_lowest_index1() {
 		use super::LowestIndex as TestingMethod;
 
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
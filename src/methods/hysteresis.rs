@@ -0,0 +1,190 @@
+use crate::core::Method;
+use crate::core::{Action, Error, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Threshold crossing with hysteresis (dead band) for timeseries of type [`ValueType`]
+///
+/// Plain threshold crossings chatter when the value moves around the level. `Hysteresis` uses two thresholds instead:
+/// * when value rises above the `upper` threshold, the state turns *high* and full buy signal is returned;
+/// * when value falls below the `lower` threshold, the state turns *low* and full sell signal is returned;
+/// * otherwise the state is held and no signal is returned.
+///
+/// Signals are returned only when the state flips, so while the value stays inside the \[`lower`; `upper`\] dead band,
+/// or keeps staying beyond the same threshold, there are no signals.
+///
+/// Initial state is *high* if initial value is above `upper`, *low* if initial value is below `lower` and *neutral* otherwise.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`lower`: [`ValueType`], `upper`: [`ValueType`]\)
+///
+/// `lower` should be <= `upper`, both should be finite numbers
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Hysteresis;
+///
+/// let mut hysteresis = Hysteresis::new((0.3, 0.7), &0.5).unwrap();
+///
+/// assert_eq!(hysteresis.next(&0.75), Action::BUY_ALL);
+/// assert_eq!(hysteresis.next(&0.65), Action::None);
+/// assert_eq!(hysteresis.next(&0.75), Action::None);
+/// assert_eq!(hysteresis.next(&0.35), Action::None);
+/// assert_eq!(hysteresis.next(&0.25), Action::SELL_ALL);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Cross`](crate::methods::Cross), [`Position`](crate::methods::Position)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hysteresis {
+	lower: ValueType,
+	upper: ValueType,
+	state: i8,
+}
+
+impl Hysteresis {
+	/// Returns current state: `1` for *high*, `-1` for *low* and `0` for *neutral*
+	#[must_use]
+	pub const fn state(&self) -> i8 {
+		self.state
+	}
+}
+
+impl Method for Hysteresis {
+	type Params = (ValueType, ValueType);
+	type Input = ValueType;
+	type Output = Action;
+
+	fn new((lower, upper): Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		if !(lower.is_finite() && upper.is_finite() && lower <= upper) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let state = if value > upper {
+			1
+		} else if value < lower {
+			-1
+		} else {
+			0
+		};
+
+		Ok(Self {
+			lower,
+			upper,
+			state,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		if value > self.upper && self.state != 1 {
+			self.state = 1;
+			Action::BUY_ALL
+		} else if value < self.lower && self.state != -1 {
+			self.state = -1;
+			Action::SELL_ALL
+		} else {
+			Action::None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Hysteresis as TestingMethod, Method};
+	use crate::core::{Action, ValueType};
+
+	#[test]
+	fn test_hysteresis_dead_band() {
+		let mut method = TestingMethod::new((-1.0, 1.0), &0.0).unwrap();
+
+		for i in 0..1000 {
+			let value = (i as ValueType * 0.7).sin() * 0.99;
+
+			assert_eq!(Action::None, method.next(&value));
+			assert_eq!(0, method.state());
+		}
+
+		assert_eq!(Action::BUY_ALL, method.next(&1.5));
+
+		// chattering around the upper threshold gives no more signals
+		for i in 0..1000 {
+			let value = (i as ValueType * 0.7).sin().mul_add(0.5, 1.0);
+
+			assert_eq!(Action::None, method.next(&value));
+			assert_eq!(1, method.state());
+		}
+
+		assert_eq!(Action::SELL_ALL, method.next(&-1.5));
+		assert_eq!(Action::None, method.next(&-2.0));
+		assert_eq!(Action::None, method.next(&0.0));
+		assert_eq!(-1, method.state());
+	}
+
+	#[test]
+	fn test_hysteresis_initial_state() {
+		let mut method = TestingMethod::new((-1.0, 1.0), &2.0).unwrap();
+		assert_eq!(Action::None, method.next(&2.0));
+
+		let mut method = TestingMethod::new((-1.0, 1.0), &-2.0).unwrap();
+		assert_eq!(Action::None, method.next(&-2.0));
+		assert_eq!(Action::BUY_ALL, method.next(&2.0));
+	}
+
+	#[test]
+	fn test_hysteresis_wrong_params() {
+		assert!(TestingMethod::new((1.0, -1.0), &0.0).is_err());
+		assert!(TestingMethod::new((ValueType::NAN, 1.0), &0.0).is_err());
+		assert!(TestingMethod::new((0.0, ValueType::INFINITY), &0.0).is_err());
+	}
+
+	#[test]
+	fn test_hysteresis() {
+		let mut method = TestingMethod::new((-0.5, 0.5), &0.0).unwrap();
+
+		// (input, expected signal, expected state)
+		let expected = [
+			(0.2, Action::None, 0),
+			(0.5, Action::None, 0),
+			(0.6, Action::BUY_ALL, 1),
+			(0.9, Action::None, 1),
+			(0.4, Action::None, 1),
+			(-0.5, Action::None, 1),
+			(0.7, Action::None, 1),
+			(-0.6, Action::SELL_ALL, -1),
+			(-0.1, Action::None, -1),
+			(-0.8, Action::None, -1),
+			(0.5, Action::None, -1),
+			(0.51, Action::BUY_ALL, 1),
+			(-3.0, Action::SELL_ALL, -1),
+			(3.0, Action::BUY_ALL, 1),
+		];
+
+		for (value, signal, state) in expected {
+			assert_eq!(signal, method.next(&value), "{value}");
+			assert_eq!(state, method.state(), "{value}");
+		}
+	}
+}
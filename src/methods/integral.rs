@@ -176,7 +176,7 @@ mod tests {
 
 	#[test]
 	fn test_integral1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -0,0 +1,219 @@
+use crate::core::Method;
+use crate::core::{Error, ValueType};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers' Laguerre RSI for timeseries of type [`ValueType`]
+///
+/// Input values pass through a 4-stage Laguerre filter controlled by a single damping factor `gamma`:
+///
+/// * `L0` = \(`1` - `gamma`\) \* `value` + `gamma` \* `L0_prev`
+/// * `L1` = -`gamma` \* `L0` + `L0_prev` + `gamma` \* `L1_prev`
+/// * `L2` = -`gamma` \* `L1` + `L1_prev` + `gamma` \* `L2_prev`
+/// * `L3` = -`gamma` \* `L2` + `L2_prev` + `gamma` \* `L3_prev`
+///
+/// Then for each pair of neighbour stages \(`L0`, `L1`\), \(`L1`, `L2`\), \(`L2`, `L3`\) the positive difference is accumulated into `CU`
+/// and the negative difference \(by absolute value\) is accumulated into `CD`:
+///
+/// `LaguerreRSI` = `CU` / \(`CU` + `CD`\)
+///
+/// When `CU` + `CD` is zero (f.e. on constant input), returns `0.5`. Differences between the stages caused only by float rounding errors are ignored.
+///
+/// The filtered value itself, \(`L0` + `2` \* `L1` + `2` \* `L2` + `L3`\) / `6`, is available through [`LaguerreRsi::filter`].
+///
+/// # Parameters
+///
+/// Has a single parameter `gamma`: [`ValueType`]
+///
+/// `gamma` should be in range \[`0.0`; `1.0`\). Usual value is `0.5`. The bigger `gamma` is, the smoother and the more lagging the output is.
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is in range \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::LaguerreRsi;
+///
+/// let mut rsi = LaguerreRsi::new(0.5, &1.0).unwrap();
+///
+/// assert_eq!(rsi.next(&1.0), 0.5);
+/// assert!(rsi.next(&2.0) > 0.5);
+///
+/// for _ in 0..10 {
+///     rsi.next(&0.0);
+/// }
+/// assert!(rsi.next(&0.0) < 0.5);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`RelativeStrengthIndex`](crate::indicators::RelativeStrengthIndex)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "Laguerre")]
+pub struct LaguerreRsi {
+	gamma: ValueType,
+	l: [ValueType; 4],
+}
+
+impl LaguerreRsi {
+	/// Returns current value of the Laguerre filter: \(`L0` + `2` \* `L1` + `2` \* `L2` + `L3`\) / `6`
+	#[must_use]
+	pub fn filter(&self) -> ValueType {
+		let [l0, l1, l2, l3] = self.l;
+
+		(l1 + l2).mul_add(2.0, l0 + l3) / 6.0
+	}
+}
+
+impl Method for LaguerreRsi {
+	type Params = ValueType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(gamma: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		if !(0.0..1.0).contains(&gamma) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			gamma,
+			l: [value; 4],
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let gamma = self.gamma;
+		let [l0_prev, l1_prev, l2_prev, l3_prev] = self.l;
+
+		let l0 = (1.0 - gamma).mul_add(value, gamma * l0_prev);
+		let l1 = gamma.mul_add(l1_prev - l0, l0_prev);
+		let l2 = gamma.mul_add(l2_prev - l1, l1_prev);
+		let l3 = gamma.mul_add(l3_prev - l2, l2_prev);
+
+		self.l = [l0, l1, l2, l3];
+
+		self.peek()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for LaguerreRsi {
+	fn peek(&self) -> <Self as Method>::Output {
+		let (cu, cd) = self
+			.l
+			.windows(2)
+			.map(|w| w[0] - w[1])
+			.fold((0.0, 0.0), |(cu, cd), diff| {
+				if diff > 0.0 {
+					(cu + diff, cd)
+				} else {
+					(cu, cd - diff)
+				}
+			});
+
+		let sum = cu + cd;
+		// on constant input stages may differ only by float rounding errors
+		let scale = self
+			.l
+			.iter()
+			.fold(0.0, |max: ValueType, x| max.max(x.abs()));
+
+		if sum > scale * ValueType::EPSILON * 16.0 {
+			cu / sum
+		} else {
+			0.5
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{LaguerreRsi as TestingMethod, Method};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_laguerre_rsi_const() {
+		for i in 0..100 {
+			let gamma = i as ValueType / 100.0;
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(gamma, &input).unwrap();
+
+			test_const_float(&mut method, &input, 0.5);
+			assert_eq_float(input, method.filter());
+		}
+	}
+
+	#[test]
+	fn test_laguerre_rsi_filter_converges() {
+		let mut method = TestingMethod::new(0.8, &1.0).unwrap();
+
+		for _ in 0..500 {
+			let _ = method.next(&10.0);
+		}
+
+		assert_eq_float(10.0, method.filter());
+		assert_eq_float(0.5, method.next(&10.0));
+	}
+
+	#[test]
+	fn test_laguerre_rsi_range() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for i in 0..100 {
+			let gamma = i as ValueType / 100.0;
+			let mut method = TestingMethod::new(gamma, &src[0]).unwrap();
+
+			for x in &src {
+				let value = method.next(x);
+				assert!((0.0..=1.0).contains(&value), "{value} with gamma={gamma}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_laguerre_rsi_trend() {
+		let mut up = TestingMethod::new(0.5, &0.0).unwrap();
+		let mut down = TestingMethod::new(0.5, &0.0).unwrap();
+
+		for i in 1..50 {
+			let x = i as ValueType;
+			let (up_value, down_value) = (up.next(&x), down.next(&-x));
+
+			// stages lag behind each other more and more, so after a few bars they become ordered
+			if i > 10 {
+				assert_eq_float(1.0, up_value);
+				assert_eq_float(0.0, down_value);
+			}
+		}
+	}
+
+	#[test]
+	fn test_laguerre_rsi_wrong_params() {
+		assert!(TestingMethod::new(-0.1, &1.0).is_err());
+		assert!(TestingMethod::new(1.0, &1.0).is_err());
+		assert!(TestingMethod::new(ValueType::NAN, &1.0).is_err());
+	}
+}
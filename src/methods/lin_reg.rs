@@ -59,6 +59,19 @@ impl LinReg {
 		// y = kx + b, x=0
 		self.s_x.mul_add(self.tan(), self.s_y) * self.length_invert
 	}
+
+	/// Returns mean value over the window
+	#[inline]
+	#[must_use]
+	pub fn mean(&self) -> ValueType {
+		self.s_y * self.length_invert
+	}
+
+	/// Returns the oldest value over the window, which leaves it on the next input
+	#[inline]
+	pub(crate) fn oldest(&self) -> ValueType {
+		*self.window.oldest()
+	}
 }
 
 impl Method for LinReg {
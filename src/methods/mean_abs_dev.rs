@@ -54,7 +54,7 @@ impl Method for MeanAbsDev {
 
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
-		self.0.next(value);
+		let _ = self.0.next(value);
 
 		self.peek()
 	}
@@ -93,7 +93,7 @@ mod tests {
 
 	#[test]
 	fn test_mean_abs_dev1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
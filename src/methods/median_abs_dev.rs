@@ -6,7 +6,12 @@ use crate::methods::SMM;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// [Median absolute deviation](https://en.wikipedia.org/wiki/Average_absolute_deviation) of specified `length` for timeseries of type [`ValueType`]
+/// [Median absolute deviation](https://en.wikipedia.org/wiki/Median_absolute_deviation) of specified `length` for timeseries of type [`ValueType`]
+///
+/// `MAD` = median of |`value` - `median`| over the last `length` values, where `median` is a rolling median ([`SMM`](crate::methods::SMM)).
+///
+/// It is a robust alternative to the [standard deviation](crate::methods::StDev), because a single outlier
+/// does not inflate it. For normally distributed values `1.4826` * `MAD` estimates standard deviation.
 ///
 /// # Parameters
 ///
@@ -22,9 +27,27 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::MedianAbsDev;
+///
+/// let mut mad = MedianAbsDev::new(5, &1.0).unwrap();
+///
+/// mad.next(&2.0);
+/// mad.next(&3.0);
+/// mad.next(&4.0);
+/// assert_eq!(mad.next(&100.0), 1.0);
+/// ```
+///
 /// # Performance
 ///
-/// O(`length`)
+/// O(`length` log `length`)
+///
+/// # See also
+///
+/// [`MeanAbsDev`](crate::methods::MeanAbsDev), [`StDev`](crate::methods::StDev)
 ///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
@@ -32,7 +55,9 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MedianAbsDev {
 	smm: SMM,
-	divider: ValueType,
+	mad: ValueType,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	deviations: Vec<ValueType>,
 }
 
 impl MedianAbsDev {
@@ -53,31 +78,36 @@ impl Method for MedianAbsDev {
 			0 | 1 => Err(Error::WrongMethodParameters),
 			length => Ok(Self {
 				smm: SMM::new(length, value)?,
-				divider: (length as ValueType).recip(),
+				mad: 0.0,
+				deviations: Vec::with_capacity(length as usize),
 			}),
 		}
 	}
 
 	#[inline]
 	fn next(&mut self, value: &Self::Input) -> Self::Output {
-		self.smm.next(value);
-
-		self.peek()
+		let smm = self.smm.next(value);
+
+		self.deviations.clear();
+		self.deviations.extend(
+			self.smm
+				.get_window()
+				.as_slice()
+				.iter()
+				.map(|x| (x - smm).abs()),
+		);
+		self.deviations.sort_unstable_by(ValueType::total_cmp);
+
+		let len = self.deviations.len();
+		self.mad = (self.deviations[len / 2] + self.deviations[(len - 1) / 2]) * 0.5;
+
+		self.mad
 	}
 }
 
 impl Peekable<<Self as Method>::Output> for MedianAbsDev {
 	fn peek(&self) -> <Self as Method>::Output {
-		let smm = self.smm.peek();
-
-		self.smm
-			.get_window()
-			.as_slice()
-			.iter()
-			.map(|x| x - smm)
-			.map(ValueType::abs)
-			.sum::<ValueType>()
-			* self.divider
+		self.mad
 	}
 }
 
@@ -86,6 +116,7 @@ mod tests {
 	use super::{MedianAbsDev as TestingMethod, Method};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::StDev;
 	use std::cmp::Ordering;
 
 	#[test]
@@ -99,10 +130,30 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_median_abs_dev_outlier() {
+		let mut mad = TestingMethod::new(20, &10.0).unwrap();
+		let mut st_dev = StDev::new(20, &10.0).unwrap();
+
+		let mut values: Vec<ValueType> = (0..20)
+			.map(|i| if i % 2 == 0 { 9.0 } else { 11.0 })
+			.collect();
+		values[15] = 1000.0;
+
+		let (mut mad_value, mut st_dev_value) = (0.0, 0.0);
+		for value in &values {
+			mad_value = mad.next(value);
+			st_dev_value = st_dev.next(value);
+		}
+
+		assert_eq_float(1.0, mad_value);
+		assert!(st_dev_value > 100.0 * mad_value);
+	}
+
 	#[test]
 	#[should_panic(expected = "WrongMethodParameters")]
 	fn test_median_abs_dev1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -142,12 +193,14 @@ mod tests {
 					+ smm_slice[((length - 1) as usize) / 2])
 					/ 2.0;
 
-				let mut sum = 0.0;
-				for j in 0..length {
-					sum += (smm - src[i.saturating_sub(j as usize)]).abs();
-				}
+				let mut deviations: Vec<ValueType> = (0..length)
+					.map(|j| (smm - src[i.saturating_sub(j as usize)]).abs())
+					.collect();
+				deviations.sort_unstable_by(ValueType::total_cmp);
 
-				let q = sum / length as ValueType;
+				let q = (deviations[(length as usize) / 2]
+					+ deviations[((length - 1) as usize) / 2])
+					* 0.5;
 
 				let value = method.next(x);
 				assert_eq_float(q, value);
@@ -37,6 +37,8 @@ mod wma;
 pub use wma::*;
 mod ema;
 pub use ema::*;
+mod t3;
+pub use t3::*;
 mod wsma;
 pub use wsma::*;
 mod rma;
@@ -47,44 +49,92 @@ mod hma;
 pub use hma::*;
 mod lin_reg;
 pub use lin_reg::*;
+mod trend_strength;
+pub use trend_strength::*;
 mod swma;
 pub use swma::*;
+mod geometric_mean;
+pub use geometric_mean::*;
 mod conv;
 pub use conv::*;
 mod vwma;
 pub use vwma::*;
 mod trima;
 pub use trima::*;
+mod zlema;
+pub use zlema::*;
 //
 mod derivative;
 pub use derivative::*;
 mod integral;
 pub use integral::*;
+mod cumulative;
+pub use cumulative::*;
 mod momentum;
 pub use momentum::*;
+mod streak;
+pub use streak::*;
+mod up_down_count;
+pub use up_down_count::*;
 mod rate_of_change;
 pub use rate_of_change::*;
+mod smoothed_roc_sum;
+pub use smoothed_roc_sum::*;
 mod tsi;
 pub use tsi::*;
 mod st_dev;
 pub use st_dev::*;
+mod covariance_matrix;
+pub use covariance_matrix::*;
+mod auto_correlation;
+pub use auto_correlation::*;
+mod ribbon_compression;
+pub use ribbon_compression::*;
+mod sharpe;
+pub use sharpe::*;
+mod percent_b;
+pub use percent_b::*;
 mod volatility;
 pub use volatility::*;
+mod efficiency_ratio;
+pub use efficiency_ratio::*;
 mod cci;
 pub use cci::*;
 mod mean_abs_dev;
 pub use mean_abs_dev::*;
 mod median_abs_dev;
 pub use median_abs_dev::*;
+mod cmo;
+pub use cmo::*;
+mod laguerre_rsi;
+pub use laguerre_rsi::*;
 mod vidya;
 pub use vidya::*;
+mod entropy;
+pub use entropy::*;
+mod center_of_gravity;
+pub use center_of_gravity::*;
 
 mod cross;
 pub use cross::*;
+mod hysteresis;
+pub use hysteresis::*;
 mod reversal;
 pub use reversal::*;
+mod sr_zones;
+pub use sr_zones::*;
+mod signal_decay;
+pub use signal_decay::*;
+mod position;
+pub use position::*;
 mod highest_lowest;
 pub use highest_lowest::*;
+mod highest_lowest_heap;
+pub use highest_lowest_heap::*;
+mod range_position;
+pub use range_position::*;
+mod abs_max;
+pub use abs_max::*;
 mod adi;
 mod highest_lowest_index;
 pub use adi::*;
@@ -94,7 +144,17 @@ pub use past::*;
 mod heikin_ashi;
 pub use heikin_ashi::HeikinAshi;
 mod tr;
-pub use tr::TR;
+pub use tr::{TrueRange, TR};
+mod williams_ad;
+pub use williams_ad::{WilliamsAD, WAD};
+mod volume_oscillator;
+pub use volume_oscillator::VolumeOscillator;
+mod candle_stats;
+pub use candle_stats::CandleStats;
+mod narrowest_range;
+pub use narrowest_range::NarrowestRange;
+mod top_k_volume;
+pub use top_k_volume::TopKVolume;
 /// Renko implementation entities
 ///
 /// For more information see [`Renko`]
@@ -106,9 +166,10 @@ pub use collapse_timeframe::CollapseTimeframe;
 
 #[cfg(test)]
 mod tests {
-	use crate::core::{Method, ValueType};
-	use crate::helpers::assert_eq_float;
+	use crate::core::{Error, Method, PeriodType, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
 	use std::fmt::Debug;
+	use std::ops::Range;
 
 	pub(super) fn test_const<P, I: ?Sized, O: Debug + PartialEq>(
 		method: &mut dyn Method<Params = P, Input = I, Output = O>,
@@ -130,4 +191,41 @@ mod tests {
 			assert_eq_float(output, method.next(input));
 		}
 	}
+
+	/// Runs `method` (constructed for each of the `lengths`) and `naive` reference over the same
+	/// [`RandomCandles`] close prices and asserts their results are equal within `sigma` relative tolerance.
+	///
+	/// `naive` gets the whole input series, current index and method's length.
+	pub(super) fn test_method_against_naive<M, C, N>(
+		lengths: Range<PeriodType>,
+		constructor: C,
+		naive: N,
+		sigma: ValueType,
+	) where
+		M: Method<Input = ValueType, Output = ValueType>,
+		C: Fn(PeriodType, &ValueType) -> Result<M, Error>,
+		N: Fn(&[ValueType], usize, usize) -> ValueType,
+	{
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in lengths {
+			let mut method = constructor(length, &src[0]).unwrap();
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let calculated = method.next(x);
+				let original = naive(&src, i, length as usize);
+
+				let diff = (original - calculated).abs();
+				let scale = original.abs().max(calculated.abs()).max(1.0);
+
+				assert!(
+					diff <= sigma * scale,
+					"length={length}, index={i}, original={original}, calculated={calculated}, diff={diff}",
+				);
+			});
+		}
+	}
 }
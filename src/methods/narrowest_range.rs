@@ -0,0 +1,136 @@
+use crate::core::{Action, Error, Method, PeriodType, ValueType, OHLCV};
+use crate::methods::Lowest;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Detects volatility contraction bars (NR7-style): flags the candle when its range (`high` - `low`)
+/// is the narrowest one over the last `length` candles
+///
+/// Classic NR7 pattern uses `length` = `7`, NR4 uses `length` = `4`.
+///
+/// The range of the initial candle fills the whole window, so the first `length` - `1` candles
+/// are compared against it too.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// Returns full buy signal when current candle's range is equal to the lowest range over the last `length` candles,
+/// otherwise returns no signal.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::NarrowestRange;
+///
+/// let candle = |low, high| Candle { open: low, high, low, close: high, volume: 1.0 };
+/// let mut nr = NarrowestRange::new(3, &candle(10.0, 12.0)).unwrap();
+///
+/// assert_eq!(nr.next(&candle(10.0, 13.0)), Action::None);
+/// assert_eq!(nr.next(&candle(10.0, 11.0)), Action::BUY_ALL);
+/// assert_eq!(nr.next(&candle(10.0, 11.5)), Action::None);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`) in the worst case, O(1) on average
+///
+/// # See also
+///
+/// [`Lowest`](crate::methods::Lowest), [`TR`](crate::methods::TR)
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`Action`]: crate::core::Action
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NarrowestRange {
+	lowest: Lowest,
+}
+
+impl Method for NarrowestRange {
+	type Params = PeriodType;
+	type Input = dyn OHLCV;
+	type Output = Action;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			lowest: Lowest::new(length, &(value.high() - value.low()))?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let range: ValueType = value.high() - value.low();
+
+		Action::from(range <= self.lowest.next(&range))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, NarrowestRange as TestingMethod};
+	use crate::core::{Action, Candle, ValueType};
+	use crate::helpers::RandomCandles;
+
+	fn candle(range: ValueType) -> Candle {
+		Candle {
+			open: 100.0,
+			high: 100.0 + range,
+			low: 100.0,
+			close: 100.0,
+			volume: 1.0,
+		}
+	}
+
+	#[test]
+	fn test_narrowest_range_nr7() {
+		let mut method = TestingMethod::new(7, &candle(2.0)).unwrap();
+
+		let ranges = [3.0, 4.0, 5.0, 3.5, 6.0, 2.5, 1.0, 2.0, 1.5, 3.0, 4.0, 1.2];
+		let signals: Vec<_> = ranges.iter().map(|&r| method.next(&candle(r))).collect();
+
+		for (i, signal) in signals.into_iter().enumerate() {
+			if i == 6 {
+				assert_eq!(signal, Action::BUY_ALL);
+			} else {
+				assert_eq!(signal, Action::None, "at bar {}", i + 1);
+			}
+		}
+	}
+
+	#[test]
+	fn test_narrowest_range() {
+		let candles: Vec<Candle> = RandomCandles::new().take(300).collect();
+		let ranges: Vec<_> = candles.iter().map(|c| c.high - c.low).collect();
+
+		for length in 1..20 {
+			let mut method = TestingMethod::new(length, &candles[0]).unwrap();
+
+			for (i, candle) in candles.iter().enumerate() {
+				let min = (0..length as usize)
+					.map(|j| ranges[i.saturating_sub(j)])
+					.fold(ValueType::INFINITY, ValueType::min);
+
+				assert_eq!(
+					method.next(candle),
+					Action::from(ranges[i] <= min),
+					"at {i} with length {length}"
+				);
+			}
+		}
+	}
+}
@@ -118,7 +118,7 @@ mod tests {
 
 	#[test]
 	fn test_past1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first()).unwrap();
 
@@ -0,0 +1,162 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::methods::{StDev, SMA};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [%B](https://school.stockcharts.com/doku.php?id=technical_indicators:bollinger_band_perce) shows where value is relative to the [Bollinger Bands](crate::indicators::BollingerBands)
+///
+/// `%B` = \(`value` - `lower band`\) / \(`upper band` - `lower band`\)
+///
+/// where `upper band` = [`SMA`]\(`length`\) + `sigma` \* [`StDev`]\(`length`\)
+/// and `lower band` = [`SMA`]\(`length`\) - `sigma` \* [`StDev`]\(`length`\).
+///
+/// `%B` is `0.0` when value is at the lower band, `0.5` when value is at the middle band and `1.0` when value is at the upper band.
+/// When value is outside of the bands, `%B` is below `0.0` or above `1.0`.
+///
+/// When the bands have zero width (all the values inside the window are equal), returns `0.5`.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `sigma`: [`ValueType`]\)
+///
+/// `length` should be > `1`
+///
+/// `sigma` should be > `0.0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::PercentB;
+///
+/// let mut percent_b = PercentB::new((3, 2.0), &1.0).unwrap();
+///
+/// assert_eq!(percent_b.next(&1.0), 0.5);
+/// percent_b.next(&2.0);
+/// assert_eq!(percent_b.next(&3.0), 0.75); // SMA = 2.0, StDev = 1.0
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`BollingerBands`](crate::indicators::BollingerBands)
+///
+/// [`SMA`]: crate::methods::SMA
+/// [`StDev`]: crate::methods::StDev
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PercentB {
+	ma: SMA,
+	st_dev: StDev,
+	sigma: ValueType,
+}
+
+impl Method for PercentB {
+	type Params = (PeriodType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, sigma): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		if length < 2 || sigma <= 0.0 || !sigma.is_finite() {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			ma: SMA::new(length, value)?,
+			st_dev: StDev::new(length, value)?,
+			sigma,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let middle = self.ma.next(value);
+		let width = self.st_dev.next(value) * self.sigma;
+
+		if width > 0.0 {
+			// same as (value - lower) / (upper - lower)
+			(value - middle) / (2.0 * width) + 0.5
+		} else {
+			0.5
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, PercentB as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+	use crate::methods::{StDev, SMA};
+
+	#[test]
+	fn test_percent_b_const() {
+		for i in 2..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((i, 2.0), &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(0.5, output);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_percent_b_middle() {
+		// symmetric window: the last value is always equal to the mean
+		let src = [1.0, 3.0, 2.0, 1.0, 3.0, 2.0, 1.0, 3.0, 2.0];
+		let mut method = TestingMethod::new((3, 2.0), &2.0).unwrap();
+
+		for (i, x) in src.iter().enumerate() {
+			let value = method.next(x);
+
+			if i % 3 == 2 {
+				assert_eq_float(0.5, value);
+			}
+		}
+	}
+
+	#[test]
+	fn test_percent_b() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		for length in 2..50 {
+			for sigma in [1.0, 2.0, 2.5] {
+				let mut method = TestingMethod::new((length, sigma), &src[0]).unwrap();
+				let mut ma = SMA::new(length, &src[0]).unwrap();
+				let mut st_dev = StDev::new(length, &src[0]).unwrap();
+
+				for x in &src {
+					let value = method.next(x);
+
+					let middle = ma.next(x);
+					let dev = st_dev.next(x) * sigma;
+					let (upper, lower) = (middle + dev, middle - dev);
+
+					if upper > lower {
+						assert_eq_float((x - lower) / (upper - lower), value);
+					} else {
+						assert_eq_float(0.5, value);
+					}
+				}
+			}
+		}
+	}
+}
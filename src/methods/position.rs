@@ -0,0 +1,140 @@
+use crate::core::Method;
+use crate::core::{Action, Error};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Turns a stream of signals into the current position state
+///
+/// Position is:
+/// * `1` for *long* position;
+/// * `-1` for *short* position;
+/// * `0` for *flat* (no position).
+///
+/// Position starts *flat*. Any *buy* signal turns the position to *long*, any *sell* signal turns it to *short*.
+/// Repeated signals of the same direction, [`Action::None`](crate::core::Action::None) and signals with zero strength do not change the position.
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is [`Action`]
+///
+/// Initial value is ignored, so position is always *flat* at the start.
+///
+/// # Output type
+///
+/// Output type is `i8`
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::Position;
+///
+/// let mut position = Position::new((), &Action::None).unwrap();
+///
+/// assert_eq!(position.next(&Action::None), 0);
+/// assert_eq!(position.next(&Action::BUY_ALL), 1);
+/// assert_eq!(position.next(&Action::None), 1);
+/// assert_eq!(position.next(&Action::SELL_ALL), -1);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// [`Action`]: crate::core::Action
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Position {
+	position: i8,
+}
+
+impl Method for Position {
+	type Params = ();
+	type Input = Action;
+	type Output = i8;
+
+	fn new((): Self::Params, _: &Self::Input) -> Result<Self, Error> {
+		Ok(Self::default())
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		match Option::<bool>::from(value) {
+			Some(true) => self.position = 1,
+			Some(false) => self.position = -1,
+			None => {}
+		}
+
+		self.position
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Position {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.position
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Position as TestingMethod};
+	use crate::core::Action;
+	use crate::helpers::RandomCandles;
+
+	#[test]
+	fn test_position_path() {
+		let signals = [
+			Action::BUY_ALL,
+			Action::BUY_ALL,
+			Action::SELL_ALL,
+			Action::None,
+			Action::SELL_ALL,
+		];
+		let expected = [1, 1, -1, -1, -1];
+
+		let mut method = TestingMethod::new((), &Action::None).unwrap();
+
+		for (signal, &expected) in signals.iter().zip(&expected) {
+			assert_eq!(expected, method.next(signal));
+		}
+	}
+
+	#[test]
+	fn test_position_ignores_weak_signals() {
+		let mut method = TestingMethod::new((), &Action::BUY_ALL).unwrap();
+
+		assert_eq!(0, method.next(&Action::None));
+		assert_eq!(0, method.next(&Action::Buy(0)));
+		assert_eq!(1, method.next(&Action::Buy(1)));
+		assert_eq!(1, method.next(&Action::Sell(0)));
+		assert_eq!(-1, method.next(&Action::Sell(1)));
+	}
+
+	#[test]
+	fn test_position() {
+		let signals: Vec<_> = RandomCandles::new()
+			.take(300)
+			.map(|c| Action::from(c.close - c.open))
+			.collect();
+
+		let mut method = TestingMethod::new((), &signals[0]).unwrap();
+		let mut position = 0;
+
+		for signal in &signals {
+			if signal.is_buy() {
+				position = 1;
+			} else if signal.is_sell() {
+				position = -1;
+			}
+
+			assert_eq!(position, method.next(signal));
+		}
+	}
+}
@@ -0,0 +1,169 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use crate::methods::{Highest, Lowest};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Shows where the current value is inside its rolling range over the last `length` values for timeseries of type [`ValueType`]
+///
+/// `output` = \(`value` - [`Lowest`]\(`length`\)\) / \([`Highest`]\(`length`\) - [`Lowest`]\(`length`\)\)
+///
+/// It is the same as raw `%K` of the [`StochasticOscillator`](crate::indicators::StochasticOscillator),
+/// but calculated over a single timeseries, so it can be applied to any values.
+///
+/// `output` is `1.0` when the value is the highest one inside the window and `0.0` when it is the lowest one.
+///
+/// When all the values inside the window are equal, returns `0.5`.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is in range \[`0.0`; `1.0`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::RangePosition;
+///
+/// let mut position = RangePosition::new(3, &1.0).unwrap();
+///
+/// assert_eq!(position.next(&1.0), 0.5);
+/// assert_eq!(position.next(&3.0), 1.0);
+/// assert_eq!(position.next(&2.0), 0.5);
+/// assert_eq!(position.next(&0.0), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(`length`)
+///
+/// This method is relatively slow compare to the other methods.
+///
+/// # See also
+///
+/// [`Highest`], [`Lowest`], [`HighestLowestDelta`](crate::methods::HighestLowestDelta)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "ChannelPosition")]
+pub struct RangePosition {
+	highest: Highest,
+	lowest: Lowest,
+	last_value: ValueType,
+}
+
+impl Method for RangePosition {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			highest: Highest::new(length, value)?,
+			lowest: Lowest::new(length, value)?,
+			last_value: 0.5,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let highest = self.highest.next(value);
+		let lowest = self.lowest.next(value);
+		let range = highest - lowest;
+
+		self.last_value = if range > 0.0 {
+			(value - lowest) / range
+		} else {
+			0.5
+		};
+
+		self.last_value
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for RangePosition {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.last_value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, RangePosition as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_range_position_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			test_const_float(&mut method, &input, 0.5);
+		}
+	}
+
+	#[test]
+	fn test_range_position_extremes() {
+		let mut method = TestingMethod::new(5, &10.0).unwrap();
+
+		for i in 1..20 {
+			assert_eq_float(1.0, method.next(&(10.0 + i as ValueType)));
+		}
+
+		for i in 1..20 {
+			assert_eq_float(0.0, method.next(&(10.0 - i as ValueType)));
+		}
+	}
+
+	#[test]
+	fn test_range_position() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..30 {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			for i in 0..src.len() {
+				let window = (0..length).map(|age| src[i.saturating_sub(age)]);
+				let highest = window.clone().fold(ValueType::MIN, ValueType::max);
+				let lowest = window.fold(ValueType::MAX, ValueType::min);
+
+				let expected = if highest > lowest {
+					(src[i] - lowest) / (highest - lowest)
+				} else {
+					0.5
+				};
+
+				let value = method.next(&src[i]);
+				assert_eq_float(expected, value);
+				assert!((0.0..=1.0).contains(&value));
+			}
+		}
+	}
+
+	#[test]
+	fn test_range_position_wrong_params() {
+		assert!(TestingMethod::new(0, &1.0).is_err());
+	}
+}
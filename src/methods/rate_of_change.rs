@@ -86,7 +86,7 @@ mod tests {
 
 	#[test]
 	fn test_rate_of_change1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 		let mut der = Derivative::new(1, &candles.first().close).unwrap();
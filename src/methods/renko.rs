@@ -1,4 +1,4 @@
-use crate::core::{Error, Method, Source, ValueType, OHLCV};
+use crate::core::{BufferedMethod, Error, Method, Source, ValueType, OHLCV};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -81,6 +81,28 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(renko.next(&inputs[6]).len(), 1);
 /// ```
 ///
+/// Price movement which is not enough for a full block at the end of the timeseries
+/// may be flushed as a partial block by [`BufferedMethod::finalize`].
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Source;
+/// use yata::methods::Renko;
+///
+/// let candle = |close| Candle { close, volume: 1.0, ..Candle::default() };
+/// let mut renko = Renko::new((0.01, Source::Close), &candle(100.0)).unwrap();
+///
+/// assert!(renko.next(&candle(101.0)).is_empty());
+///
+/// let partial = renko.finalize();
+/// assert_eq!(partial.len(), 1);
+///
+/// let block = partial[0].clone().next().unwrap();
+/// assert!((block.open - 100.5).abs() < 1e-5);
+/// assert!((block.close - 101.0).abs() < 1e-5);
+/// assert_eq!(block.volume, 1.0);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -103,6 +125,8 @@ pub struct Renko {
 	brick_size: ValueType,
 	src: Source,
 	volume: ValueType,
+	/// last value which is not flushed yet
+	pending: Option<ValueType>,
 }
 
 /// Single unit for [`Renko`] charts
@@ -323,6 +347,7 @@ impl Method for Renko {
 				next_block_lower: (value - half_size) * (1. - brick_size),
 				src,
 				volume: 0.0,
+				pending: None,
 			})
 		} else {
 			Err(Error::WrongMethodParameters)
@@ -337,6 +362,7 @@ impl Method for Renko {
 	fn next(&mut self, candle: &Self::Input) -> Self::Output {
 		let value = candle.source(self.src);
 		self.volume += candle.volume();
+		self.pending = Some(value);
 
 		if value >= self.next_block_upper {
 			let len = ((value - self.last_block_upper) / self.last_block_upper / self.brick_size)
@@ -390,12 +416,43 @@ impl Method for Renko {
 	}
 }
 
+impl BufferedMethod for Renko {
+	/// Flushes a partial block from the last block's bound to the last value
+	///
+	/// Blocks grid is not changed, so the next full block is still built from the last full block.
+	fn finalize(&mut self) -> Vec<Self::Output> {
+		let Some(value) = self.pending.take() else {
+			return Vec::new();
+		};
+
+		let base_line = if value > self.last_block_upper {
+			self.last_block_upper
+		} else if value < self.last_block_lower {
+			self.last_block_lower
+		} else {
+			return Vec::new();
+		};
+
+		let volume = self.volume;
+		self.volume = 0.0;
+
+		vec![RenkoOutput {
+			len: 1,
+			pos: 0,
+			brick_size: value / base_line - 1.0,
+			base_line,
+			block_volume: volume,
+		}]
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::core::Source;
+	use crate::helpers::assert_eq_float;
 	use crate::prelude::Candle;
 
-	use super::{Method, Renko};
+	use super::{BufferedMethod, Method, Renko};
 
 	#[test]
 	#[allow(clippy::match_same_arms)]
@@ -426,4 +483,37 @@ mod tests {
 				_ => panic!("Expected match arm for index {i}"),
 			});
 	}
+
+	#[test]
+	fn test_renko_finalize() {
+		let candle = |close| Candle {
+			close,
+			volume: 1.0,
+			..Candle::default()
+		};
+
+		let mut renko = Renko::new((0.01, Source::Close), &candle(100.0)).unwrap();
+		assert!(renko.finalize().is_empty());
+
+		// inside the last block there is nothing to flush
+		assert!(renko.next(&candle(100.2)).is_empty());
+		assert!(renko.finalize().is_empty());
+
+		assert_eq!(renko.next(&candle(102.0)).len(), 1);
+		assert!(renko.next(&candle(101.0)).is_empty());
+		assert!(renko.next(&candle(99.8)).is_empty());
+
+		// falling partial block from the lower bound of the last block
+		let partial = renko.finalize();
+		assert_eq!(partial.len(), 1);
+		assert!(partial[0].is_falling());
+
+		let blocks: Vec<_> = partial[0].clone().collect();
+		assert_eq!(blocks.len(), 1);
+		assert_eq_float(100.5, blocks[0].open);
+		assert_eq_float(99.8, blocks[0].close);
+		assert_eq_float(2.0, blocks[0].volume);
+
+		assert!(renko.finalize().is_empty());
+	}
 }
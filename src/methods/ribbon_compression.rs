@@ -0,0 +1,207 @@
+use crate::core::Method;
+use crate::core::{Error, ValueType};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Normalized spread of a moving averages ribbon
+///
+/// `output` = \(`max` - `min`\) / |`mean`|
+///
+/// where `max`, `min` and `mean` are calculated over the `K` input values (usually the values of several moving averages of different lengths,
+/// like in [`GMMA`](crate::indicators::GuppyMultipleMovingAverage)).
+///
+/// Small values mean that the ribbon is compressed, which is usually treated as a consolidation.
+/// Large values mean that the ribbon fans out, which is usually treated as a trend.
+///
+/// When the mean value is zero (or negligible compared to the input values), the compression is undefined,
+/// so returns [`NaN`](ValueType::NAN). It may happen only when the input values have different signs or are all zeros.
+///
+/// `K` is defined by the length of the initial value and must remain the same for every next input.
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is a slice of [`ValueType`] of length `K` > `0`
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::RibbonCompression;
+///
+/// let mut ribbon = RibbonCompression::new((), &[1.0, 1.0, 1.0][..]).unwrap();
+///
+/// assert_eq!(ribbon.next(&[2.0, 2.0, 2.0][..]), 0.0);
+/// assert_eq!(ribbon.next(&[9.0, 10.0, 11.0][..]), 0.2);
+/// ```
+///
+/// # Performance
+///
+/// O(`K`)
+///
+/// # Panics
+///
+/// Panics if the length of the input differs from the length of the initial value.
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RibbonCompression {
+	k: usize,
+	last_value: ValueType,
+}
+
+impl RibbonCompression {
+	fn compression(values: &[ValueType]) -> ValueType {
+		let (min, max, sum) = values.iter().fold(
+			(ValueType::INFINITY, ValueType::NEG_INFINITY, 0.0),
+			|(min, max, sum), &x| (min.min(x), max.max(x), sum + x),
+		);
+
+		let mean = sum / values.len() as ValueType;
+
+		if mean.abs() <= ValueType::EPSILON * max.abs().max(min.abs()) {
+			ValueType::NAN
+		} else {
+			(max - min) / mean.abs()
+		}
+	}
+}
+
+impl Method for RibbonCompression {
+	type Params = ();
+	type Input = [ValueType];
+	type Output = ValueType;
+
+	fn new((): Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		if value.is_empty() {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			k: value.len(),
+			last_value: Self::compression(value),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		assert_eq!(
+			value.len(),
+			self.k,
+			"RibbonCompression input length must be the same as initial value length"
+		);
+
+		self.last_value = Self::compression(value);
+
+		self.last_value
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for RibbonCompression {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.last_value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, RibbonCompression as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, Peekable, RandomCandles};
+	use crate::methods::EMA;
+
+	#[test]
+	fn test_ribbon_compression_const() {
+		for k in 1..20 {
+			let input = vec![(k as ValueType + 56.0) / 16.3251; k];
+			let mut method = TestingMethod::new((), &input).unwrap();
+
+			for _ in 0..100 {
+				assert_eq_float(0.0, method.next(&input));
+			}
+		}
+	}
+
+	#[test]
+	fn test_ribbon_compression_fan_out() {
+		let mut method = TestingMethod::new((), &[10.0; 4][..]).unwrap();
+
+		let mut prev = method.next(&[10.0; 4][..]);
+		assert_eq_float(0.0, prev);
+
+		for i in 1..20 {
+			let spread = i as ValueType * 0.1;
+			let input = [
+				10.0,
+				10.0 + spread,
+				spread.mul_add(2.0, 10.0),
+				spread.mul_add(3.0, 10.0),
+			];
+			let value = method.next(&input[..]);
+
+			let mean = input.iter().sum::<ValueType>() / 4.0;
+			assert_eq_float(3.0 * spread / mean, value);
+			assert!(value > prev);
+
+			prev = value;
+		}
+	}
+
+	#[test]
+	fn test_ribbon_compression_zero_mean() {
+		let mut method = TestingMethod::new((), &[0.0, 0.0][..]).unwrap();
+		assert!(method.peek().is_nan());
+
+		assert!(method.next(&[-1.0, 1.0][..]).is_nan());
+		assert!(method.next(&[-0.1, 0.3 - 0.2][..]).is_nan());
+		assert_eq_float(1.0, method.next(&[-1.0, 3.0][..]));
+		assert_eq_float(1.0, method.next(&[-3.0, 1.0][..]));
+	}
+
+	#[test]
+	fn test_ribbon_compression_emas() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		let mut emas: Vec<_> = [3, 5, 8, 10, 12, 15]
+			.iter()
+			.map(|&length| EMA::new(length, &src[0]).unwrap())
+			.collect();
+		let mut method = TestingMethod::new((), &[src[0]; 6][..]).unwrap();
+
+		for x in &src {
+			let values: Vec<_> = emas.iter_mut().map(|ema| ema.next(x)).collect();
+			let value = method.next(&values);
+
+			let max = values
+				.iter()
+				.copied()
+				.fold(ValueType::NEG_INFINITY, ValueType::max);
+			let min = values
+				.iter()
+				.copied()
+				.fold(ValueType::INFINITY, ValueType::min);
+			let mean = values.iter().sum::<ValueType>() / values.len() as ValueType;
+
+			assert!(value >= 0.0);
+			assert_eq_float((max - min) / mean, value);
+		}
+	}
+
+	#[test]
+	fn test_ribbon_compression_wrong_params() {
+		assert!(TestingMethod::new((), &[][..]).is_err());
+	}
+}
@@ -120,7 +120,7 @@ mod tests {
 
 	#[test]
 	fn test_rma1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -0,0 +1,375 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+use crate::methods::{StDev, SMA};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+fn validate(length: PeriodType, periods_per_year: ValueType, risk_free: ValueType) -> bool {
+	length > 1 && periods_per_year > 0.0 && periods_per_year.is_finite() && risk_free.is_finite()
+}
+
+/// Relative tolerance, below which deviation is treated as zero
+///
+/// Running sums of squares leave a rounding residue of about `sqrt(EPSILON)` relative to the magnitude of the returns.
+const DEVIATION_EPSILON: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-3
+} else {
+	1e-6
+};
+
+/// Divides `excess` return by the `deviation` and annualizes it by multiplying on `k`
+///
+/// When `deviation` is zero (relative to the `scale` of the returns), returns infinity with the sign of the `excess` return
+/// or `0.0` if there is no `excess` return at all.
+#[inline]
+fn annualized_ratio(
+	excess: ValueType,
+	deviation: ValueType,
+	scale: ValueType,
+	k: ValueType,
+) -> ValueType {
+	if deviation > scale * DEVIATION_EPSILON {
+		excess / deviation * k
+	} else if excess > 0.0 {
+		ValueType::INFINITY
+	} else if excess < 0.0 {
+		ValueType::NEG_INFINITY
+	} else {
+		0.0
+	}
+}
+
+/// Rolling annualized [Sharpe ratio](https://en.wikipedia.org/wiki/Sharpe_ratio) over the window of size `length` for timeseries of returns
+///
+/// `Sharpe ratio` = \(`mean(returns)` - `risk_free` / `periods_per_year`\) / `stdev(returns)` \* `sqrt(periods_per_year)`
+///
+/// If standard deviation of the returns inside the window is zero (up to the rounding residue relative to the magnitude of the returns),
+/// returns `+inf` for positive excess return, `-inf` for negative excess return and `0.0` if there is no excess return.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`length`: [`PeriodType`], `periods_per_year`: [`ValueType`], `risk_free`: [`ValueType`]\)
+///
+/// `length` should be > `1`
+///
+/// `periods_per_year` is a count of return observations per year (f.e. `252.0` for daily returns). Should be > `0.0`
+///
+/// `risk_free` is an annual risk-free rate of return. Use `0.0` if you don't need it
+///
+/// # Input type
+///
+/// Input type is [`ValueType`] (return of the period, f.e. from [`RateOfChange`](crate::methods::RateOfChange))
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::RollingSharpe;
+///
+/// // Rolling Sharpe ratio over 3 periods with 4 periods per year and no risk-free rate
+/// let mut sharpe = RollingSharpe::new((3, 4.0, 0.0), &0.0).unwrap();
+///
+/// sharpe.next(&1.0);
+/// assert_eq!(sharpe.next(&2.0), 2.0); // mean = 1.0, stdev = 1.0
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`RollingSortino`], [`StDev`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`StDev`]: crate::methods::StDev
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingSharpe {
+	mean: SMA,
+	st_dev: StDev,
+	risk_free: ValueType,
+	k: ValueType,
+}
+
+impl Method for RollingSharpe {
+	type Params = (PeriodType, ValueType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(
+		(length, periods_per_year, risk_free): Self::Params,
+		value: &Self::Input,
+	) -> Result<Self, Error> {
+		if !validate(length, periods_per_year, risk_free) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			mean: SMA::new(length, value)?,
+			st_dev: StDev::new(length, value)?,
+			risk_free: risk_free / periods_per_year,
+			k: periods_per_year.sqrt(),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let mean = self.mean.next(value);
+		let st_dev = self.st_dev.next(value);
+
+		let scale = mean.abs().max(self.risk_free.abs());
+
+		annualized_ratio(mean - self.risk_free, st_dev, scale, self.k)
+	}
+}
+
+/// Rolling annualized [Sortino ratio](https://en.wikipedia.org/wiki/Sortino_ratio) over the window of size `length` for timeseries of returns
+///
+/// `Sortino ratio` = \(`mean(returns)` - `target`\) / `downside deviation` \* `sqrt(periods_per_year)`
+///
+/// where `target` = `risk_free` / `periods_per_year` and
+/// `downside deviation` = `sqrt(mean(min(returns - target, 0.0)^2))`.
+///
+/// If downside deviation inside the window is zero (up to the rounding residue relative to the magnitude of the returns),
+/// returns `+inf` for positive excess return, `-inf` for negative excess return and `0.0` if there is no excess return.
+///
+/// # Parameters
+///
+/// Has a tuple of 3 parameters \(`length`: [`PeriodType`], `periods_per_year`: [`ValueType`], `risk_free`: [`ValueType`]\)
+///
+/// `length` should be > `1`
+///
+/// `periods_per_year` is a count of return observations per year (f.e. `252.0` for daily returns). Should be > `0.0`
+///
+/// `risk_free` is an annual risk-free rate of return. Use `0.0` if you don't need it
+///
+/// # Input type
+///
+/// Input type is [`ValueType`] (return of the period, f.e. from [`RateOfChange`](crate::methods::RateOfChange))
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::RollingSortino;
+///
+/// // Rolling Sortino ratio over 4 periods with 4 periods per year and no risk-free rate
+/// let mut sortino = RollingSortino::new((4, 4.0, 0.0), &3.0).unwrap();
+///
+/// sortino.next(&3.0);
+/// sortino.next(&-2.0);
+/// assert_eq!(sortino.next(&0.0), 2.0); // mean = 1.0, downside deviation = 1.0
+/// assert_eq!(sortino.downside_deviation(), 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`RollingSharpe`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingSortino {
+	window: Window<ValueType>,
+	sum: ValueType,
+	sq_downside_sum: ValueType,
+	divider: ValueType,
+	risk_free: ValueType,
+	k: ValueType,
+}
+
+impl RollingSortino {
+	#[inline]
+	fn sq_downside(&self, value: ValueType) -> ValueType {
+		let downside = (value - self.risk_free).min(0.0);
+		downside * downside
+	}
+
+	/// Returns current downside deviation of the returns inside the window
+	#[inline]
+	#[must_use]
+	pub fn downside_deviation(&self) -> ValueType {
+		// sometimes float values may produce negative values, when sum is really near to zero value
+		(self.sq_downside_sum * self.divider).max(0.0).sqrt()
+	}
+}
+
+impl Method for RollingSortino {
+	type Params = (PeriodType, ValueType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(
+		(length, periods_per_year, risk_free): Self::Params,
+		&value: &Self::Input,
+	) -> Result<Self, Error> {
+		if !validate(length, periods_per_year, risk_free) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let float_length = length as ValueType;
+
+		let mut method = Self {
+			window: Window::new(length, value),
+			sum: value * float_length,
+			sq_downside_sum: 0.0,
+			divider: float_length.recip(),
+			risk_free: risk_free / periods_per_year,
+			k: periods_per_year.sqrt(),
+		};
+
+		method.sq_downside_sum = method.sq_downside(value) * float_length;
+
+		Ok(method)
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let prev_value = self.window.push(value);
+
+		self.sum += value - prev_value;
+		self.sq_downside_sum += self.sq_downside(value) - self.sq_downside(prev_value);
+
+		self.peek()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for RollingSortino {
+	fn peek(&self) -> <Self as Method>::Output {
+		let mean = self.sum * self.divider;
+
+		let scale = mean.abs().max(self.risk_free.abs());
+
+		annualized_ratio(
+			mean - self.risk_free,
+			self.downside_deviation(),
+			scale,
+			self.k,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, RollingSharpe as TestingMethod, RollingSortino, DEVIATION_EPSILON};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	const PERIODS_PER_YEAR: ValueType = 252.0;
+
+	#[test]
+	fn test_rolling_sharpe_constant_return() {
+		for input in [0.01, -0.01, 1e-4, 0.5, -3.7] {
+			for length in 2..255 {
+				let mut method =
+					TestingMethod::new((length, PERIODS_PER_YEAR, 0.0), &input).unwrap();
+
+				// rounding residue of the varying history must not leak into the constant window
+				for i in 0..length {
+					let _ = method.next(&(input * (1.0 + (i % 3) as ValueType)));
+				}
+
+				for i in 0..length as usize * 2 {
+					let value = method.next(&input);
+
+					if i + 1 >= length as usize {
+						assert!(
+							value.is_infinite(),
+							"input={input}, length={length}: {value}"
+						);
+						assert_eq!(value.is_sign_positive(), input > 0.0);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_rolling_sortino_constant_return() {
+		for length in 2..255 {
+			let input = 0.01;
+			let mut method = RollingSortino::new((length, PERIODS_PER_YEAR, 0.0), &input).unwrap();
+
+			for _ in 0..100 {
+				assert!(method.next(&input).is_infinite());
+				assert_eq_float(0.0, method.downside_deviation());
+			}
+		}
+	}
+
+	#[test]
+	fn test_rolling_sharpe_zero_return() {
+		let mut method = TestingMethod::new((10, PERIODS_PER_YEAR, 0.0), &0.0).unwrap();
+		let mut sortino = RollingSortino::new((10, PERIODS_PER_YEAR, 0.0), &0.0).unwrap();
+
+		for _ in 0..100 {
+			assert_eq_float(0.0, method.next(&0.0));
+			assert_eq_float(0.0, sortino.next(&0.0));
+		}
+	}
+
+	#[test]
+	fn test_rolling_sharpe() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close - 1.0).collect();
+		let risk_free = 0.05;
+
+		for length in 2..50 {
+			let mut sharpe =
+				TestingMethod::new((length, PERIODS_PER_YEAR, risk_free), &src[0]).unwrap();
+			let mut sortino =
+				RollingSortino::new((length, PERIODS_PER_YEAR, risk_free), &src[0]).unwrap();
+
+			let target = risk_free / PERIODS_PER_YEAR;
+			let k = PERIODS_PER_YEAR.sqrt();
+
+			for i in 0..src.len() {
+				let window: Vec<ValueType> = (0..length as usize)
+					.map(|j| src[i.saturating_sub(j)])
+					.collect();
+				let n = length as ValueType;
+
+				let mean = window.iter().sum::<ValueType>() / n;
+				let st_dev = (window.iter().map(|x| (x - mean).powi(2)).sum::<ValueType>()
+					/ (n - 1.0))
+					.sqrt();
+				let downside = (window
+					.iter()
+					.map(|x| (x - target).min(0.0).powi(2))
+					.sum::<ValueType>()
+					/ n)
+					.sqrt();
+
+				// far enough from the zero deviation tolerance
+				let scale = mean.abs().max(target.abs()) * DEVIATION_EPSILON * 10.0;
+
+				let value = sharpe.next(&src[i]);
+				if st_dev > scale {
+					assert_eq_float((mean - target) / st_dev * k, value);
+				}
+
+				let value = sortino.next(&src[i]);
+				if downside > scale {
+					assert_eq_float((mean - target) / downside * k, value);
+				}
+			}
+		}
+	}
+}
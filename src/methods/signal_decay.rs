@@ -0,0 +1,213 @@
+use crate::core::Method;
+use crate::core::{Action, Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Decay schedule for the [`SignalDecay`] method
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Decay {
+	/// Signal strength linearly fades by `1/length` of it's initial strength at each bar
+	#[default]
+	Linear,
+
+	/// Signal strength is multiplied by the `factor` at each bar.
+	///
+	/// `factor` must be in range \(`0.0`; `1.0`\)
+	Exponential(ValueType),
+}
+
+/// Fades every fresh signal to zero strength over the next `length` bars
+///
+/// When input signal appears (it's strength is not zero), it is returned at full strength and remembered.
+/// On each next bar without fresh signal, the remembered signal is returned with reduced strength:
+///
+/// * for [`Decay::Linear`]: `strength` * \(`1` - `n` / `length`\);
+/// * for [`Decay::Exponential`]: `strength` * `factor`<sup>`n`</sup>,
+///
+/// where `n` is a count of bars since the signal appeared.
+///
+/// After `length` bars without fresh signal returns [`Action::None`](crate::core::Action::None).
+/// Every fresh signal restarts the schedule.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `decay`: [`Decay`]\)
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`Action`]
+///
+/// # Output type
+///
+/// Output type is [`Action`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::{Decay, SignalDecay};
+///
+/// let mut decay = SignalDecay::new((4, Decay::Linear), &Action::None).unwrap();
+///
+/// assert_eq!(decay.next(&Action::BUY_ALL), Action::BUY_ALL);
+/// assert_eq!(decay.next(&Action::None), Action::from(0.75));
+/// assert_eq!(decay.next(&Action::None), Action::from(0.5));
+/// assert_eq!(decay.next(&Action::None), Action::from(0.25));
+/// assert_eq!(decay.next(&Action::None), Action::None);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// [`Action`]: crate::core::Action
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalDecay {
+	length: PeriodType,
+	decay: Decay,
+	strength: ValueType,
+	position: PeriodType,
+}
+
+impl Method for SignalDecay {
+	type Params = (PeriodType, Decay);
+	type Input = Action;
+	type Output = Action;
+
+	fn new((length, decay): Self::Params, _: &Self::Input) -> Result<Self, Error> {
+		if length == 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		if let Decay::Exponential(factor) = decay {
+			if !(factor > 0.0 && factor < 1.0) {
+				return Err(Error::WrongMethodParameters);
+			}
+		}
+
+		Ok(Self {
+			length,
+			decay,
+			strength: 0.0,
+			position: length,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		if value.is_buy() || value.is_sell() {
+			self.strength = value.ratio().unwrap_or_default();
+			self.position = 0;
+		} else if self.position < self.length {
+			self.position += 1;
+		}
+
+		if self.position >= self.length {
+			return Action::None;
+		}
+
+		let k = match self.decay {
+			Decay::Linear => 1.0 - self.position as ValueType / self.length as ValueType,
+			Decay::Exponential(factor) => factor.powf(self.position as ValueType),
+		};
+
+		Action::from(self.strength * k)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Decay, Method, SignalDecay as TestingMethod};
+	use crate::core::{Action, ValueType};
+
+	const SIGMA: ValueType = 1. / 255.;
+
+	fn assert_ratio(expected: ValueType, action: Action) {
+		let ratio = action.ratio().unwrap();
+		assert!(
+			(expected - ratio).abs() <= SIGMA,
+			"expected={expected}, ratio={ratio}"
+		);
+	}
+
+	#[test]
+	fn test_signal_decay_const() {
+		for length in 1..255 {
+			let mut method = TestingMethod::new((length, Decay::Linear), &Action::None).unwrap();
+
+			for _ in 0..100 {
+				assert_eq!(method.next(&Action::None), Action::None);
+			}
+
+			for _ in 0..100 {
+				assert_eq!(method.next(&Action::BUY_ALL), Action::BUY_ALL);
+			}
+		}
+	}
+
+	#[test]
+	fn test_signal_decay_linear() {
+		for length in 1..255 {
+			let mut method = TestingMethod::new((length, Decay::Linear), &Action::None).unwrap();
+
+			for signal in [Action::BUY_ALL, Action::SELL_ALL, Action::Buy(100)] {
+				let ratio = signal.ratio().unwrap();
+
+				assert_eq!(method.next(&signal), signal);
+
+				for n in 1..length {
+					let expected = ratio * (1.0 - n as ValueType / length as ValueType);
+					assert_ratio(expected, method.next(&Action::None));
+				}
+
+				assert_eq!(method.next(&Action::None), Action::None);
+				assert_eq!(method.next(&Action::None), Action::None);
+			}
+		}
+	}
+
+	#[test]
+	fn test_signal_decay_exponential() {
+		let factor = 0.5;
+		let mut method =
+			TestingMethod::new((5, Decay::Exponential(factor)), &Action::None).unwrap();
+
+		assert_eq!(method.next(&Action::SELL_ALL), Action::SELL_ALL);
+		assert_ratio(-0.5, method.next(&Action::None));
+		assert_ratio(-0.25, method.next(&Action::None));
+		assert_ratio(-0.125, method.next(&Action::None));
+		assert_ratio(-0.0625, method.next(&Action::None));
+		assert_eq!(method.next(&Action::None), Action::None);
+	}
+
+	#[test]
+	fn test_signal_decay_restart() {
+		let mut method = TestingMethod::new((4, Decay::Linear), &Action::None).unwrap();
+
+		assert_eq!(method.next(&Action::BUY_ALL), Action::BUY_ALL);
+		assert_ratio(0.75, method.next(&Action::None));
+		assert_eq!(method.next(&Action::SELL_ALL), Action::SELL_ALL);
+		assert_ratio(-0.75, method.next(&Action::None));
+		assert_ratio(-0.5, method.next(&Action::Buy(0)));
+		assert_ratio(-0.25, method.next(&Action::None));
+		assert_eq!(method.next(&Action::None), Action::None);
+	}
+
+	#[test]
+	fn test_signal_decay_wrong_params() {
+		assert!(TestingMethod::new((0, Decay::Linear), &Action::None).is_err());
+		assert!(TestingMethod::new((5, Decay::Exponential(0.0)), &Action::None).is_err());
+		assert!(TestingMethod::new((5, Decay::Exponential(1.0)), &Action::None).is_err());
+		assert!(
+			TestingMethod::new((5, Decay::Exponential(ValueType::NAN)), &Action::None).is_err()
+		);
+	}
+}
@@ -288,6 +288,31 @@ impl Peekable<<Self as Method>::Output> for SMM {
 	}
 }
 
+/// Rolling median filter for despiking timeseries of type [`ValueType`]
+///
+/// It is just an alias for [`SMM`]: every value is replaced with the median of the last `length` values.
+/// With an odd `length` = `2` \* `k` + `1` (usually `3` or `5`) it removes any run of up to `k` outlier values,
+/// while a monotonic trend passes through unchanged, delayed by `k` values.
+///
+/// Useful as a preprocessing stage before sensitive methods and indicators.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::MedianFilter;
+///
+/// let mut filter = MedianFilter::new(3, &1.0).unwrap();
+///
+/// assert_eq!(filter.next(&2.0), 1.0);
+/// assert_eq!(filter.next(&100.0), 2.0); // spike is removed
+/// assert_eq!(filter.next(&4.0), 4.0);
+/// assert_eq!(filter.next(&5.0), 5.0);
+/// ```
+///
+/// [`ValueType`]: crate::core::ValueType
+pub type MedianFilter = SMM;
+
 #[cfg(test)]
 mod tests {
 	use super::{Method, SMM as TestingMethod};
@@ -308,7 +333,7 @@ mod tests {
 
 	#[test]
 	fn test_smm1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -360,4 +385,40 @@ mod tests {
 			});
 		}
 	}
+
+	#[test]
+	fn test_median_filter_despike() {
+		// linear trend with single-bar spikes up and down
+		let src: Vec<ValueType> = (0..300)
+			.map(|i| match i % 7 {
+				3 => i as ValueType + 50.0,
+				6 => i as ValueType - 50.0,
+				_ => i as ValueType,
+			})
+			.collect();
+
+		for length in [3, 5] {
+			let mut trend = super::MedianFilter::new(length, &0.0).unwrap();
+			let mut filter = super::MedianFilter::new(length, &src[0]).unwrap();
+			let lag = (length / 2) as ValueType;
+
+			// trend itself passes through unchanged, but delayed
+			for i in 0..50 {
+				let value = trend.next(&(i as ValueType));
+				assert_eq_float((i as ValueType - lag).max(0.0), value);
+			}
+
+			for (i, x) in src.iter().enumerate() {
+				let value = filter.next(x);
+
+				// spikes are removed: output is always one of the trend values inside the window
+				if i >= length as usize {
+					assert!(
+						(value - (i as ValueType - lag)).abs() <= lag,
+						"{value} at {i}"
+					);
+				}
+			}
+		}
+	}
 }
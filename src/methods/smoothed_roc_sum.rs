@@ -0,0 +1,199 @@
+use crate::core::{Error, Method, MovingAverageConstructor, PeriodType, ValueType};
+use crate::helpers::MA;
+use crate::methods::RateOfChange;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the [`SmoothedRocSum`] method
+///
+/// Consists of a list of \(`roc_period`, `weight`\) pairs and a moving average for smoothing the weighted sum.
+///
+/// # Examples
+///
+/// ```
+/// use yata::helpers::MA;
+/// use yata::methods::SmoothedRocSumParams;
+///
+/// // Coppock Curve configuration
+/// let params = SmoothedRocSumParams::new(MA::WMA(10))
+///     .roc(14, 1.0)
+///     .roc(11, 1.0);
+///
+/// assert_eq!(params.rocs(), &[(14, 1.0), (11, 1.0)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SmoothedRocSumParams<M: MovingAverageConstructor = MA> {
+	rocs: Vec<(PeriodType, ValueType)>,
+	ma: M,
+}
+
+impl<M: MovingAverageConstructor> SmoothedRocSumParams<M> {
+	/// Creates parameters with no rate of change periods and the smoothing moving average `ma`
+	pub const fn new(ma: M) -> Self {
+		Self {
+			rocs: Vec::new(),
+			ma,
+		}
+	}
+
+	/// Adds [`RateOfChange`](crate::methods::RateOfChange) with the `period` and it's `weight` in the sum
+	#[must_use]
+	pub fn roc(mut self, period: PeriodType, weight: ValueType) -> Self {
+		self.rocs.push((period, weight));
+		self
+	}
+
+	/// Returns \(`roc_period`, `weight`\) pairs
+	pub fn rocs(&self) -> &[(PeriodType, ValueType)] {
+		&self.rocs
+	}
+
+	/// Returns the smoothing moving average
+	pub const fn ma(&self) -> &M {
+		&self.ma
+	}
+}
+
+/// Weighted sum of several [`RateOfChange`]s smoothed by a moving average
+///
+/// `SmoothedRocSum` = `MA`\(`weight1` \* `ROC(period1)` + `weight2` \* `ROC(period2)` + ...\)
+///
+/// It is a general form for such momentum indicators as [`CoppockCurve`](crate::indicators::CoppockCurve).
+///
+/// # Parameters
+///
+/// Has a single parameter [`SmoothedRocSumParams`]
+///
+/// There should be at least one \(`roc_period`, `weight`\) pair, every `roc_period` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::MA;
+/// use yata::methods::{SmoothedRocSum, SmoothedRocSumParams};
+///
+/// let params = SmoothedRocSumParams::new(MA::SMA(2)).roc(1, 1.0).roc(2, 0.5);
+/// let mut roc_sum = SmoothedRocSum::new(params, &1.0).unwrap();
+///
+/// assert_eq!(roc_sum.next(&1.0), 0.0);
+/// assert_eq!(roc_sum.next(&2.0), 0.75); // SMA(0.0, 1.0 + 0.5)
+/// ```
+///
+/// # Performance
+///
+/// O(`n`), where `n` is a count of \(`roc_period`, `weight`\) pairs, plus the performance of the smoothing moving average
+///
+/// # See also
+///
+/// [`RateOfChange`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`RateOfChange`]: crate::methods::RateOfChange
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SmoothedRocSum<M: MovingAverageConstructor = MA> {
+	rocs: Vec<(RateOfChange, ValueType)>,
+	ma: M::Instance,
+}
+
+impl<M: MovingAverageConstructor> Method for SmoothedRocSum<M> {
+	type Params = SmoothedRocSumParams<M>;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(params: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		if params.rocs.is_empty() {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let rocs = params
+			.rocs
+			.iter()
+			.map(|&(period, weight)| Ok((RateOfChange::new(period, value)?, weight)))
+			.collect::<Result<_, Error>>()?;
+
+		Ok(Self {
+			rocs,
+			ma: params.ma.init(0.0)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let sum = self.rocs.iter_mut().fold(0.0, |sum, (roc, weight)| {
+			roc.next(value).mul_add(*weight, sum)
+		});
+
+		self.ma.next(&sum)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, SmoothedRocSum as TestingMethod, SmoothedRocSumParams};
+	use crate::core::{IndicatorConfig, IndicatorInstance, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles, MA};
+	use crate::indicators::CoppockCurve;
+	use crate::methods::tests::test_const;
+	use crate::methods::{RateOfChange, WMA};
+
+	#[test]
+	fn test_smoothed_roc_sum_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let params = SmoothedRocSumParams::new(MA::EMA(i))
+				.roc(i, 1.0)
+				.roc(i / 2 + 1, 2.0);
+			let mut method = TestingMethod::new(params, &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(0.0, output);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_smoothed_roc_sum_wrong_params() {
+		assert!(TestingMethod::new(SmoothedRocSumParams::new(MA::SMA(3)), &1.0).is_err());
+		assert!(
+			TestingMethod::new(SmoothedRocSumParams::new(MA::SMA(3)).roc(0, 1.0), &1.0).is_err()
+		);
+	}
+
+	#[test]
+	fn test_smoothed_roc_sum_coppock() {
+		let candles: Vec<_> = RandomCandles::new().take(300).collect();
+		let src: Vec<ValueType> = candles.iter().map(|c| c.close).collect();
+
+		let params = SmoothedRocSumParams::new(MA::WMA(10))
+			.roc(14, 1.0)
+			.roc(11, 1.0);
+		let mut method = TestingMethod::new(params, &src[0]).unwrap();
+
+		let mut roc1 = RateOfChange::new(14, &src[0]).unwrap();
+		let mut roc2 = RateOfChange::new(11, &src[0]).unwrap();
+		let mut wma = WMA::new(10, &0.0).unwrap();
+
+		let mut coppock = CoppockCurve::default().init(&candles[0]).unwrap();
+
+		for (candle, x) in candles.iter().zip(&src) {
+			let value = method.next(x);
+
+			let expected = wma.next(&(roc1.next(x) + roc2.next(x)));
+
+			assert_eq_float(expected, value);
+			assert_eq_float(coppock.next(candle).value(0), value);
+		}
+	}
+}
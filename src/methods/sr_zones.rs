@@ -0,0 +1,318 @@
+use crate::core::Method;
+use crate::core::{Action, Error, PeriodType, ValueType};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Support/resistance zone found by [`SRZones`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SRZone {
+	/// Average price of all the pivots merged into the zone
+	pub price: ValueType,
+	/// The lowest pivot price merged into the zone
+	pub low: ValueType,
+	/// The highest pivot price merged into the zone
+	pub high: ValueType,
+	/// Count of pivots merged into the zone
+	pub touches: usize,
+	last_touch: usize,
+}
+
+impl SRZone {
+	const fn new(price: ValueType, index: usize) -> Self {
+		Self {
+			price,
+			low: price,
+			high: price,
+			touches: 1,
+			last_touch: index,
+		}
+	}
+
+	fn touch(&mut self, price: ValueType, index: usize) {
+		self.touches += 1;
+		self.price += (price - self.price) / self.touches as ValueType;
+		self.low = self.low.min(price);
+		self.high = self.high.max(price);
+		self.last_touch = index;
+	}
+}
+
+/// Clusters confirmed pivot prices into support/resistance zones
+///
+/// Consumes outputs of [`UpperReversalSignal`] and [`LowerReversalSignal`] (or [`ReversalSignal`]) together with the price of the pivot.
+/// Every input with a signal (any [`Action`] except [`Action::None`]) is treated as a confirmed pivot at the given price.
+/// Inputs without a signal are ignored.
+///
+/// Pivot is merged into the nearest existing zone if its price is within the `tolerance` of the zone's price:
+///
+/// |`pivot price` - `zone price`| <= `tolerance` \* |`zone price`|
+///
+/// Otherwise a new zone is created. Zone's price is the average price of all the pivots merged into it.
+///
+/// When there are already `capacity` zones, the least recently touched zone is dropped to make room for a new one.
+///
+/// Active zones are available through [`SRZones::zones`], sorted by touches count from the most touched to the least.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`tolerance`: [`ValueType`], `capacity`: [`PeriodType`]\)
+///
+/// `tolerance` is a relative clustering distance, f.e. `0.005` merges pivots within `0.5%` of the zone's price. It should be >= `0.0`
+///
+/// `capacity` is the maximum count of active zones. It should be > `0`
+///
+/// # Input type
+///
+/// Input type is a tuple of \(`signal`: [`Action`], `price`: [`ValueType`]\)
+///
+/// Note that reversal signals are produced `right` bars after the pivot itself, so `price` should be the pivot bar's price
+/// (f.e. [`Past`](crate::methods::Past) of the `high` or `low`).
+///
+/// # Output type
+///
+/// Output type is [`Option`] of [`SRZone`] which was created or touched by the current pivot. If there is no pivot, returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::core::Action;
+/// use yata::methods::SRZones;
+///
+/// let mut zones = SRZones::new((0.005, 10), &(Action::None, 0.0)).unwrap();
+///
+/// zones.next(&(Action::BUY_ALL, 100.0));
+/// zones.next(&(Action::None, 0.0));
+/// zones.next(&(Action::BUY_ALL, 110.0));
+/// let zone = zones.next(&(Action::BUY_ALL, 100.2)).unwrap();
+///
+/// assert_eq!(zone.touches, 2);
+/// assert_eq!(zones.zones().len(), 2);
+/// assert_eq!(zones.zones()[0].touches, 2);
+/// ```
+///
+/// # Performance
+///
+/// O(`capacity`) for each pivot, O(1) for each input without a pivot
+///
+/// # See also
+///
+/// [`PivotReversalStrategy`](crate::indicators::PivotReversalStrategy)
+///
+/// [`UpperReversalSignal`]: crate::methods::UpperReversalSignal
+/// [`LowerReversalSignal`]: crate::methods::LowerReversalSignal
+/// [`ReversalSignal`]: crate::methods::ReversalSignal
+/// [`Action`]: crate::core::Action
+/// [`Action::None`]: crate::core::Action::None
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "PriceActionZone")]
+#[doc(alias = "SupportResistance")]
+pub struct SRZones {
+	tolerance: ValueType,
+	capacity: usize,
+	index: usize,
+	/// always sorted by touches count, most touched first
+	zones: Vec<SRZone>,
+}
+
+impl SRZones {
+	/// Returns active zones sorted by touches count from the most touched to the least
+	///
+	/// Zones with equal touches count are ordered from the oldest to the newest.
+	#[must_use]
+	pub fn zones(&self) -> &[SRZone] {
+		&self.zones
+	}
+
+	fn find(&self, price: ValueType) -> Option<usize> {
+		self.zones
+			.iter()
+			.enumerate()
+			.map(|(i, zone)| {
+				(
+					i,
+					(price - zone.price).abs(),
+					zone.price.abs() * self.tolerance,
+				)
+			})
+			.filter(|&(_, distance, tolerance)| distance <= tolerance)
+			.min_by(|a, b| a.1.total_cmp(&b.1))
+			.map(|(i, ..)| i)
+	}
+
+	fn touch(&mut self, mut i: usize, price: ValueType) -> SRZone {
+		self.zones[i].touch(price, self.index);
+
+		while i > 0 && self.zones[i - 1].touches < self.zones[i].touches {
+			self.zones.swap(i - 1, i);
+			i -= 1;
+		}
+
+		self.zones[i]
+	}
+
+	fn insert(&mut self, price: ValueType) -> SRZone {
+		if self.zones.len() == self.capacity {
+			let stale = self
+				.zones
+				.iter()
+				.enumerate()
+				.min_by_key(|(_, zone)| zone.last_touch)
+				.map(|(i, _)| i);
+
+			if let Some(i) = stale {
+				self.zones.remove(i);
+			}
+		}
+
+		let zone = SRZone::new(price, self.index);
+		self.zones.push(zone);
+
+		zone
+	}
+}
+
+impl Method for SRZones {
+	type Params = (ValueType, PeriodType);
+	type Input = (Action, ValueType);
+	type Output = Option<SRZone>;
+
+	fn new((tolerance, capacity): Self::Params, _: &Self::Input) -> Result<Self, Error> {
+		if !(tolerance >= 0.0 && tolerance.is_finite()) || capacity == 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			tolerance,
+			capacity: capacity as usize,
+			index: 0,
+			zones: Vec::with_capacity(capacity as usize),
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &(signal, price): &Self::Input) -> Self::Output {
+		if signal.is_none() {
+			return None;
+		}
+
+		self.index += 1;
+
+		let zone = match self.find(price) {
+			Some(i) => self.touch(i, price),
+			None => self.insert(price),
+		};
+
+		Some(zone)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, SRZones as TestingMethod};
+	use crate::core::{Action, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::{LowerReversalSignal, Past, UpperReversalSignal};
+
+	#[test]
+	fn test_sr_zones_merge() {
+		let mut method = TestingMethod::new((0.005, 10), &(Action::None, 0.0)).unwrap();
+
+		let zone = method.next(&(Action::BUY_ALL, 100.0)).unwrap();
+		assert_eq!(zone.touches, 1);
+
+		assert!(method.next(&(Action::None, 100.1)).is_none());
+
+		let zone = method.next(&(Action::BUY_ALL, 100.2)).unwrap();
+		assert_eq!(zone.touches, 2);
+		assert_eq_float(100.1, zone.price);
+		assert_eq_float(100.0, zone.low);
+		assert_eq_float(100.2, zone.high);
+
+		assert_eq!(method.zones().len(), 1);
+		assert_eq!(method.zones()[0], zone);
+	}
+
+	#[test]
+	fn test_sr_zones_sorted() {
+		let mut method = TestingMethod::new((0.005, 10), &(Action::None, 0.0)).unwrap();
+
+		for &price in &[100.0, 110.0, 120.0, 110.2, 119.9, 120.1] {
+			let _ = method.next(&(Action::BUY_ALL, price));
+		}
+
+		let touches: Vec<_> = method.zones().iter().map(|zone| zone.touches).collect();
+		assert_eq!(touches, [3, 2, 1]);
+
+		let prices: Vec<_> = method
+			.zones()
+			.iter()
+			.map(|zone| zone.price.round())
+			.collect();
+		assert_eq!(prices, [120.0, 110.0, 100.0]);
+	}
+
+	#[test]
+	fn test_sr_zones_capacity() {
+		let mut method = TestingMethod::new((0.001, 2), &(Action::None, 0.0)).unwrap();
+
+		let _ = method.next(&(Action::BUY_ALL, 100.0));
+		let _ = method.next(&(Action::BUY_ALL, 110.0));
+		let _ = method.next(&(Action::BUY_ALL, 100.0));
+		let _ = method.next(&(Action::BUY_ALL, 120.0));
+
+		// zone at 110 is the least recently touched one
+		let prices: Vec<_> = method.zones().iter().map(|zone| zone.price).collect();
+		assert_eq!(prices, [100.0, 120.0]);
+	}
+
+	#[test]
+	fn test_sr_zones_pivots() {
+		let candles: Vec<_> = RandomCandles::default().take(300).collect();
+		let (left, right) = (3, 2);
+
+		let mut ph = UpperReversalSignal::new(left, right, &candles[0].high).unwrap();
+		let mut pl = LowerReversalSignal::new(left, right, &candles[0].low).unwrap();
+		let mut past_high = Past::new(right, &candles[0].high).unwrap();
+		let mut past_low = Past::new(right, &candles[0].low).unwrap();
+
+		let mut method = TestingMethod::new((0.01, 255), &(Action::None, 0.0)).unwrap();
+		let mut pivots = 0;
+
+		for candle in &candles {
+			let high = (ph.next(&candle.high), past_high.next(&candle.high));
+			let low = (pl.next(&candle.low), past_low.next(&candle.low));
+
+			pivots += usize::from(high.0.is_some()) + usize::from(low.0.is_some());
+
+			for input in [high, low] {
+				if let Some(zone) = method.next(&input) {
+					assert!(zone.low <= input.1 && input.1 <= zone.high);
+				}
+			}
+		}
+
+		let zones = method.zones();
+		assert!(pivots > 0);
+		assert_eq!(zones.iter().map(|zone| zone.touches).sum::<usize>(), pivots);
+		assert!(zones.windows(2).all(|w| w[0].touches >= w[1].touches));
+
+		for zone in zones {
+			assert!(zone.low <= zone.price && zone.price <= zone.high);
+		}
+	}
+
+	#[test]
+	fn test_sr_zones_wrong_params() {
+		let input = (Action::None, 0.0);
+
+		assert!(TestingMethod::new((-0.1, 10), &input).is_err());
+		assert!(TestingMethod::new((ValueType::NAN, 10), &input).is_err());
+		assert!(TestingMethod::new((0.01, 0), &input).is_err());
+	}
+}
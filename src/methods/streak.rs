@@ -0,0 +1,169 @@
+use crate::core::Method;
+use crate::core::{Error, ValueType};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Counts current run length of consecutive rising or falling values for timeseries of type [`ValueType`]
+///
+/// When value is greater than the previous one, the streak increases if it is positive, otherwise it resets to `1`.
+///
+/// When value is lower than the previous one, the streak decreases if it is negative, otherwise it resets to `-1`.
+///
+/// When value is equal to the previous one, the streak resets to `0`.
+///
+/// It is used f.e. in [Connors RSI](https://alvarezquanttrading.com/wp-content/uploads/2016/05/ConnorsRSIGuidebook.pdf).
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always an integer number
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::Streak;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 1.0, 2.0];
+/// let r      = [0.0, 1.0, 2.0, -1.0, -2.0, 0.0, 1.0];
+///
+/// let mut streak = Streak::new((), &values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = streak.next(&values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Streak {
+	last_value: ValueType,
+	streak: ValueType,
+}
+
+impl Method for Streak {
+	type Params = ();
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((): Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			last_value: value,
+			streak: 0.0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		self.streak = if value > self.last_value {
+			self.streak.max(0.0) + 1.0
+		} else if value < self.last_value {
+			self.streak.min(0.0) - 1.0
+		} else {
+			0.0
+		};
+
+		self.last_value = value;
+
+		self.streak
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for Streak {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.streak
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, Streak as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_streak_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((), &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(0.0, output);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_streak_sequence() {
+		let values = [5.0, 6.0, 7.0, 6.5, 6.0, 6.0, 6.0, 5.0, 8.0];
+		let expected = [0.0, 1.0, 2.0, -1.0, -2.0, 0.0, 0.0, -1.0, 1.0];
+
+		let mut method = TestingMethod::new((), &values[0]).unwrap();
+
+		for (value, &expected) in values.iter().zip(&expected) {
+			assert_eq_float(expected, method.next(value));
+		}
+	}
+
+	#[test]
+	fn test_streak_seed() {
+		let mut method = TestingMethod::new((), &1.0).unwrap();
+
+		assert_eq_float(1.0, method.next(&2.0));
+
+		let mut method = TestingMethod::new((), &3.0).unwrap();
+
+		assert_eq_float(-1.0, method.next(&2.0));
+	}
+
+	#[test]
+	fn test_streak() {
+		let candles = RandomCandles::default();
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		let mut method = TestingMethod::new((), &src[0]).unwrap();
+		let mut prev_streak = 0.0;
+
+		for (i, x) in src.iter().enumerate() {
+			let value = method.next(x);
+			let prev = src[i.saturating_sub(1)];
+
+			let expected = if *x > prev {
+				if prev_streak > 0.0 {
+					prev_streak + 1.0
+				} else {
+					1.0
+				}
+			} else if *x < prev {
+				if prev_streak < 0.0 {
+					prev_streak - 1.0
+				} else {
+					-1.0
+				}
+			} else {
+				0.0
+			};
+
+			assert_eq_float(expected, value);
+			prev_streak = expected;
+		}
+	}
+}
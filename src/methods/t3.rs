@@ -0,0 +1,197 @@
+use crate::core::{Error, PeriodType, ValueType};
+use crate::core::{Method, MovingAverage};
+use crate::helpers::Peekable;
+use crate::methods::EMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tillson T3 moving average of specified `length` for timeseries of type [`ValueType`]
+///
+/// T3 is a six-fold smoothed [`EMA`]. It is built from three stages of generalized [`DEMA`](crate::methods::DEMA) (GD):
+///
+/// GD\(`x`\) = \(`1` + `v`\) \* [`EMA`]\(`x`\) - `v` \* [`EMA`]\([`EMA`]\(`x`\)\)
+///
+/// T3 = GD\(GD\(GD\(`x`\)\)\)
+///
+/// where `v` is a `volume_factor`. After expanding it becomes a weighted sum of the last four of six chained [`EMA`]s `e3`..`e6`:
+///
+/// T3 = `c1` \* `e6` + `c2` \* `e5` + `c3` \* `e4` + `c4` \* `e3`
+///
+/// * `c1` = -`v`³
+/// * `c2` = `3v`² + `3v`³
+/// * `c3` = -`6v`² - `3v` - `3v`³
+/// * `c4` = `1` + `3v` + `v`³ + `3v`²
+///
+/// Sum of all the coefficients is always equal to `1`.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `volume_factor`: [`ValueType`]\)
+///
+/// `length` should be > `0`
+///
+/// `volume_factor` should be in range \[`0.0`; `1.0`\]. Usual value is `0.7`.
+/// When `volume_factor` is `0.0`, T3 becomes the same as [`TMA`](crate::methods::TMA).
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::T3;
+///
+/// let mut t3 = T3::new((5, 0.7), &1.0).unwrap();
+///
+/// assert!((t3.next(&1.0) - 1.0).abs() < 1e-5);
+/// assert!(t3.next(&2.0) > 1.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`EMA`], [`DEMA`](crate::methods::DEMA), [`TEMA`](crate::methods::TEMA)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "Tillson")]
+pub struct T3 {
+	emas: [EMA; 6],
+	values: [ValueType; 6],
+	c: [ValueType; 4],
+}
+
+impl Method for T3 {
+	type Params = (PeriodType, ValueType);
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new((length, volume_factor): Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		if !(0.0..=1.0).contains(&volume_factor) {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let ema = EMA::new(length, &value)?;
+
+		let v = volume_factor;
+		let v2 = v * v;
+		let v3 = v2 * v;
+
+		Ok(Self {
+			emas: [ema; 6],
+			values: [value; 6],
+			c: [
+				-v3,
+				3.0 * (v2 + v3),
+				-3.0 * v2.mul_add(2.0, v + v3),
+				(v + v2).mul_add(3.0, 1.0 + v3),
+			],
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		let mut value = *value;
+
+		for (ema, output) in self.emas.iter_mut().zip(self.values.iter_mut()) {
+			value = ema.next(&value);
+			*output = value;
+		}
+
+		self.peek()
+	}
+}
+
+impl MovingAverage for T3 {}
+
+impl Peekable<<Self as Method>::Output> for T3 {
+	fn peek(&self) -> <Self as Method>::Output {
+		let [_, _, e3, e4, e5, e6] = self.values;
+		let [c1, c2, c3, c4] = self.c;
+
+		c1.mul_add(e6, c2.mul_add(e5, c3.mul_add(e4, c4 * e3)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, T3 as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{DEMA, TMA};
+
+	#[test]
+	fn test_t3_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new((i, 0.7), &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(input, output);
+			test_const_float(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_t3_tma() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in 1..30 {
+			let mut t3 = TestingMethod::new((length, 0.0), &src[0]).unwrap();
+			let mut tma = TMA::new(length, &src[0]).unwrap();
+
+			for x in &src {
+				assert_eq_float(tma.next(x), t3.next(x));
+			}
+		}
+	}
+
+	#[test]
+	fn test_t3_smoother_than_dema() {
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		// sum of absolute second differences measures how "curved" the output is
+		let roughness = |values: &[ValueType]| -> ValueType {
+			values
+				.windows(3)
+				.map(|w| w[1].mul_add(-2.0, w[2] + w[0]).abs())
+				.sum()
+		};
+
+		for length in [5, 10, 20, 50] {
+			let mut t3 = TestingMethod::new((length, 0.7), &src[0]).unwrap();
+			let mut dema = DEMA::new(length, &src[0]).unwrap();
+
+			let t3_values: Vec<_> = src.iter().map(|x| t3.next(x)).collect();
+			let dema_values: Vec<_> = src.iter().map(|x| dema.next(x)).collect();
+
+			assert!(roughness(&t3_values) < roughness(&dema_values));
+		}
+	}
+
+	#[test]
+	fn test_t3_wrong_params() {
+		assert!(TestingMethod::new((0, 0.7), &1.0).is_err());
+		assert!(TestingMethod::new((10, -0.1), &1.0).is_err());
+		assert!(TestingMethod::new((10, 1.1), &1.0).is_err());
+	}
+}
@@ -0,0 +1,253 @@
+use crate::core::{Error, Method, PeriodType, ValueType, Window, OHLCV};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct TopItem {
+	volume: ValueType,
+	index: usize,
+}
+
+impl TopItem {
+	/// Checks if `self` goes before `other` in the top: greater volume first, the newer bar first on equal volumes
+	fn is_above(&self, other: &Self) -> bool {
+		self.volume
+			.total_cmp(&other.volume)
+			.then(self.index.cmp(&other.index))
+			.is_gt()
+	}
+}
+
+/// Searches for `k` highest-volume bars over the last `length` [`OHLCV`]s
+///
+/// Bars with equal volumes are ranked from the newest to the oldest, so the current bar always wins a tie.
+///
+/// Only `k` largest volumes are kept sorted, so the whole window is never sorted.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`length`: [`PeriodType`], `k`: [`PeriodType`]\)
+///
+/// `length` should be > `0`
+///
+/// `k` should be > `0` and <= `length`
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is a tuple of \(`sum`: [`ValueType`], `is_top`: [`bool`]\),
+/// where `sum` is the sum of `k` highest volumes inside the window
+/// and `is_top` is `true` when the current bar is one of the top `k` bars.
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::TopKVolume;
+///
+/// let candle = |volume| Candle { volume, ..Candle::default() };
+///
+/// let mut top = TopKVolume::new((4, 2), &candle(1.0)).unwrap();
+///
+/// assert_eq!(top.next(&candle(5.0)), (6.0, true));
+/// assert_eq!(top.next(&candle(3.0)), (8.0, true));
+/// assert_eq!(top.next(&candle(2.0)), (8.0, false));
+/// assert_eq!(top.next(&candle(1.0)), (8.0, false));
+/// assert_eq!(top.next(&candle(1.0)), (5.0, false)); // `5.0` has left the window
+/// ```
+///
+/// # Performance
+///
+/// O(`k`) per bar, when the bar leaving the window is not in the top.
+///
+/// O(`length` \* `k`) per bar, when the bar leaving the window is in the top, so the top has to be refilled from the whole window.
+///
+/// # See also
+///
+/// [`Highest`](crate::methods::Highest)
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TopKVolume {
+	k: usize,
+	index: usize,
+	window: Window<ValueType>,
+	/// sorted from the top to the bottom, up to `k` items
+	top: Vec<TopItem>,
+	last_value: (ValueType, bool),
+}
+
+impl TopKVolume {
+	fn insert(top: &mut Vec<TopItem>, k: usize, item: TopItem) -> bool {
+		let position = top.iter().position(|x| item.is_above(x));
+
+		match position {
+			Some(position) => {
+				top.insert(position, item);
+				top.truncate(k);
+				true
+			}
+			None if top.len() < k => {
+				top.push(item);
+				true
+			}
+			None => false,
+		}
+	}
+
+	fn refill(&mut self) {
+		self.top.clear();
+
+		for (age, &volume) in self.window.iter().enumerate() {
+			let item = TopItem {
+				volume,
+				index: self.index - age,
+			};
+
+			let _ = Self::insert(&mut self.top, self.k, item);
+		}
+	}
+
+	fn sum(&self) -> ValueType {
+		self.top.iter().map(|x| x.volume).sum()
+	}
+}
+
+impl Method for TopKVolume {
+	type Params = (PeriodType, PeriodType);
+	type Input = dyn OHLCV;
+	type Output = (ValueType, bool);
+
+	fn new((length, k): Self::Params, candle: &Self::Input) -> Result<Self, Error> {
+		if length == 0 || k == 0 || k > length {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		let mut method = Self {
+			k: k as usize,
+			index: length as usize - 1,
+			window: Window::new(length, candle.volume()),
+			top: Vec::with_capacity(k as usize + 1),
+			last_value: (0.0, false),
+		};
+
+		method.refill();
+		method.last_value = (method.sum(), true);
+
+		Ok(method)
+	}
+
+	#[inline]
+	fn next(&mut self, candle: &Self::Input) -> Self::Output {
+		let volume = candle.volume();
+
+		let _ = self.window.push(volume);
+		self.index += 1;
+
+		let expired = self.index - self.window.len() as usize;
+		let top_length = self.top.len();
+		self.top.retain(|x| x.index != expired);
+
+		let is_top = if self.top.len() == top_length {
+			let item = TopItem {
+				volume,
+				index: self.index,
+			};
+
+			Self::insert(&mut self.top, self.k, item)
+		} else {
+			self.refill();
+			self.top.iter().any(|x| x.index == self.index)
+		};
+
+		self.last_value = (self.sum(), is_top);
+
+		self.last_value
+	}
+
+	fn output_name(&self, index: usize) -> Option<&'static str> {
+		["sum", "is_top"].get(index).copied()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for TopKVolume {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.last_value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, TopKVolume as TestingMethod};
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles};
+
+	#[test]
+	fn test_top_k_volume_const() {
+		for length in 1..100 {
+			for k in [1, length / 2, length] {
+				if k == 0 {
+					continue;
+				}
+
+				let candle = Candle {
+					volume: (length as ValueType + 56.0) / 16.3251,
+					..Candle::default()
+				};
+				let mut method = TestingMethod::new((length, k), &candle).unwrap();
+
+				for _ in 0..100 {
+					let (sum, is_top) = method.next(&candle);
+					assert_eq_float(candle.volume * k as ValueType, sum);
+					assert!(is_top);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_top_k_volume() {
+		let candles: Vec<_> = RandomCandles::default().take(300).collect();
+
+		for length in 1..30 {
+			for k in 1..=length.min(6) {
+				let mut method = TestingMethod::new((length, k), &candles[0]).unwrap();
+				let (length, k) = (length as usize, k as usize);
+
+				for i in 0..candles.len() {
+					let (sum, is_top) = method.next(&candles[i]);
+
+					// (volume, age) pairs sorted by volume descending, the newest first on ties
+					let mut window: Vec<_> = (0..length)
+						.map(|age| (candles[i.saturating_sub(age)].volume, age))
+						.collect();
+					window.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+
+					let expected_sum: ValueType = window.iter().take(k).map(|x| x.0).sum();
+					let expected_is_top = window.iter().take(k).any(|x| x.1 == 0);
+
+					assert_eq_float(expected_sum, sum);
+					assert_eq!(expected_is_top, is_top, "length={length}, k={k}, i={i}");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_top_k_volume_wrong_params() {
+		let candle = Candle::default();
+
+		assert!(TestingMethod::new((0, 1), &candle).is_err());
+		assert!(TestingMethod::new((10, 0), &candle).is_err());
+		assert!(TestingMethod::new((10, 11), &candle).is_err());
+	}
+}
@@ -5,6 +5,15 @@ use serde::{Deserialize, Serialize};
 
 /// [True Range](https://en.wikipedia.org/wiki/Average_true_range)
 ///
+/// True range is max(`high` - `low`, |`high` - `previous close`|, |`low` - `previous close`|).
+///
+/// Previous close for the first candle is the close of the initial candle, so when the first candle is the same as the initial one,
+/// its true range is just `high` - `low`.
+///
+/// It is a building block for all the ATR-based indicators, such as
+/// [`AverageDirectionalIndex`](crate::indicators::AverageDirectionalIndex),
+/// [`KeltnerChannel`](crate::indicators::KeltnerChannel) and [`ChandeKrollStop`](crate::indicators::ChandeKrollStop).
+///
 /// # Parameters
 ///
 /// Has no parameters
@@ -17,6 +26,22 @@ use serde::{Deserialize, Serialize};
 ///
 /// Output type is [`ValueType`]
 ///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::TrueRange;
+///
+/// let candle = Candle { open: 10.0, high: 12.0, low: 9.0, close: 11.0, volume: 1.0 };
+/// let mut tr = TrueRange::new(&candle).unwrap();
+///
+/// assert_eq!(tr.next(&candle), 3.0);
+///
+/// // gap up: previous close is below the current range
+/// let gap = Candle { open: 14.0, high: 15.0, low: 13.5, close: 14.5, volume: 1.0 };
+/// assert_eq!(tr.next(&gap), 4.0);
+/// ```
+///
 /// # Performance
 ///
 /// O(1)
@@ -37,6 +62,9 @@ pub struct TR {
 	prev_close: ValueType,
 }
 
+/// Just an alias for TR
+pub type TrueRange = TR;
+
 impl TR {
 	/// Creates new TR method instance
 	/// It's a simple shortcut for [`Method::new`](crate::core::Method::new) method.
@@ -69,6 +97,7 @@ impl Method for TR {
 #[cfg(test)]
 mod tests {
 	use super::{Method, OHLCV, TR as TestingMethod};
+	use crate::core::Candle;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
 
@@ -83,6 +112,32 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_tr_hand_computed() {
+		let candle = |high, low, close| Candle {
+			open: close,
+			high,
+			low,
+			close,
+			volume: 1.0,
+		};
+
+		let src = [
+			(candle(10.0, 8.0, 9.0), 2.0),   // first candle: just high - low
+			(candle(10.5, 9.5, 10.0), 1.5),  // |high - prev close| = 1.5 > high - low
+			(candle(13.0, 12.0, 12.5), 3.0), // gap up: prev close is below the range
+			(candle(12.8, 12.1, 12.2), 0.7), // prev close is inside the range
+			(candle(9.0, 8.5, 8.8), 3.7),    // gap down: prev close is above the range
+			(candle(9.2, 7.0, 8.0), 2.2),    // high - low is the largest
+		];
+
+		let mut method = TestingMethod::new(&src[0].0).unwrap();
+
+		for (candle, expected) in &src {
+			assert_eq_float(*expected, method.next(candle));
+		}
+	}
+
 	#[test]
 	fn test_tr() {
 		let candles = RandomCandles::default();
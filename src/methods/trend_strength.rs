@@ -0,0 +1,208 @@
+use crate::core::{Error, Method, PeriodType, ValueType};
+use crate::helpers::Peekable;
+use crate::methods::LinReg;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Slope of the [linear regression](https://en.wikipedia.org/wiki/Linear_regression) line over the last `length` values
+/// normalized by the mean value over the same window, in percents per bar
+///
+/// `output` = `slope` / `mean` * `100`
+///
+/// Unlike the raw slope ([`LinReg::tan`]) it does not depend on the price level, so it is comparable across instruments.
+///
+/// When the mean value is zero (up to float rounding errors), the output is undefined, so returns [`NaN`](ValueType::NAN)
+/// (f.e. for the values `-1.0`, `0.0`, `1.0`, which do have a slope, but have no level to normalize it by).
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `1`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::TrendStrength;
+///
+/// let mut trend = TrendStrength::new(3, &9.0).unwrap();
+///
+/// let _ = trend.next(&10.0);
+/// // slope is 1.0 per bar, mean is 10.0
+/// assert!((trend.next(&11.0) - 10.0).abs() < 1e-6);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`LinReg`]
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+/// [`LinReg`]: crate::methods::LinReg
+/// [`LinReg::tan`]: crate::methods::LinReg::tan
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrendStrength {
+	lin_reg: LinReg,
+	/// sum of absolute values over the window
+	abs_sum: ValueType,
+}
+
+impl TrendStrength {
+	fn value(&self) -> ValueType {
+		let mean = self.lin_reg.mean();
+
+		// positive and negative values may cancel each other out only up to float rounding errors
+		if mean.abs() <= self.abs_sum * ValueType::EPSILON {
+			ValueType::NAN
+		} else {
+			self.lin_reg.tan() / mean * 100.0
+		}
+	}
+}
+
+impl Method for TrendStrength {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			lin_reg: LinReg::new(length, value)?,
+			abs_sum: value.abs() * length as ValueType,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.abs_sum += value.abs() - self.lin_reg.oldest().abs();
+		let _ = self.lin_reg.next(value);
+
+		self.value()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for TrendStrength {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.value()
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::suboptimal_flops)]
+mod tests {
+	use super::{Method, TrendStrength as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, Peekable, RandomCandles};
+	use crate::methods::tests::test_const_float;
+
+	#[test]
+	fn test_trend_strength_const() {
+		for i in 2..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(0.0, output);
+			test_const_float(&mut method, &input, output);
+		}
+	}
+
+	#[test]
+	fn test_trend_strength_zero_mean() {
+		let mut method = TestingMethod::new(4, &0.0).unwrap();
+
+		assert!(method.next(&0.0).is_nan());
+
+		let mut method = TestingMethod::new(3, &0.0).unwrap();
+		for x in [-1.0, 0.0, 1.0] {
+			let _ = method.next(&x);
+		}
+		// slope is `1.0` per bar, but there is no level to normalize it by
+		assert!(method.peek().is_nan());
+
+		for x in [0.1, 0.2, -0.3] {
+			let _ = method.next(&x);
+		}
+		// `0.1 + 0.2 - 0.3` is not exactly zero
+		assert!(method.peek().is_nan());
+
+		assert!(method.next(&1.0).is_finite());
+	}
+
+	#[test]
+	fn test_trend_strength_percent() {
+		let growth: ValueType = 1.01;
+		let src: Vec<ValueType> = (0..100).map(|i| 100.0 * growth.powi(i)).collect();
+
+		for length in [2, 5, 10, 20] {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+
+			for (i, x) in src.iter().enumerate() {
+				let value = method.next(x);
+
+				if i >= length as usize {
+					assert!(
+						(value - 1.0).abs() < 0.01,
+						"{value} at {i} with length {length}"
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	#[cfg_attr(not(feature = "value_type_f32"), allow(clippy::useless_conversion))]
+	fn test_trend_strength() {
+		// running sums of `LinReg` accumulate rounding errors with `f32`, so the reference is evaluated in `f64`
+		const SIGMA: f64 = if cfg!(feature = "value_type_f32") {
+			5e-2
+		} else {
+			1e-8
+		};
+
+		let src: Vec<ValueType> = RandomCandles::default()
+			.take(300)
+			.map(|x| x.close)
+			.collect();
+
+		for length in [2, 3, 5, 10, 21, 50, 100] {
+			let mut method = TestingMethod::new(length, &src[0]).unwrap();
+			let length = length as usize;
+			let n = length as f64;
+
+			for (i, x) in src.iter().enumerate() {
+				let value = f64::from(method.next(x));
+
+				let points: Vec<(f64, f64)> = (0..length)
+					.map(|j| (-(j as f64), f64::from(src[i.saturating_sub(j)])))
+					.collect();
+				let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+				let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+				let cov: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+				let var: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+
+				let expected = cov / var / mean_y * 100.0;
+
+				assert!(
+					(expected - value).abs() <= SIGMA * expected.abs().max(1.0),
+					"{expected} != {value} at {i} with length {length}"
+				);
+			}
+		}
+	}
+}
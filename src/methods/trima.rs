@@ -84,9 +84,98 @@ impl Buffered<<Self as Method>::Output> for TRIMA {
 	}
 }
 
+/// Triangular Moving Average of specified `length` for timeseries of type [`ValueType`] with the standard period split
+///
+/// It is an [`SMA`] of an [`SMA`], where lengths of both stages are chosen so the total window is exactly `length` values:
+///
+/// * when `length` is odd, both stages have length \(`length` + `1`\) / `2`;
+/// * when `length` is even, the first stage has length `length` / `2` + `1` and the second one has length `length` / `2`.
+///
+/// So the weights of the values inside the window form a triangle: `1`, `2`, ..., `2`, `1`.
+///
+/// Unlike [`TRIMA`], which applies [`SMA`] of the same `length` twice and so covers `2` \* `length` - `1` values.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::TriangularMA;
+///
+/// // weights are 1, 2, 2, 1
+/// let mut tma = TriangularMA::new(4, &0.0).unwrap();
+///
+/// tma.next(&6.0);
+/// tma.next(&12.0);
+///
+/// assert_eq!(tma.next(&18.0), (18.0 * 1.0 + 12.0 * 2.0 + 6.0 * 2.0) / 6.0);
+/// assert_eq!(tma.next(&24.0), (24.0 * 1.0 + 18.0 * 2.0 + 12.0 * 2.0 + 6.0 * 1.0) / 6.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`TRIMA`], [`TMA`](crate::methods::TMA) (which is a triple exponential moving average)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "Tma")]
+pub struct TriangularMA {
+	sma1: SMA,
+	sma2: SMA,
+}
+
+impl Method for TriangularMA {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		if length == 0 {
+			return Err(Error::WrongMethodParameters);
+		}
+
+		Ok(Self {
+			sma1: SMA::new(length / 2 + 1, value)?,
+			sma2: SMA::new(length / 2 + length % 2, value)?,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, value: &Self::Input) -> Self::Output {
+		self.sma2.next(&self.sma1.next(value))
+	}
+}
+
+impl MovingAverage for TriangularMA {}
+
+impl Peekable<<Self as Method>::Output> for TriangularMA {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.sma2.peek()
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{Method, TRIMA as TestingMethod};
+	use super::{Method, TriangularMA, TRIMA as TestingMethod};
 	use crate::core::ValueType;
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
@@ -104,7 +193,7 @@ mod tests {
 
 	#[test]
 	fn test_trima1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
@@ -145,4 +234,48 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn test_triangular_ma_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TriangularMA::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_triangular_ma() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(300).map(|x| x.close).collect();
+
+		(1..255).for_each(|length| {
+			let mut ma = TriangularMA::new(length, &src[0]).unwrap();
+			let length = length as usize;
+
+			// triangular weights from the newest value to the oldest: 1, 2, ..., 2, 1
+			let weights: Vec<ValueType> = (0..length)
+				.map(|i| (i + 1).min(length - i).min(length.div_ceil(2)) as ValueType)
+				.collect();
+			let weights_sum: ValueType = weights.iter().sum();
+
+			src.iter().enumerate().for_each(|(i, x)| {
+				let sum: ValueType = weights
+					.iter()
+					.enumerate()
+					.map(|(age, w)| w * src[i.saturating_sub(age)])
+					.sum();
+
+				assert_eq_float(sum / weights_sum, ma.next(x));
+			});
+		});
+	}
+
+	#[test]
+	fn test_triangular_ma_wrong_params() {
+		assert!(TriangularMA::new(0, &1.0).is_err());
+	}
 }
@@ -97,8 +97,8 @@ impl Method for TSI {
 		let momentum = value - self.last_value;
 		self.last_value = value;
 
-		self.ema12.next(&self.ema11.next(&momentum));
-		self.ema22.next(&self.ema21.next(&momentum.abs()));
+		let _ = self.ema12.next(&self.ema11.next(&momentum));
+		let _ = self.ema22.next(&self.ema21.next(&momentum.abs()));
 
 		self.peek()
 	}
@@ -0,0 +1,141 @@
+use crate::core::Method;
+use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Counts how many of the last `length` values were greater than their previous values for timeseries of type [`ValueType`]
+///
+/// It is a breadth-like metric over a single timeseries. Ratio of up moves may be calculated as `count` / `length`.
+///
+/// Equal values are not counted as up moves. The very first value is compared to the initial value.
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// Output value is always an integer number in range \[`0`; `length`\]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::UpDownCount;
+///
+/// let values = [1.0, 2.0, 3.0, 2.0, 1.0, 1.0, 2.0];
+/// let r      = [0.0, 1.0, 2.0, 2.0, 1.0, 0.0, 1.0];
+///
+/// let mut count = UpDownCount::new(3, &values[0]).unwrap();
+///
+/// (0..values.len()).for_each(|i| {
+///     let v = count.next(&values[i]);
+///     assert_eq!(v, r[i]);
+/// });
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`Streak`](crate::methods::Streak)
+///
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UpDownCount {
+	last_value: ValueType,
+	window: Window<bool>,
+	count: PeriodType,
+}
+
+impl Method for UpDownCount {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, &value: &Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				last_value: value,
+				window: Window::new(length, false),
+				count: 0,
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let is_up = value > self.last_value;
+		self.last_value = value;
+
+		let was_up = self.window.push(is_up);
+		self.count = self.count + PeriodType::from(is_up) - PeriodType::from(was_up);
+
+		self.peek()
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for UpDownCount {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.count as ValueType
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, UpDownCount as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::assert_eq_float;
+	use crate::methods::tests::{test_const, test_method_against_naive};
+
+	#[test]
+	fn test_up_down_count_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			assert_eq_float(0.0, output);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_up_down_count_rising() {
+		let mut method = TestingMethod::new(5, &0.0).unwrap();
+
+		for i in 1..20 {
+			let expected = i.min(5) as ValueType;
+			assert_eq_float(expected, method.next(&(i as ValueType)));
+		}
+	}
+
+	#[test]
+	fn test_up_down_count() {
+		test_method_against_naive(
+			1..255,
+			TestingMethod::new,
+			|src, i, length| {
+				let from = (i + 1).saturating_sub(length).max(1);
+
+				(from..=i).filter(|&j| src[j] > src[j - 1]).count() as ValueType
+			},
+			0.0,
+		);
+	}
+}
@@ -1,18 +1,30 @@
 use crate::{
-	core::{Error, Method, MovingAverage, PeriodType, ValueType, Window},
+	core::{Error, Method, MovingAverage, PeriodType, ValueType},
 	helpers::Peekable,
+	methods::Cmo,
 };
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-/// [Variable Index Dynamic Average](https://www.metatrader5.com/en/terminal/help/indicators/trend_indicators/vida) of specified `length` for timeseries of type [`ValueType`]
+/// [Variable Index Dynamic Average](https://www.metatrader5.com/en/terminal/help/indicators/trend_indicators/vida) of specified `period` for timeseries of type [`ValueType`]
+///
+/// It is an [`EMA`](crate::methods::EMA) with the smoothing constant scaled by the absolute value of
+/// [Chande Momentum Oscillator](crate::methods::Cmo) over the last `cmo_period` changes:
+///
+/// `VIDYA` = `value` * `f` * |`CMO`| + `previous VIDYA` * (`1` - `f` * |`CMO`|), where `f` = `2` / (`period` + `1`).
+///
+/// So its effective period shrinks, when the timeseries moves in a single direction, and grows, when it moves sideways.
+/// When the timeseries moves in a single direction only, it is exactly the same as [`EMA`](crate::methods::EMA) of the same `period`.
+/// When the timeseries doesn't change at all over the last `cmo_period` values, it returns the current value.
 ///
 /// # Parameters
 ///
-/// Has a single parameter `length`: [`PeriodType`]
+/// Has a tuple of 2 parameters \(`period`: [`PeriodType`], `cmo_period`: [`PeriodType`]\)
 ///
-/// `length` must be > `0`
+/// `period` must be in range \[`1`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+///
+/// `cmo_period` must be > `0`
 ///
 /// # Input type
 ///
@@ -28,11 +40,11 @@ use serde::{Deserialize, Serialize};
 /// use yata::prelude::*;
 /// use yata::methods::Vidya;
 ///
-/// // Vidya with period length=3
-/// let mut vidya = Vidya::new(3, &1.0).unwrap();
+/// // Vidya with period=3 and CMO over last 5 changes
+/// let mut vidya = Vidya::new((3, 5), &1.0).unwrap();
 ///
-/// vidya.next(&3.0);
-/// vidya.next(&6.0);
+/// let _ = vidya.next(&3.0);
+/// let _ = vidya.next(&6.0);
 ///
 /// println!("{}", vidya.next(&9.0));
 /// println!("{}", vidya.next(&12.0));
@@ -42,6 +54,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// O\(1\)
 ///
+/// # See also
+///
+/// [`Cmo`](crate::methods::Cmo), [`EMA`](crate::methods::EMA)
+///
 /// [`ValueType`]: crate::core::ValueType
 /// [`PeriodType`]: crate::core::PeriodType
 #[derive(Debug, Clone)]
@@ -53,11 +69,8 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vidya {
 	f: ValueType,
-	up_sum: ValueType,
-	dn_sum: ValueType,
-	last_input: ValueType,
+	cmo: Cmo,
 	last_output: ValueType,
-	window: Window<ValueType>,
 }
 
 impl Vidya {
@@ -69,43 +82,30 @@ impl Vidya {
 }
 
 impl Method for Vidya {
-	type Params = PeriodType;
+	type Params = (PeriodType, PeriodType);
 	type Input = ValueType;
 	type Output = Self::Input;
 
-	fn new(length: Self::Params, &input: &Self::Input) -> Result<Self, Error> {
-		match length {
+	fn new((period, cmo_period): Self::Params, &input: &Self::Input) -> Result<Self, Error> {
+		match period {
 			0 | PeriodType::MAX => Err(Error::WrongMethodParameters),
-			length => Ok(Self {
-				f: 2. / (1 + length) as ValueType,
-				up_sum: 0.,
-				dn_sum: 0.,
-				last_input: input,
+			period => Ok(Self {
+				f: 2. / (1 + period) as ValueType,
+				cmo: Cmo::new(cmo_period, &input)?,
 				last_output: input,
-				window: Window::new(length, 0.),
 			}),
 		}
 	}
 
 	#[inline]
-	fn next(&mut self, &input: &Self::Input) -> Self::Output {
-		let change = input - self.last_input;
-		self.last_input = input;
+	fn next(&mut self, input: &Self::Input) -> Self::Output {
+		let cmo = self.cmo.next(input);
 
-		let left_change = self.window.push(change);
-
-		self.up_sum -= left_change * (left_change > 0.) as u8 as ValueType;
-		self.dn_sum += left_change * (left_change < 0.) as u8 as ValueType;
-
-		self.up_sum += change * (change > 0.) as u8 as ValueType;
-		self.dn_sum -= change * (change < 0.) as u8 as ValueType;
-
-		self.last_output = if self.up_sum != 0. || self.dn_sum != 0. {
-			let cmo = ((self.up_sum - self.dn_sum) / (self.up_sum + self.dn_sum)).abs();
-			let f_cmo = self.f * cmo;
-			input.mul_add(f_cmo, (1.0 - f_cmo) * self.last_output)
+		self.last_output = if self.cmo.is_flat() {
+			*input
 		} else {
-			input
+			let f_cmo = self.f * cmo.abs();
+			input.mul_add(f_cmo, (1.0 - f_cmo) * self.last_output)
 		};
 
 		self.last_output
@@ -126,12 +126,30 @@ mod tests {
 	use super::{Method, ValueType};
 	use crate::helpers::{assert_eq_float, RandomCandles};
 	use crate::methods::tests::test_const;
+	use crate::methods::EMA;
+
+	#[test]
+	fn test_vidya_flat_after_movement() {
+		let mut method = TestingMethod::new((3, 3), &1.1).unwrap();
+
+		for x in [2.3, 0.7, 3.9, 0.3] {
+			let _ = method.next(&x);
+		}
+
+		for _ in 0..3 {
+			let _ = method.next(&5.0);
+		}
+
+		for _ in 0..10 {
+			assert_eq_float(5.0, method.next(&5.0));
+		}
+	}
 
 	#[test]
 	fn test_vidya_const() {
 		for i in 1..255 {
 			let input = (i as ValueType + 56.0) / 16.3251;
-			let mut method = TestingMethod::new(i, &input).unwrap();
+			let mut method = TestingMethod::new((i, i), &input).unwrap();
 
 			let output = method.next(&input);
 			test_const(&mut method, &input, &output);
@@ -140,9 +158,9 @@ mod tests {
 
 	#[test]
 	fn test_vidya1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
-		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
+		let mut ma = TestingMethod::new((1, 1), &candles.first().close).unwrap();
 
 		candles.take(100).for_each(|x| {
 			assert_eq_float(x.close, ma.next(&x.close));
@@ -169,13 +187,13 @@ mod tests {
 			.map(|&x| if x < 0.0 { x.abs() } else { 0.0 })
 			.collect();
 
-		(1..255).for_each(|ma_length| {
-			let mut ma = TestingMethod::new(ma_length, &src[0]).unwrap();
-			let ma_length = ma_length as usize;
+		for (period, cmo_period) in (1..255).map(|i| (i, i)).chain([(5, 20), (20, 5), (10, 1)]) {
+			let mut ma = TestingMethod::new((period, cmo_period), &src[0]).unwrap();
+			let cmo_period = cmo_period as usize;
 
 			let mut value = src[0];
 			src.iter().enumerate().for_each(|(i, &x)| {
-				let from_slice = i.saturating_sub(ma_length - 1);
+				let from_slice = i.saturating_sub(cmo_period - 1);
 				let pos: ValueType = pos_change[from_slice..=i].iter().sum();
 				let neg: ValueType = neg_change[from_slice..=i].iter().sum();
 
@@ -183,13 +201,72 @@ mod tests {
 					x
 				} else {
 					let cmo = (pos - neg) / (pos + neg);
-					let f = 2.0 / (ma_length + 1) as ValueType;
+					let f = 2.0 / (period + 1) as ValueType;
 
 					x * f * cmo.abs() + value * (1.0 - f * cmo.abs())
 				};
 
 				assert_eq_float(value, ma.next(&x));
 			});
-		});
+		}
+	}
+
+	#[test]
+	fn test_vidya_trend_is_ema() {
+		// constant positive changes make |CMO| = 1, so VIDYA turns into EMA
+		let src: Vec<ValueType> = (0..200)
+			.map(|i| (i as ValueType).mul_add(0.5, 10.0))
+			.collect();
+
+		let mut vidya = TestingMethod::new((10, 14), &src[0]).unwrap();
+		let mut ema = EMA::new(10, &src[0]).unwrap();
+
+		for x in &src {
+			assert_eq_float(ema.next(x), vidya.next(x));
+		}
+	}
+
+	#[test]
+	fn test_vidya_volatility_spike() {
+		// sideways choppy market followed by a sharp move up
+		let src: Vec<ValueType> = (0..200)
+			.map(|i| {
+				let chop = if i % 2 == 0 { 0.5 } else { -0.5 };
+
+				if i < 100 {
+					100.0 + chop
+				} else {
+					((i - 100) as ValueType).mul_add(2.0, 100.0) + chop
+				}
+			})
+			.collect();
+
+		// EMA with the same smoothness as VIDYA in the sideways market
+		let mut vidya = TestingMethod::new((10, 10), &src[0]).unwrap();
+		let mut ema = EMA::new(50, &src[0]).unwrap();
+
+		let (mut vidya_noise, mut ema_noise) = (0.0, 0.0);
+		let (mut vidya_lag, mut ema_lag) = (0.0, 0.0);
+		let (mut prev_v, mut prev_e) = (src[0], src[0]);
+
+		for (i, x) in src.iter().enumerate() {
+			let (v, e) = (vidya.next(x), ema.next(x));
+
+			if (50..100).contains(&i) {
+				vidya_noise += (v - prev_v).abs();
+				ema_noise += (e - prev_e).abs();
+			} else if i >= 100 {
+				vidya_lag += x - v;
+				ema_lag += x - e;
+			}
+
+			(prev_v, prev_e) = (v, e);
+		}
+
+		assert!(
+			vidya_noise <= ema_noise * 1.5,
+			"{vidya_noise} vs {ema_noise}"
+		);
+		assert!(vidya_lag * 2.0 < ema_lag, "{vidya_lag} vs {ema_lag}");
 	}
 }
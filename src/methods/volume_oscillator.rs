@@ -0,0 +1,204 @@
+use crate::core::{Error, Method, MovingAverageConstructor, ValueType, OHLCV};
+use crate::helpers::{Peekable, MA};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume Oscillator shows the difference between fast and slow moving averages of volume in percents
+///
+/// `output` = `100` \* \(`fast MA`\(`volume`\) - `slow MA`\(`volume`\)\) / `slow MA`\(`volume`\)
+///
+/// Positive values mean that the recent volume is above the average, negative values mean that it is below.
+///
+/// When the slow moving average of volume is zero (or negative), returns `0.0`.
+///
+/// # Parameters
+///
+/// Has a tuple of 2 parameters \(`fast`: [`MA`], `slow`: [`MA`]\)
+///
+/// Both moving averages should be valid. Usually `fast` period is less than `slow` period.
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::helpers::MA;
+/// use yata::methods::VolumeOscillator;
+///
+/// let candle = Candle { volume: 100.0, ..Candle::default() };
+/// let mut vo: VolumeOscillator = VolumeOscillator::new((MA::SMA(2), MA::SMA(4)), &candle).unwrap();
+///
+/// assert_eq!(vo.next(&candle), 0.0);
+///
+/// let spike = Candle { volume: 500.0, ..Candle::default() };
+/// // fast SMA = 300.0, slow SMA = 200.0
+/// assert_eq!(vo.next(&spike), 50.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1) when both moving averages are O(1)
+///
+/// # See also
+///
+/// [`KlingerVolumeOscillator`](crate::indicators::KlingerVolumeOscillator)
+///
+/// [`MA`]: crate::helpers::MA
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "PVO")]
+#[doc(alias = "RateOfChangeOfVolume")]
+pub struct VolumeOscillator<M: MovingAverageConstructor = MA> {
+	fast: M::Instance,
+	slow: M::Instance,
+	last_value: ValueType,
+}
+
+impl<M: MovingAverageConstructor> VolumeOscillator<M> {
+	fn value(fast: ValueType, slow: ValueType) -> ValueType {
+		if slow > 0.0 {
+			(fast - slow) / slow * 100.0
+		} else {
+			0.0
+		}
+	}
+}
+
+impl<M: MovingAverageConstructor> Method for VolumeOscillator<M> {
+	type Params = (M, M);
+	type Input = dyn OHLCV;
+	type Output = ValueType;
+
+	fn new((fast, slow): Self::Params, candle: &Self::Input) -> Result<Self, Error> {
+		let volume = candle.volume();
+
+		Ok(Self {
+			fast: fast.init(volume)?,
+			slow: slow.init(volume)?,
+			last_value: 0.0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: &Self::Input) -> Self::Output {
+		let volume = candle.volume();
+
+		let fast = self.fast.next(&volume);
+		let slow = self.slow.next(&volume);
+
+		self.last_value = Self::value(fast, slow);
+
+		self.last_value
+	}
+}
+
+impl<M: MovingAverageConstructor> Peekable<<Self as Method>::Output> for VolumeOscillator<M> {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.last_value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, VolumeOscillator};
+	use crate::core::{Candle, ValueType};
+	use crate::helpers::{assert_eq_float, RandomCandles, MA};
+	use crate::methods::tests::test_const_float;
+	use crate::methods::{EMA, SMA};
+
+	type TestingMethod = VolumeOscillator<MA>;
+
+	#[test]
+	fn test_volume_oscillator_const() {
+		for i in 2..255 {
+			let candle = Candle {
+				volume: (i as ValueType + 56.0) / 16.3251,
+				..Candle::default()
+			};
+			let mut method = TestingMethod::new((MA::SMA(i / 2), MA::EMA(i)), &candle).unwrap();
+
+			test_const_float(&mut method, &candle, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_volume_oscillator_spike() {
+		let quiet = Candle {
+			volume: 100.0,
+			..Candle::default()
+		};
+		let spike = Candle {
+			volume: 1000.0,
+			..Candle::default()
+		};
+
+		let mut method = TestingMethod::new((MA::EMA(3), MA::EMA(20)), &quiet).unwrap();
+
+		for _ in 0..50 {
+			assert_eq_float(0.0, method.next(&quiet));
+		}
+
+		let value = method.next(&spike);
+		assert!(value > 10.0, "{value}");
+
+		for _ in 0..200 {
+			let _ = method.next(&quiet);
+		}
+
+		assert!(method.next(&quiet).abs() < 1e-3);
+	}
+
+	#[test]
+	fn test_volume_oscillator_zero_volume() {
+		let candle = Candle::default();
+		let mut method = TestingMethod::new((MA::SMA(2), MA::SMA(5)), &candle).unwrap();
+
+		for _ in 0..10 {
+			assert_eq_float(0.0, method.next(&candle));
+		}
+	}
+
+	#[test]
+	fn test_volume_oscillator() {
+		let candles: Vec<_> = RandomCandles::default().take(300).collect();
+
+		for fast in 1..20 {
+			let slow = fast * 2 + 1;
+			let mut method =
+				TestingMethod::new((MA::SMA(fast), MA::EMA(slow)), &candles[0]).unwrap();
+			let mut sma = SMA::new(fast, &candles[0].volume).unwrap();
+			let mut ema = EMA::new(slow, &candles[0].volume).unwrap();
+
+			for candle in &candles {
+				let fast = sma.next(&candle.volume);
+				let slow = ema.next(&candle.volume);
+
+				let expected = if slow > 0.0 {
+					(fast - slow) / slow * 100.0
+				} else {
+					0.0
+				};
+
+				assert_eq_float(expected, method.next(candle));
+			}
+		}
+	}
+
+	#[test]
+	fn test_volume_oscillator_wrong_params() {
+		let candle = Candle::default();
+
+		assert!(TestingMethod::new((MA::SMA(0), MA::SMA(5)), &candle).is_err());
+		assert!(TestingMethod::new((MA::SMA(2), MA::SMA(0)), &candle).is_err());
+	}
+}
@@ -108,7 +108,7 @@ mod tests {
 
 	#[test]
 	fn test_vwma1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma =
 			TestingMethod::new(1, &(candles.first().close, candles.first().volume)).unwrap();
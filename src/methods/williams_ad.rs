@@ -0,0 +1,198 @@
+use crate::core::{Error, Method, ValueType, OHLCV};
+use crate::helpers::Peekable;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams Accumulation/Distribution for timeseries of [`OHLCV`]
+///
+/// Unlike [`ADI`](crate::methods::ADI), it does not use volume at all.
+/// It is based on the close-to-close movement relative to the true range:
+///
+/// * `true high` = max\(`high`, `previous close`\)
+/// * `true low` = min\(`low`, `previous close`\)
+///
+/// When `close` > `previous close`, then `AD` = `close` - `true low`.
+///
+/// When `close` < `previous close`, then `AD` = `close` - `true high`.
+///
+/// Otherwise `AD` = `0.0`.
+///
+/// `WAD` = `WAD_prev` + `AD`
+///
+/// It is cumulative and starts from `0.0` with the initial candle's `close` as the previous close.
+///
+/// # Parameters
+///
+/// Has no parameters
+///
+/// # Input type
+///
+/// Input type is reference to [`OHLCV`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::WilliamsAD;
+///
+/// let candles: [Candle; 3] = [
+/// //   open  high  low  close volume
+///     (10.0, 11.0, 9.0, 10.0, 0.0).into(),
+///     (10.0, 12.0, 10.5, 11.5, 0.0).into(), // up: 11.5 - min(10.5, 10.0)
+///     (11.5, 12.5, 10.0, 11.0, 0.0).into(), // down: 11.0 - max(12.5, 11.5)
+/// ];
+///
+/// let mut wad = WilliamsAD::new((), &candles[0]).unwrap();
+///
+/// assert_eq!(wad.next(&candles[1]), 1.5);
+/// assert_eq!(wad.next(&candles[2]), 0.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`ADI`](crate::methods::ADI), [`TR`](crate::methods::TR)
+///
+/// [`OHLCV`]: crate::core::OHLCV
+/// [`ValueType`]: crate::core::ValueType
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[doc(alias = "WAD")]
+pub struct WilliamsAD {
+	prev_close: ValueType,
+	sum: ValueType,
+}
+
+/// Just an alias for `WilliamsAD`
+pub type WAD = WilliamsAD;
+
+impl Method for WilliamsAD {
+	type Params = ();
+	type Input = dyn OHLCV;
+	type Output = ValueType;
+
+	fn new((): Self::Params, candle: &Self::Input) -> Result<Self, Error> {
+		Ok(Self {
+			prev_close: candle.close(),
+			sum: 0.0,
+		})
+	}
+
+	#[inline]
+	fn next(&mut self, candle: &Self::Input) -> Self::Output {
+		let close = candle.close();
+
+		if close > self.prev_close {
+			self.sum += close - candle.low().min(self.prev_close);
+		} else if close < self.prev_close {
+			self.sum += close - candle.high().max(self.prev_close);
+		}
+
+		self.prev_close = close;
+
+		self.sum
+	}
+}
+
+impl Peekable<<Self as Method>::Output> for WilliamsAD {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.sum
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, WilliamsAD as TestingMethod};
+	use crate::core::Candle;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::test_const;
+
+	#[test]
+	fn test_williams_ad_const() {
+		let candle = Candle {
+			open: 121.0,
+			high: 133.0,
+			low: 49.0,
+			close: 70.0,
+			volume: 531.0,
+		};
+
+		let mut method = TestingMethod::new((), &candle).unwrap();
+		let output = method.next(&candle);
+
+		assert_eq_float(0.0, output);
+		test_const(&mut method, &candle, &output);
+	}
+
+	#[test]
+	fn test_williams_ad() {
+		let candle = |high, low, close| Candle {
+			open: close,
+			high,
+			low,
+			close,
+			volume: 0.0,
+		};
+
+		let candles = [
+			candle(11.0, 9.0, 10.0),
+			// gap up: true low is the previous close 10.0, AD = 12.0 - 10.0 = 2.0
+			candle(12.5, 11.0, 12.0),
+			// up inside the range: true low is the low 11.5, AD = 12.5 - 11.5 = 1.0
+			candle(13.0, 11.5, 12.5),
+			// unchanged close: AD = 0.0
+			candle(14.0, 10.0, 12.5),
+			// gap down: true high is the previous close 12.5, AD = 9.0 - 12.5 = -3.5
+			candle(10.0, 8.5, 9.0),
+			// down inside the range: true high is the high 9.5, AD = 8.75 - 9.5 = -0.75
+			candle(9.5, 8.0, 8.75),
+		];
+
+		let mut method = TestingMethod::new((), &candles[0]).unwrap();
+		let results: Vec<_> = candles[1..].iter().map(|x| method.next(x)).collect();
+
+		assert_eq_float(2.0, results[0]);
+		assert_eq_float(3.0, results[1]);
+		assert_eq_float(3.0, results[2]);
+		assert_eq_float(-0.5, results[3]);
+		assert_eq_float(-1.25, results[4]);
+	}
+
+	#[test]
+	fn test_williams_ad_naive() {
+		let candles: Vec<Candle> = RandomCandles::default().take(300).collect();
+		let mut method = TestingMethod::new((), &candles[0]).unwrap();
+
+		let mut sum = 0.0;
+		for pair in candles.windows(2) {
+			let (prev, candle) = (pair[0], pair[1]);
+
+			let true_high = if candle.high > prev.close {
+				candle.high
+			} else {
+				prev.close
+			};
+			let true_low = if candle.low < prev.close {
+				candle.low
+			} else {
+				prev.close
+			};
+
+			if candle.close > prev.close {
+				sum += candle.close - true_low;
+			} else if candle.close < prev.close {
+				sum += candle.close - true_high;
+			}
+
+			assert_eq_float(sum, method.next(&candle));
+		}
+	}
+}
@@ -121,7 +121,7 @@ mod tests {
 
 	#[test]
 	fn test_wma1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
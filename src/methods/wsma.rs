@@ -100,7 +100,7 @@ mod tests {
 
 	#[test]
 	fn test_wsma1() {
-		let mut candles = RandomCandles::default();
+		let candles = RandomCandles::default();
 		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
 
 		candles.take(100).for_each(|x| {
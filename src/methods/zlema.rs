@@ -0,0 +1,174 @@
+use crate::core::{Error, PeriodType, ValueType, Window};
+use crate::core::{Method, MovingAverage};
+use crate::helpers::Peekable;
+use crate::methods::EMA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [Zero Lag Exponential Moving Average](https://en.wikipedia.org/wiki/Zero_lag_exponential_moving_average) of specified `length` for timeseries of type [`ValueType`]
+///
+/// `ZLEMA` = [`EMA`]\(`value` + (`value` - `value[lag]`)\)
+///
+/// where `lag` = (`length` - `1`) / `2`, rounded down. So for `length` = `1` and `length` = `2` `lag` is equal to `0`
+/// and `ZLEMA` is just the same as [`EMA`].
+///
+/// # Parameters
+///
+/// Has a single parameter `length`: [`PeriodType`]
+///
+/// `length` should be > `0`
+///
+/// # Input type
+///
+/// Input type is [`ValueType`]
+///
+/// # Output type
+///
+/// Output type is [`ValueType`]
+///
+/// # Examples
+///
+/// ```
+/// use yata::prelude::*;
+/// use yata::methods::ZLEMA;
+///
+/// // ZLEMA of length=3 (lag=1)
+/// let mut zlema = ZLEMA::new(3, &3.0).unwrap();
+///
+/// assert_eq!(zlema.next(&3.0), 3.0);
+/// assert_eq!(zlema.next(&5.0), 5.0);
+/// assert_eq!(zlema.next(&7.0), 7.0);
+/// ```
+///
+/// # Performance
+///
+/// O(1)
+///
+/// # See also
+///
+/// [`EMA`], [`DEMA`](crate::methods::DEMA), [`TEMA`](crate::methods::TEMA)
+///
+/// [`EMA`]: crate::methods::EMA
+/// [`ValueType`]: crate::core::ValueType
+/// [`PeriodType`]: crate::core::PeriodType
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZLEMA {
+	ema: EMA,
+	window: Window<ValueType>,
+}
+
+impl Method for ZLEMA {
+	type Params = PeriodType;
+	type Input = ValueType;
+	type Output = Self::Input;
+
+	fn new(length: Self::Params, value: &Self::Input) -> Result<Self, Error> {
+		match length {
+			0 => Err(Error::WrongMethodParameters),
+			length => Ok(Self {
+				ema: EMA::new(length, value)?,
+				window: Window::new((length - 1) / 2, *value),
+			}),
+		}
+	}
+
+	#[inline]
+	fn next(&mut self, &value: &Self::Input) -> Self::Output {
+		let lagged = if self.window.is_empty() {
+			value
+		} else {
+			self.window.push(value)
+		};
+
+		self.ema.next(&value.mul_add(2., -lagged))
+	}
+}
+
+impl MovingAverage for ZLEMA {}
+
+impl Peekable<<Self as Method>::Output> for ZLEMA {
+	fn peek(&self) -> <Self as Method>::Output {
+		self.ema.peek()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Method, ZLEMA as TestingMethod};
+	use crate::core::ValueType;
+	use crate::helpers::{assert_eq_float, RandomCandles};
+	use crate::methods::tests::{test_const, test_method_against_naive};
+	use crate::methods::EMA;
+
+	#[test]
+	fn test_zlema_const() {
+		for i in 1..255 {
+			let input = (i as ValueType + 56.0) / 16.3251;
+			let mut method = TestingMethod::new(i, &input).unwrap();
+
+			let output = method.next(&input);
+			test_const(&mut method, &input, &output);
+		}
+	}
+
+	#[test]
+	fn test_zlema1() {
+		let candles = RandomCandles::default();
+
+		let mut ma = TestingMethod::new(1, &candles.first().close).unwrap();
+
+		candles.take(100).for_each(|x| {
+			assert_eq_float(x.close, ma.next(&x.close));
+		});
+	}
+
+	#[test]
+	fn test_zlema() {
+		let sigma = if cfg!(feature = "value_type_f32") {
+			1e-4
+		} else {
+			1e-10
+		};
+
+		test_method_against_naive(
+			1..255,
+			TestingMethod::new,
+			|src, i, length| {
+				let alpha = 2. / (length + 1) as ValueType;
+				let lag = (length - 1) / 2;
+
+				(0..=i).fold(src[0], |ema, j| {
+					let delagged = src[j].mul_add(2., -src[j.saturating_sub(lag)]);
+					(delagged - ema).mul_add(alpha, ema)
+				})
+			},
+			sigma,
+		);
+	}
+
+	#[test]
+	fn test_zlema_ramp_lag() {
+		let ramp: Vec<ValueType> = (0..200).map(|x| x as ValueType * 0.5).collect();
+
+		for length in 3..50 {
+			let mut zlema = TestingMethod::new(length, &ramp[0]).unwrap();
+			let mut ema = EMA::new(length, &ramp[0]).unwrap();
+
+			let (zlema_residuals, ema_residuals) =
+				ramp.iter()
+					.fold((0.0, 0.0), |(zlema_residuals, ema_residuals), x| {
+						(
+							zlema_residuals + (x - zlema.next(x)).abs(),
+							ema_residuals + (x - ema.next(x)).abs(),
+						)
+					});
+
+			assert!(
+				zlema_residuals < ema_residuals,
+				"length={length}, ZLEMA residuals={zlema_residuals}, EMA residuals={ema_residuals}"
+			);
+		}
+	}
+}
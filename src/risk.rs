@@ -0,0 +1,86 @@
+//! Risk management helpers
+//!
+//! Helps to turn signals of [indicators](crate::indicators) into trades of a reasonable size.
+//! Stop distances usually come from ATR-based (see [`TR`](crate::methods::TR)) or channel-breakout indicators,
+//! f.e. [`ChandelierExit`](crate::indicators::ChandelierExit) or [`DonchianChannel`](crate::indicators::DonchianChannel).
+
+use crate::core::ValueType;
+
+/// Calculates position size (in units), which loses exactly `risk_pct` percents of `equity` when the stop is hit
+///
+/// `size` = `equity` \* `risk_pct` / `100` / `stop`
+///
+/// where `stop` is the distance between the entry price and the stop price (f.e. `2.0` \* `ATR`).
+///
+/// The size is not limited by `equity` / `entry`, so the position may cost more than the whole `equity`
+/// (when `stop` is small relative to `entry`). Limiting the leverage is up to the caller.
+///
+/// Returns `0.0` when any of the values is not finite, when `stop` or `entry` is zero or negative
+/// or when `equity` or `risk_pct` is zero or negative.
+///
+/// # Examples
+///
+/// ```
+/// use yata::risk::position_size;
+///
+/// // risk 1% of 10000 with $2 stop distance
+/// assert_eq!(position_size(10000.0, 1.0, 50.0, 2.0), 50.0);
+///
+/// // the entry price doesn't affect the size
+/// assert_eq!(position_size(10000.0, 1.0, 500.0, 2.0), 50.0);
+///
+/// // no stop distance, no position
+/// assert_eq!(position_size(10000.0, 1.0, 50.0, 0.0), 0.0);
+/// ```
+#[must_use]
+pub fn position_size(
+	equity: ValueType,
+	risk_pct: ValueType,
+	entry: ValueType,
+	stop: ValueType,
+) -> ValueType {
+	let is_valid = [equity, risk_pct, entry, stop]
+		.iter()
+		.all(|&x| x.is_finite() && x > 0.0);
+
+	if !is_valid {
+		return 0.0;
+	}
+
+	equity * risk_pct / 100.0 / stop
+}
+
+#[cfg(test)]
+mod tests {
+	use super::position_size;
+	use crate::core::ValueType;
+	use crate::helpers::assert_eq_float;
+
+	#[test]
+	fn test_position_size() {
+		assert_eq_float(50.0, position_size(10000.0, 1.0, 100.0, 2.0));
+		assert_eq_float(100.0, position_size(10000.0, 2.0, 100.0, 2.0));
+		assert_eq_float(25.0, position_size(10000.0, 1.0, 100.0, 4.0));
+
+		// the loss at the stop is exactly the risked amount
+		let size = position_size(12345.0, 0.75, 17.0, 0.3);
+		assert_eq_float(12345.0 * 0.0075, size * 0.3);
+	}
+
+	#[test]
+	fn test_position_size_no_equity_limit() {
+		// the position costs 200000.0, which is far more than the equity
+		assert_eq_float(200.0, position_size(10000.0, 1.0, 1000.0, 0.5));
+	}
+
+	#[test]
+	fn test_position_size_guards() {
+		assert_eq_float(0.0, position_size(10000.0, 1.0, 100.0, 0.0));
+		assert_eq_float(0.0, position_size(10000.0, 1.0, 100.0, -2.0));
+		assert_eq_float(0.0, position_size(10000.0, 1.0, 0.0, 2.0));
+		assert_eq_float(0.0, position_size(10000.0, 0.0, 100.0, 2.0));
+		assert_eq_float(0.0, position_size(-10000.0, 1.0, 100.0, 2.0));
+		assert_eq_float(0.0, position_size(10000.0, 1.0, 100.0, ValueType::NAN));
+		assert_eq_float(0.0, position_size(ValueType::INFINITY, 1.0, 100.0, 2.0));
+	}
+}
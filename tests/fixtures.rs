@@ -0,0 +1,205 @@
+//! Regression tests over recorded fixtures
+//!
+//! Every indicator is run over the fixed candles from `tests/fixtures/candles.csv` and its results are compared
+//! with the reference values in `tests/fixtures/<indicator>.csv`, which come from a source independent of this crate.
+//! See `tests/fixtures/README.md` for the format.
+
+use std::fs;
+use std::path::PathBuf;
+
+use yata::core::{Candle, IndicatorConfig, IndicatorInstance, ValueType};
+use yata::indicators::PivotReversalStrategy;
+
+const TOLERANCE: ValueType = if cfg!(feature = "value_type_f32") {
+	1e-4
+} else {
+	1e-8
+};
+
+/// Reads fixture file into a header and rows of values, where empty cells are `None`
+fn read_csv(name: &str) -> (Vec<String>, Vec<Vec<Option<ValueType>>>) {
+	let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", name]
+		.iter()
+		.collect();
+	let content = fs::read_to_string(&path)
+		.unwrap_or_else(|err| panic!("Unable to read fixture {}: {err}", path.display()));
+
+	// empty lines are rows with a single empty cell
+	let mut lines = content.lines();
+	let header = lines
+		.next()
+		.unwrap_or_else(|| panic!("Fixture {name} is empty"))
+		.split(',')
+		.map(|column| column.trim().to_string())
+		.collect::<Vec<_>>();
+
+	let rows = lines
+		.enumerate()
+		.map(|(row, line)| {
+			let values = line
+				.split(',')
+				.map(|value| match value.trim() {
+					"" => None,
+					value => Some(value.parse().unwrap_or_else(|_| {
+						panic!("Fixture {name}, row {row}: unable to parse {value:?}")
+					})),
+				})
+				.collect::<Vec<_>>();
+
+			assert_eq!(
+				values.len(),
+				header.len(),
+				"Fixture {name}, row {row}: wrong columns count"
+			);
+
+			values
+		})
+		.collect();
+
+	(header, rows)
+}
+
+fn read_candles() -> Vec<Candle> {
+	let (header, rows) = read_csv("candles.csv");
+	assert_eq!(header, ["open", "high", "low", "close", "volume"]);
+
+	rows.iter()
+		.enumerate()
+		.map(|(row, values)| {
+			let value = |i: usize| {
+				values[i].unwrap_or_else(|| panic!("Fixture candles.csv, row {row}: empty value"))
+			};
+
+			(value(0), value(1), value(2), value(3), value(4)).into()
+		})
+		.collect()
+}
+
+fn is_close(expected: ValueType, actual: ValueType) -> bool {
+	(expected - actual).abs() <= TOLERANCE * expected.abs().max(1.0)
+}
+
+/// Runs indicator over the fixture candles and compares every raw value and signal with the reference ones
+///
+/// Reference file must have a column for every value name and every signal name of the indicator.
+/// Signals are compared by their analog representation (`-1`, `0` or `1`).
+/// Empty cells mean that the reference value is not defined for the row, so they are not compared.
+fn check_fixture<C: IndicatorConfig>(cfg: C, name: &str) {
+	let candles = read_candles();
+	let (header, expected) = read_csv(name);
+
+	let mut instance = cfg.init(&candles[0]).unwrap();
+	let value_names = instance.value_names();
+	let signal_names = instance.signal_names();
+
+	let columns: Vec<_> = value_names.iter().chain(signal_names).copied().collect();
+	assert_eq!(
+		header, columns,
+		"Fixture {name}: columns do not match indicator's outputs"
+	);
+	assert_eq!(
+		expected.len(),
+		candles.len(),
+		"Fixture {name}: wrong rows count"
+	);
+
+	for (row, (candle, expected)) in candles.iter().zip(&expected).enumerate() {
+		let result = instance.next(candle);
+
+		for (i, value_name) in value_names.iter().enumerate() {
+			let (Some(expected), actual) = (expected[i], result.value(i)) else {
+				continue;
+			};
+
+			assert!(
+				is_close(expected, actual),
+				"Fixture {name}, row {row}, value `{value_name}`: expected {expected}, got {actual}"
+			);
+		}
+
+		for (i, signal_name) in signal_names.iter().enumerate() {
+			let Some(expected) = expected[value_names.len() + i] else {
+				continue;
+			};
+			let actual = result.signal(i).analog();
+
+			assert!(
+				is_close(expected, actual.into()),
+				"Fixture {name}, row {row}, signal `{signal_name}`: expected {expected}, got {actual}"
+			);
+		}
+	}
+}
+
+/// Straightforward pivot reversal signal, which is used to produce `pivot_reversal_strategy.csv`
+///
+/// A pivot high is a candle whose `high` is strictly greater than `high` of `left` candles before it and `right` candles after it
+/// (same as `ta.pivothigh` in TradingView Pine), so it is confirmed `right` candles later. Pivot low is the same for `low`.
+///
+/// The signal is not defined until both a pivot high and a pivot low are confirmed.
+fn naive_pivot_reversal(candles: &[Candle], left: usize, right: usize) -> Vec<Option<ValueType>> {
+	let mut pivot_high = None;
+	let mut pivot_low = None;
+
+	(0..candles.len())
+		.map(|i| {
+			let is_pivot = |price: fn(&Candle) -> ValueType| {
+				i >= left + right && {
+					let pivot = i - right;
+					(pivot - left..=i)
+						.filter(|&j| j != pivot)
+						.all(|j| price(&candles[pivot]) > price(&candles[j]))
+				}
+			};
+
+			let swh = is_pivot(|candle| candle.high);
+			// pivot low is a pivot high of the negated `low` values
+			let swl = is_pivot(|candle| -candle.low);
+
+			if swh {
+				pivot_high = Some(candles[i - right].high);
+			}
+			if swl {
+				pivot_low = Some(candles[i - right].low);
+			}
+
+			let (pivot_high, pivot_low) = (pivot_high?, pivot_low?);
+			let le = swh || candles[i].high <= pivot_high;
+			let se = swl || candles[i].low >= pivot_low;
+
+			Some(ValueType::from(i8::from(se) - i8::from(le)))
+		})
+		.collect()
+}
+
+#[test]
+fn fixture_pivot_reversal_strategy_reference() {
+	let candles = read_candles();
+	let (header, rows) = read_csv("pivot_reversal_strategy.csv");
+	assert_eq!(header, ["pivot"]);
+
+	let cfg = PivotReversalStrategy::default();
+	let expected = naive_pivot_reversal(&candles, cfg.left as usize, cfg.right as usize);
+	let reference: Vec<_> = rows.iter().map(|row| row[0]).collect();
+
+	assert_eq!(reference, expected);
+}
+
+#[test]
+fn fixture_pivot_reversal_strategy() {
+	check_fixture(
+		PivotReversalStrategy::default(),
+		"pivot_reversal_strategy.csv",
+	);
+}
+
+#[test]
+#[should_panic(expected = "Fixture pivot_reversal_strategy.csv, row")]
+fn fixture_flags_regression() {
+	let cfg = PivotReversalStrategy {
+		left: 3,
+		..PivotReversalStrategy::default()
+	};
+
+	check_fixture(cfg, "pivot_reversal_strategy.csv");
+}